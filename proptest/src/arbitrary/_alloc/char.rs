@@ -48,7 +48,8 @@ arbitrary!(DecodeUtf16<<Vec<u16> as IntoIterator>::IntoIter>,
     static_map(vec(any::<u16>(), ..VEC_MAX), decode_utf16)
 );
 
-arbitrary!(ParseCharError, IndFlatten<Mapped<bool, Just<Self>>>;
+arbitrary!(ParseCharError,
+    IndFlatten<FlatMap<StrategyFor<bool>, fn(bool) -> Just<Self>>>;
     any::<bool>().prop_ind_flat_map(|is_two|
         Just((if is_two { "__" } else { "" }).parse::<char>().unwrap_err()))
 );
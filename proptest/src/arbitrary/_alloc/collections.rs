@@ -19,6 +19,8 @@ use crate::std_facade::{
     binary_heap, btree_map, btree_set, fmt, linked_list, vec, vec_deque, Arc,
     BTreeMap, BTreeSet, BinaryHeap, Box, LinkedList, Rc, Vec, VecDeque,
 };
+#[cfg(feature = "std")]
+use core::hash::BuildHasher;
 use core::hash::Hash;
 use core::ops::{Bound, RangeInclusive};
 
@@ -79,7 +81,16 @@ impl_1!(LinkedList, LinkedListStrategy, => linked_list);
 impl_1!(BTreeSet, BTreeSetStrategy, Ord => btree_set);
 impl_1!(BinaryHeap, BinaryHeapStrategy, Ord => binary_heap);
 #[cfg(feature = "std")]
-impl_1!(HashSet, HashSetStrategy, Hash, Eq => hash_set);
+arbitrary!([A: Arbitrary + Hash + Eq, S: BuildHasher + Default] HashSet<A, S>,
+HashSetStrategy<A::Strategy, S>, RangedParams1<A::Parameters>;
+args => {
+    let product_unpack![range, a] = args;
+    hash_set_with_hasher(any_with::<A>(a), range)
+});
+
+#[cfg(feature = "std")]
+lift1!([Hash + Eq +] HashSet<A>, SizeRange;
+    base, args => hash_set(base, args));
 
 //==============================================================================
 // IntoIterator:
@@ -112,12 +123,13 @@ into_iter_1!(hash_set, HashSet, Hash, Eq);
 //==============================================================================
 
 #[cfg(feature = "std")]
-arbitrary!([A: Arbitrary + Hash + Eq, B: Arbitrary] HashMap<A, B>,
-HashMapStrategy<A::Strategy, B::Strategy>,
+arbitrary!([A: Arbitrary + Hash + Eq, B: Arbitrary, S: BuildHasher + Default]
+HashMap<A, B, S>,
+HashMapStrategy<A::Strategy, B::Strategy, S>,
 RangedParams2<A::Parameters, B::Parameters>;
 args => {
     let product_unpack![range, a, b] = args;
-    hash_map(any_with::<A>(a), any_with::<B>(b), range)
+    hash_map_with_hasher(any_with::<A>(a), any_with::<B>(b), range)
 });
 
 #[cfg(feature = "std")]
@@ -29,11 +29,14 @@ macro_rules! atomic {
 // impl_wrap_gen!(AtomicPtr); // We don't have impl Arbitrary for *mut T yet.
 atomic!(AtomicBool, bool; AtomicIsize, isize; AtomicUsize, usize);
 
-#[cfg(feature = "unstable")]
-atomic!(AtomicI8, i8; AtomicI16, i16; AtomicI32, i32;
-        AtomicU8, u8; AtomicU16, u16; AtomicU32, u32);
+#[cfg(target_has_atomic = "8")]
+atomic!(AtomicI8, i8; AtomicU8, u8);
+#[cfg(target_has_atomic = "16")]
+atomic!(AtomicI16, i16; AtomicU16, u16);
+#[cfg(target_has_atomic = "32")]
+atomic!(AtomicI32, i32; AtomicU32, u32);
 
-#[cfg(all(feature = "unstable", feature = "atomic64bit"))]
+#[cfg(all(feature = "atomic64bit", target_has_atomic = "64"))]
 atomic!(AtomicI64, i64; AtomicU64, u64);
 
 arbitrary!(Ordering,
@@ -58,7 +61,11 @@ mod test {
         ordering => Ordering
     );
 
-    #[cfg(feature = "unstable")]
+    #[cfg(all(
+        target_has_atomic = "8",
+        target_has_atomic = "16",
+        target_has_atomic = "32"
+    ))]
     no_panic_test!(
         atomic_i8  => AtomicI8,
         atomic_i16 => AtomicI16,
@@ -68,9 +75,21 @@ mod test {
         atomic_u32 => AtomicU32
     );
 
-    #[cfg(all(feature = "unstable", feature = "atomic64bit"))]
+    #[cfg(all(feature = "atomic64bit", target_has_atomic = "64"))]
     no_panic_test!(
         atomic_i64 => AtomicI64,
         atomic_u64 => AtomicU64
     );
+
+    #[test]
+    fn atomics_shrink_like_their_values() {
+        use core::sync::atomic::Ordering::SeqCst;
+
+        use super::*;
+        use crate::test_runner::TestRunner;
+
+        let mut runner = TestRunner::deterministic();
+        assert!(!any::<AtomicBool>().minimal(&mut runner).load(SeqCst));
+        assert_eq!(0, any::<AtomicUsize>().minimal(&mut runner).load(SeqCst));
+    }
 }
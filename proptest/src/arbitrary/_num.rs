@@ -0,0 +1,26 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Arbitrary implementations for the `num` crates.
+
+use ::num_bigint::{BigInt, BigUint};
+
+use crate::bigint::{BigIntStrategy, BigUintStrategy};
+use crate::collection::SizeRange;
+
+arbitrary!(BigUint, BigUintStrategy, SizeRange; bits => crate::bigint::biguint(bits));
+arbitrary!(BigInt, BigIntStrategy, SizeRange; bits => crate::bigint::bigint(bits));
+
+#[cfg(test)]
+mod test {
+    no_panic_test!(
+        biguint => ::num_bigint::BigUint,
+        bigint => ::num_bigint::BigInt
+    );
+}
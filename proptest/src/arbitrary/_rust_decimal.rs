@@ -0,0 +1,21 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Arbitrary implementations for the `rust_decimal` crate.
+
+use ::rust_decimal::Decimal;
+
+use crate::decimal::AnyDecimal;
+
+arbitrary!(Decimal, AnyDecimal; crate::decimal::any());
+
+#[cfg(test)]
+mod test {
+    no_panic_test!(decimal => ::rust_decimal::Decimal);
+}
@@ -11,27 +11,22 @@
 
 use crate::std_facade::{Box, String, Vec};
 use std::ffi::*;
-use std::ops::RangeInclusive;
 
 use crate::arbitrary::*;
 use crate::collection::*;
+use crate::ffi::{c_string, os_string, CStringStrategy, OsStringStrategy};
 use crate::strategy::statics::static_map;
 use crate::strategy::*;
 
 use super::string::not_utf8_bytes;
 
-arbitrary!(CString,
-    SFnPtrMap<VecStrategy<RangeInclusive<u8>>, Self>, SizeRange;
-    args => static_map(vec(1..=::std::u8::MAX, args + 1), |mut vec| {
-        vec.pop().unwrap();
-        // Could use: Self::from_vec_unchecked(vec) safely.
-        Self::new(vec).unwrap()
-    })
+arbitrary!(CString, CStringStrategy, SizeRange;
+    args => c_string(args)
 );
 
-arbitrary!(OsString, MapInto<StrategyFor<String>, Self>,
+arbitrary!(OsString, OsStringStrategy,
     <String as Arbitrary>::Parameters;
-    a => any_with::<String>(a).prop_map_into()
+    a => os_string(a)
 );
 
 macro_rules! dst_wrapped {
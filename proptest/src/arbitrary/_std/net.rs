@@ -9,9 +9,12 @@
 
 //! Arbitrary implementations for `std::net`.
 
+use core::fmt;
+use core::ops::RangeInclusive;
 use std::net::*;
 
 use crate::arbitrary::*;
+use crate::net::AddrParams;
 use crate::strategy::statics::static_map;
 use crate::strategy::*;
 
@@ -20,45 +23,95 @@ use crate::strategy::*;
 
 arbitrary!(AddrParseError; "".parse::<Ipv4Addr>().unwrap_err());
 
-arbitrary!(Ipv4Addr,
-    TupleUnion<(
-        WA<Just<Self>>,
-        WA<Just<Self>>,
-        WA<MapInto<StrategyFor<u32>, Self>>
-    )>;
-    prop_oneof![
-        1  => Just(Self::new(0, 0, 0, 0)),
-        4  => Just(Self::new(127, 0, 0, 1)),
-        10 => any::<u32>().prop_map_into()
-    ]
-);
+/// The strategy for a class of addresses, shrinking towards `range.start()`.
+type AddrClass<B, A> = SFnPtrMap<RangeInclusive<B>, A>;
 
-arbitrary!(Ipv6Addr,
-    TupleUnion<(
-        WA<SMapped<Ipv4Addr, Self>>,
-        WA<MapInto<StrategyFor<[u16; 8]>, Self>>
-    )>;
-    prop_oneof![
-        2 => static_map(any::<Ipv4Addr>(), |ip| ip.to_ipv6_mapped()),
-        1 => any::<[u16; 8]>().prop_map_into()
-    ]
-);
+/// Builds a union of the address classes with non-zero weight, in order.
+fn addr_classes<B, A>(
+    classes: &[(u32, RangeInclusive<B>)],
+    to_addr: fn(B) -> A,
+) -> Union<AddrClass<B, A>>
+where
+    B: Copy,
+    RangeInclusive<B>: Strategy<Value = B>,
+    A: fmt::Debug,
+{
+    Union::new_weighted(
+        classes
+            .iter()
+            .filter(|&&(weight, _)| weight > 0)
+            .map(|(weight, range)| {
+                (*weight, static_map(range.clone(), to_addr))
+            })
+            .collect(),
+    )
+}
+
+/// Generates addresses according to [`AddrParams`].
+///
+/// ## Panics
+///
+/// Panics if every weight in the parameters, other than `ipv4_mapped`, is 0.
+impl Arbitrary for Ipv4Addr {
+    type Parameters = AddrParams;
+    type Strategy = Union<AddrClass<u32, Self>>;
 
-arbitrary!(SocketAddrV4, SMapped<(Ipv4Addr, u16), Self>;
-    static_map(any::<(Ipv4Addr, u16)>(), |(a, b)| Self::new(a, b))
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        addr_classes(
+            &[
+                (args.unspecified(), 0..=0),
+                (args.loopback(), 0x7F00_0001..=0x7FFF_FFFF),
+                (args.link_local(), 0xA9FE_0000..=0xA9FE_FFFF),
+                (args.multicast(), 0xE000_0000..=0xEFFF_FFFF),
+                (args.other(), 0..=u32::MAX),
+            ],
+            Self::from,
+        )
+    }
+}
+
+/// Generates addresses according to [`AddrParams`].
+///
+/// ## Panics
+///
+/// Panics if every weight in the parameters is 0.
+impl Arbitrary for Ipv6Addr {
+    type Parameters = AddrParams;
+    type Strategy = Union<AddrClass<u128, Self>>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        addr_classes(
+            &[
+                (args.unspecified(), 0..=0),
+                (args.loopback(), 1..=1),
+                (args.link_local(), 0xFE80 << 112..=(0xFEC0 << 112) - 1),
+                (args.multicast(), 0xFF00 << 112..=u128::MAX),
+                (args.ipv4_mapped(), 0xFFFF << 32..=0xFFFF_FFFF_FFFF),
+                (args.other(), 0..=u128::MAX),
+            ],
+            Self::from,
+        )
+    }
+}
+
+arbitrary!(SocketAddrV4, SMapped<(Ipv4Addr, u16), Self>, AddrParams;
+    args => static_map(any_with::<(Ipv4Addr, u16)>((args, ())),
+        |(a, b)| Self::new(a, b))
 );
 
-arbitrary!(SocketAddrV6, SMapped<(Ipv6Addr, u16, u32, u32), Self>;
-    static_map(any::<(Ipv6Addr, u16, u32, u32)>(),
+arbitrary!(SocketAddrV6, SMapped<(Ipv6Addr, u16, u32, u32), Self>,
+    AddrParams;
+    args => static_map(any_with::<(Ipv6Addr, u16, u32, u32)>(
+        (args, (), (), ())),
         |(a, b, c, d)| Self::new(a, b, c, d))
 );
 
 arbitrary!(IpAddr,
     TupleUnion<(WA<MapInto<StrategyFor<Ipv4Addr>, Self>>,
-                WA<MapInto<StrategyFor<Ipv6Addr>, Self>>)>;
-    prop_oneof![
-        any::<Ipv4Addr>().prop_map_into(),
-        any::<Ipv6Addr>().prop_map_into()
+                WA<MapInto<StrategyFor<Ipv6Addr>, Self>>)>, AddrParams;
+    args => prop_oneof![
+        any_with::<Ipv4Addr>(args).prop_map_into(),
+        any_with::<Ipv6Addr>(args).prop_map_into()
     ]
 );
 
@@ -71,10 +124,10 @@ arbitrary!(Shutdown,
 );
 arbitrary!(SocketAddr,
     TupleUnion<(WA<MapInto<StrategyFor<SocketAddrV4>, Self>>,
-                WA<MapInto<StrategyFor<SocketAddrV6>, Self>>)>;
-    prop_oneof![
-        any::<SocketAddrV4>().prop_map_into(),
-        any::<SocketAddrV6>().prop_map_into()
+                WA<MapInto<StrategyFor<SocketAddrV6>, Self>>)>, AddrParams;
+    args => prop_oneof![
+        any_with::<SocketAddrV4>(args).prop_map_into(),
+        any_with::<SocketAddrV6>(args).prop_map_into()
     ]
 );
 
@@ -114,4 +167,52 @@ mod test {
     no_panic_test!(
         ipv6_multicast_scope => Ipv6MulticastScope
     );
+
+    use super::*;
+    use crate::test_runner::TestRunner;
+
+    #[test]
+    fn special_classes_are_generated() {
+        let mut runner = TestRunner::deterministic();
+        let (mut v4, mut v6) = ([0; 4], [0; 5]);
+        for _ in 0..1024 {
+            let ip = any::<Ipv4Addr>().new_tree(&mut runner).unwrap().current();
+            v4[0] += ip.is_unspecified() as usize;
+            v4[1] += ip.is_loopback() as usize;
+            v4[2] += ip.is_link_local() as usize;
+            v4[3] += ip.is_multicast() as usize;
+
+            let ip = any::<Ipv6Addr>().new_tree(&mut runner).unwrap().current();
+            v6[0] += ip.is_unspecified() as usize;
+            v6[1] += ip.is_loopback() as usize;
+            v6[2] += (ip.segments()[0] & 0xFFC0 == 0xFE80) as usize;
+            v6[3] += ip.is_multicast() as usize;
+            v6[4] += ip.to_ipv4_mapped().is_some() as usize;
+        }
+        assert!(v4.iter().all(|&n| n > 20), "{:?}", v4);
+        assert!(v6.iter().all(|&n| n > 20), "{:?}", v6);
+    }
+
+    #[test]
+    fn zero_weights_exclude_classes() {
+        let mut runner = TestRunner::deterministic();
+        let params = AddrParams::none().with_multicast(1);
+        for _ in 0..256 {
+            let addr = any_with::<SocketAddr>(params)
+                .new_tree(&mut runner)
+                .unwrap()
+                .current();
+            assert!(addr.ip().is_multicast(), "{}", addr);
+        }
+    }
+
+    #[test]
+    fn shrinks_to_unspecified() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..64 {
+            let mut tree = any::<IpAddr>().new_tree(&mut runner).unwrap();
+            while tree.simplify() {}
+            assert!(tree.current().is_unspecified());
+        }
+    }
 }
@@ -13,10 +13,12 @@ use std::path::*;
 
 use crate::{
     arbitrary::{SMapped, StrategyFor},
+    collection::vec,
     path::PathParams,
-    prelude::{any, any_with, Arbitrary, Strategy},
+    prelude::{any, any_with, Arbitrary, Just, Strategy},
+    sample::select,
     std_facade::{string::ToString, Arc, Box, Rc, String, Vec},
-    strategy::{statics::static_map, MapInto},
+    strategy::{statics::static_map, MapInto, SBoxedStrategy},
 };
 
 arbitrary!(StripPrefixError; Path::new("").strip_prefix("a").unwrap_err());
@@ -36,32 +38,75 @@ arbitrary!(StripPrefixError; Path::new("").strip_prefix("a").unwrap_err());
 #[derive(Debug)]
 pub struct PathParamsOutput {
     is_absolute: bool,
-    components: Vec<String>,
+    /// Each component, and whether it is preceded by `/` rather than the main separator.
+    components: Vec<(String, bool)>,
+}
+
+/// Components which commonly break path handling.
+fn special_components() -> Vec<String> {
+    let mut special: Vec<String> = [
+        ".",
+        "..",
+        ".hidden",
+        "-rf",
+        "CON",
+        "prn",
+        "AUX",
+        "NUL",
+        "nul.txt",
+        "COM1",
+        "lpt9.tar.gz",
+        "trailing.",
+        "trailing ",
+        " leading",
+        "with space",
+        "caf\u{e9}",
+        "cafe\u{301}",
+        "\u{65e5}\u{672c}\u{8a9e}",
+        "\u{1f600}",
+        "\u{202e}txt.exe",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    special.push("a".repeat(255));
+    special.push("a".repeat(256));
+    special
 }
 
 impl Arbitrary for PathParamsOutput {
     type Parameters = PathParams;
-    type Strategy = SMapped<(bool, Vec<String>), Self>;
+    type Strategy = SBoxedStrategy<Self>;
 
     fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
-        static_map(
-            (
-                any::<bool>(),
-                any_with::<Vec<String>>((
-                    args.components(),
-                    args.component_regex(),
-                )),
-            ),
-            |(is_absolute, components)| Self {
+        let is_absolute = match args.absolute() {
+            Some(is_absolute) => Just(is_absolute).sboxed(),
+            None => any::<bool>().sboxed(),
+        };
+        let component = any_with::<String>(args.component_regex());
+        let component = if args.special_components() {
+            prop_oneof![3 => component, 1 => select(special_components())]
+                .sboxed()
+        } else {
+            component.sboxed()
+        };
+        let alternate = if args.mixed_separators() {
+            any::<bool>().sboxed()
+        } else {
+            Just(false).sboxed()
+        };
+
+        (is_absolute, vec((component, alternate), args.components()))
+            .prop_map(|(is_absolute, components)| Self {
                 is_absolute,
                 components,
-            },
-        )
+            })
+            .sboxed()
     }
 }
 
-/// This implementation accepts as its argument a [`PathParams`] struct. It generates either a
-/// relative or an absolute path with equal probability.
+/// This implementation accepts as its argument a [`PathParams`] struct. By default, it generates
+/// either a relative or an absolute path with equal probability.
 ///
 /// Currently, this implementation does not generate:
 ///
@@ -84,13 +129,24 @@ impl Arbitrary for PathBuf {
                     out.push(&MAIN_SEPARATOR.to_string());
                 }
 
-                for component in components {
+                for (component, alternate) in components {
                     // If a component has an embedded / (or \ on Windows), remove it from the
                     // string.
                     let component = component
                         .chars()
                         .filter(|&c| !std::path::is_separator(c))
                         .collect::<String>();
+                    // `push` does not add a separator after one which is already present,
+                    // so only add the alternate one between two components.
+                    let between_components = out
+                        .to_str()
+                        .and_then(|path| path.chars().last())
+                        .map_or(false, |c| !std::path::is_separator(c));
+                    if alternate && between_components {
+                        let mut path = out.into_os_string();
+                        path.push("/");
+                        out = PathBuf::from(path);
+                    }
                     out.push(&component);
                 }
 
@@ -128,4 +184,69 @@ mod test {
         rc_path => Rc<Path>,
         arc_path => Arc<Path>
     );
+
+    use super::*;
+    use crate::strategy::ValueTree;
+    use crate::test_runner::TestRunner;
+
+    #[test]
+    fn absolute_can_be_fixed() {
+        let mut runner = TestRunner::deterministic();
+        for absolute in [false, true].iter().cloned() {
+            let params = PathParams::default()
+                .with_components(1..4)
+                .with_absolute(Some(absolute));
+            for _ in 0..64 {
+                let path = any_with::<PathBuf>(params.clone())
+                    .new_tree(&mut runner)
+                    .unwrap()
+                    .current();
+                assert_eq!(absolute, path.has_root(), "{:?}", path);
+            }
+        }
+    }
+
+    #[test]
+    fn absolute_paths_with_mixed_separators_have_one_root() {
+        let mut runner = TestRunner::deterministic();
+        let params = PathParams::default()
+            .with_components(1..4)
+            .with_absolute(Some(true))
+            .with_mixed_separators(true);
+        for _ in 0..256 {
+            let path = any_with::<PathBuf>(params.clone())
+                .new_tree(&mut runner)
+                .unwrap()
+                .current();
+            let path = path.to_str().unwrap();
+            let mut chars = path.chars();
+            assert_eq!(Some(MAIN_SEPARATOR), chars.next(), "{:?}", path);
+            assert!(
+                !chars.next().map_or(false, std::path::is_separator),
+                "{:?}",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn special_components_are_generated() {
+        let mut runner = TestRunner::deterministic();
+        let params = PathParams::default()
+            .with_components(4..8)
+            .with_special_components(true)
+            .with_mixed_separators(true);
+        let (mut parent, mut long) = (false, false);
+        for _ in 0..256 {
+            let path = any_with::<PathBuf>(params.clone())
+                .new_tree(&mut runner)
+                .unwrap()
+                .current();
+            for component in path.components() {
+                parent |= component == Component::ParentDir;
+                long |= component.as_os_str().len() == 256;
+            }
+        }
+        assert!(parent && long);
+    }
 }
@@ -9,35 +9,23 @@
 
 //! Arbitrary implementations for `std::time`.
 
-use core::ops::Range;
 use std::time::*;
 
-use crate::arbitrary::*;
-use crate::num;
-use crate::strategy::statics::{self, static_map};
+use crate::time::{self, DurationStrategy, SystemTimeStrategy, TimeParams};
 
-arbitrary!(Duration, SMapped<(u64, u32), Self>;
-    static_map(any::<(u64, u32)>(), |(a, b)| Duration::new(a, b))
+arbitrary!(Duration, DurationStrategy, TimeParams;
+    args => time::duration(args)
 );
 
 // Instant::now() "never" returns the same Instant, so no shrinking may occur!
+// It panics on `wasm32-unknown-unknown`, which has no clock.
+#[cfg(not(target_arch = "wasm32"))]
 arbitrary!(Instant; Self::now());
 
-arbitrary!(
-    // We can't use `any::<Duration>()` because the addition to `SystemTime`
-    // can overflow and panic. To be conservative, we only allow seconds to go
-    // to i32::MAX since a certain popular OS still uses `i32` to represent the
-    // seconds counter.
-    SystemTime, statics::Map<(num::i32::Any, Range<u32>),
-                             fn ((i32, u32)) -> SystemTime>;
-    static_map((num::i32::ANY, 0..1_000_000_000u32),
-                |(sec, ns)| {
-                    if sec >= 0 {
-                        UNIX_EPOCH + Duration::new(sec as u64, ns)
-                    } else {
-                        UNIX_EPOCH - Duration::new((-(sec as i64)) as u64, ns)
-                    }
-                })
+// By default, seconds only go to i32::MAX either side of the epoch since a
+// certain popular OS still uses `i32` to represent the seconds counter.
+arbitrary!(SystemTime, SystemTimeStrategy, TimeParams;
+    args => time::system_time(args)
 );
 
 #[cfg(test)]
@@ -0,0 +1,21 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Arbitrary implementations for the `uuid` crate.
+
+use ::uuid::Uuid;
+
+use crate::uuid::UuidStrategy;
+
+arbitrary!(Uuid, UuidStrategy; crate::uuid::any());
+
+#[cfg(test)]
+mod test {
+    no_panic_test!(uuid => ::uuid::Uuid);
+}
@@ -42,6 +42,15 @@ mod _alloc;
 #[cfg(feature = "std")]
 mod _std;
 
+#[cfg(feature = "num")]
+mod _num;
+
+#[cfg(feature = "rust_decimal")]
+mod _rust_decimal;
+
+#[cfg(feature = "uuid")]
+mod _uuid;
+
 pub use self::traits::*;
 
 //==============================================================================
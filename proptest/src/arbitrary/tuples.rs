@@ -36,10 +36,14 @@ impl_tuple!(T0, T1, T2, T3, T4, T5, T6);
 impl_tuple!(T0, T1, T2, T3, T4, T5, T6, T7);
 impl_tuple!(T0, T1, T2, T3, T4, T5, T6, T7, T8);
 impl_tuple!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9);
+impl_tuple!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+impl_tuple!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
 
 #[cfg(test)]
 mod test {
     no_panic_test!(
-        tuple_n10 => ((), bool, u8, u16, u32, u64, i8, i16, i32, i64)
+        tuple_n10 => ((), bool, u8, u16, u32, u64, i8, i16, i32, i64),
+        tuple_n12 => ((), bool, u8, u16, u32, u64, i8, i16, i32, i64, char,
+                      usize)
     );
 }
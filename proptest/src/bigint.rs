@@ -0,0 +1,289 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating `num_bigint::BigUint` and `BigInt` values.
+//!
+//! Values are chosen by their bit length, that is the number of bits needed
+//! to represent their magnitude, so that large and small numbers are both
+//! likely to be generated. A share of the values are edge cases such as 0,
+//! 1 and `2^k - 1`, `2^k` and `2^k + 1`, which are where arbitrary-precision
+//! arithmetic tends to carry into, or borrow from, a new digit.
+//!
+//! All strategies shrink towards zero by binary searching on the magnitude,
+//! without changing the sign.
+
+use core::cmp::max;
+
+use ::num_bigint::{BigInt, BigUint, Sign};
+use rand::Rng;
+
+use crate::collection::SizeRange;
+use crate::std_facade::Vec;
+use crate::strategy::*;
+use crate::test_runner::*;
+
+/// One in this many generated values is an edge case.
+const EDGE_CASE_RATIO: u32 = 4;
+
+/// Strategy to create `BigUint`s with a bit length within a given range.
+///
+/// Created by the `biguint()` function in the same module.
+#[must_use = "strategies do nothing unless used"]
+#[derive(Clone, Debug)]
+pub struct BigUintStrategy {
+    bits: SizeRange,
+}
+
+/// `ValueTree` corresponding to `BigUintStrategy`.
+///
+/// Shrinks a `BigUint` towards the smallest value of the minimum bit length
+/// by binary search.
+#[derive(Clone, Debug)]
+pub struct BigUintValueTree {
+    lo: BigUint,
+    curr: BigUint,
+    hi: BigUint,
+}
+
+/// Create a strategy to generate `BigUint`s whose bit length, as given by
+/// `BigUint::bits()`, is within `bits`.
+///
+/// Zero has a bit length of 0, so is only generated if `bits` includes 0.
+/// Values shrink towards zero, or towards `2^(n - 1)` if `n` is the least
+/// bit length allowed.
+pub fn biguint(bits: impl Into<SizeRange>) -> BigUintStrategy {
+    BigUintStrategy { bits: bits.into() }
+}
+
+/// Return the least `BigUint` with a bit length of at least `bits`.
+fn least_with_bits(bits: usize) -> BigUint {
+    if 0 == bits {
+        BigUint::default()
+    } else {
+        BigUint::from(1u32) << (bits - 1)
+    }
+}
+
+/// Generate a `BigUint` with a bit length of exactly `bits`.
+fn random_with_bits(runner: &mut TestRunner, bits: usize) -> BigUint {
+    if 0 == bits {
+        return BigUint::default();
+    }
+
+    let rng = runner.rng();
+    let mut digits = (0..(bits + 31) / 32)
+        .map(|_| rng.gen::<u32>())
+        .collect::<Vec<_>>();
+    let top_bits = (bits - 1) % 32 + 1;
+    let top = digits.last_mut().unwrap();
+    *top &= u32::MAX >> (32 - top_bits);
+    *top |= 1 << (top_bits - 1);
+    BigUint::new(digits)
+}
+
+/// Generate an edge case with a bit length between `lo` and `hi` inclusive.
+fn edge_case_with_bits(
+    runner: &mut TestRunner,
+    lo: usize,
+    hi: usize,
+) -> BigUint {
+    if 0 == hi || (0 == lo && runner.rng().gen_ratio(1, 4)) {
+        return BigUint::default();
+    }
+
+    let bits = runner.rng().gen_range(max(lo, 1)..=hi);
+    let power = least_with_bits(bits);
+    match runner.rng().gen_range(0..3) {
+        0 => power,
+        1 => (power << 1) - 1u32,
+        _ if bits > 1 => power + 1u32,
+        _ => power,
+    }
+}
+
+impl BigUintStrategy {
+    fn new_magnitude(&self, runner: &mut TestRunner) -> BigUintValueTree {
+        let (lo, hi) = self.bits.start_end_incl();
+        let value = if runner.rng().gen_ratio(1, EDGE_CASE_RATIO) {
+            edge_case_with_bits(runner, lo, hi)
+        } else {
+            let bits = runner.rng().gen_range(lo..=hi);
+            random_with_bits(runner, bits)
+        };
+
+        BigUintValueTree::new_above(least_with_bits(lo), value)
+    }
+}
+
+impl Strategy for BigUintStrategy {
+    type Tree = BigUintValueTree;
+    type Value = BigUint;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        Ok(self.new_magnitude(runner))
+    }
+}
+
+impl BigUintValueTree {
+    /// Creates a new binary searcher starting at the given value.
+    pub fn new(start: BigUint) -> Self {
+        BigUintValueTree::new_above(BigUint::default(), start)
+    }
+
+    /// Creates a new binary searcher which will not search below the given
+    /// `lo` value.
+    pub fn new_above(lo: BigUint, start: BigUint) -> Self {
+        BigUintValueTree {
+            lo,
+            hi: start.clone(),
+            curr: start,
+        }
+    }
+
+    fn reposition(&mut self) -> bool {
+        let new_mid = &self.lo + (&self.hi - &self.lo) / 2u32;
+
+        if new_mid == self.curr {
+            false
+        } else {
+            self.curr = new_mid;
+            true
+        }
+    }
+}
+
+impl ValueTree for BigUintValueTree {
+    type Value = BigUint;
+
+    fn current(&self) -> BigUint {
+        self.curr.clone()
+    }
+
+    fn simplify(&mut self) -> bool {
+        if self.hi <= self.lo {
+            return false;
+        }
+
+        self.hi = self.curr.clone();
+        self.reposition()
+    }
+
+    fn complicate(&mut self) -> bool {
+        if self.hi <= self.lo {
+            return false;
+        }
+
+        self.lo = &self.curr + 1u32;
+        self.reposition()
+    }
+}
+
+/// Strategy to create `BigInt`s with a bit length within a given range.
+///
+/// Created by the `bigint()` function in the same module.
+#[must_use = "strategies do nothing unless used"]
+#[derive(Clone, Debug)]
+pub struct BigIntStrategy(BigUintStrategy);
+
+/// `ValueTree` corresponding to `BigIntStrategy`.
+///
+/// Shrinks the magnitude of a `BigInt` in the same way as
+/// `BigUintValueTree`, keeping its sign.
+#[derive(Clone, Debug)]
+pub struct BigIntValueTree {
+    negative: bool,
+    magnitude: BigUintValueTree,
+}
+
+/// Create a strategy to generate `BigInt`s whose bit length, as given by
+/// `BigInt::bits()`, is within `bits`.
+///
+/// Positive and negative values are equally likely. Values shrink towards
+/// zero, or towards `2^(n - 1)` or `-2^(n - 1)` if `n` is the least bit length
+/// allowed.
+pub fn bigint(bits: impl Into<SizeRange>) -> BigIntStrategy {
+    BigIntStrategy(biguint(bits))
+}
+
+impl Strategy for BigIntStrategy {
+    type Tree = BigIntValueTree;
+    type Value = BigInt;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let negative = runner.rng().gen();
+        Ok(BigIntValueTree {
+            negative,
+            magnitude: self.0.new_magnitude(runner),
+        })
+    }
+}
+
+impl ValueTree for BigIntValueTree {
+    type Value = BigInt;
+
+    fn current(&self) -> BigInt {
+        let sign = if self.negative {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+        BigInt::from_biguint(sign, self.magnitude.current())
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.magnitude.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.magnitude.complicate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn biguints_have_requested_bit_lengths() {
+        let mut runner = TestRunner::deterministic();
+        let input = biguint(3..=130);
+        let mut seen_edge_case = false;
+        for _ in 0..1024 {
+            let value = input.new_tree(&mut runner).unwrap().current();
+            assert!(value.bits() >= 3 && value.bits() <= 130, "{}", value);
+            seen_edge_case |= value.count_ones() == value.bits();
+        }
+        assert!(seen_edge_case);
+
+        assert_eq!(BigUint::from(4u32), input.minimal(&mut runner));
+        assert_eq!(BigUint::default(), biguint(0..64).minimal(&mut runner));
+    }
+
+    #[test]
+    fn bigints_shrink_towards_zero() {
+        let mut runner = TestRunner::deterministic();
+        let mut negative = 0;
+        for _ in 0..256 {
+            let mut tree = bigint(1..200).new_tree(&mut runner).unwrap();
+            let sign = tree.current().sign();
+            negative += (sign == Sign::Minus) as u32;
+            while tree.simplify() {
+                assert_eq!(sign, tree.current().sign());
+            }
+            assert_eq!(1, tree.current().magnitude().bits());
+        }
+        assert!(negative > 96 && negative < 160, "{}", negative);
+    }
+
+    #[test]
+    fn biguint_sanity() {
+        check_strategy_sanity(biguint(0..80), None);
+        check_strategy_sanity(bigint(0..80), None);
+    }
+}
@@ -0,0 +1,427 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for building binary formats out of fields.
+//!
+//! Parsers of binary formats usually reject random bytes at the first
+//! header or checksum, so generating `Vec<u8>` directly tests little beyond
+//! the first few checks. The functions in this module instead build inputs
+//! out of fields, such as fixed magic bytes, integers of a given width and
+//! endianness, and regions with a length prefix or a trailing checksum,
+//! which are combined with `concat()` and `repeat()`.
+//!
+//! Each field is generated from a structured value and only encoded into
+//! bytes at the end, so inputs stay well-formed while they shrink: removing
+//! bytes from a length-prefixed region shrinks the prefix with it, and
+//! simplifying a checksummed region recomputes its checksum. Use `corrupt()`
+//! on any field to make it occasionally invalid.
+//!
+//! ## Example
+//!
+//! ```
+//! use proptest::binary::*;
+//! use proptest::prelude::*;
+//!
+//! // A file starting with `RECS` and a version, followed by records which
+//! // each have a little-endian 16-bit length, and a CRC-32 of everything.
+//! fn file() -> BinaryStrategy {
+//!     let record = length_prefixed(Width::U16, Endian::Little, bytes(0..64));
+//!     checksummed(
+//!         Checksum::Crc32,
+//!         Endian::Little,
+//!         concat(vec![
+//!             magic(b"RECS"),
+//!             uint(Width::U8, Endian::Little, 1..=2),
+//!             repeat(record, 0..8),
+//!         ]),
+//!     )
+//! }
+//!
+//! fn count_records(mut data: &[u8]) -> Option<usize> {
+//!     let mut count = 0;
+//!     while !data.is_empty() {
+//!         let len = u16::from_le_bytes([*data.first()?, *data.get(1)?]);
+//!         data = data.get(2 + len as usize..)?;
+//!         count += 1;
+//!     }
+//!     Some(count)
+//! }
+//!
+//! proptest! {
+//!     # /*
+//!     #[test]
+//!     # */
+//!     fn records_can_be_walked(file in file()) {
+//!         let (body, crc) = file.split_at(file.len() - 4);
+//!         prop_assert_eq!(Checksum::Crc32.compute(body).to_le_bytes(), crc);
+//!         prop_assert!(count_records(&body[5..]).is_some());
+//!     }
+//! }
+//! #
+//! # fn main() { records_can_be_walked(); }
+//! ```
+
+use core::ops::RangeInclusive;
+
+use crate::arbitrary::any;
+use crate::collection::{vec, SizeRange};
+use crate::option;
+use crate::sample::Index;
+use crate::std_facade::{Box, Vec};
+use crate::strategy::*;
+use crate::test_runner::*;
+
+/// The byte order in which integers are encoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endian {
+    /// Most significant byte first, as in network protocols.
+    Big,
+    /// Least significant byte first, as on most processors.
+    Little,
+}
+
+/// The width of an encoded integer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Width {
+    /// One byte.
+    U8,
+    /// Two bytes.
+    U16,
+    /// Four bytes.
+    U32,
+    /// Eight bytes.
+    U64,
+}
+
+impl Width {
+    /// Return the number of bytes in an integer of this width.
+    pub fn bytes(self) -> usize {
+        match self {
+            Width::U8 => 1,
+            Width::U16 => 2,
+            Width::U32 => 4,
+            Width::U64 => 8,
+        }
+    }
+
+    /// Return the greatest integer which can be encoded in this width.
+    pub fn max(self) -> u64 {
+        match self {
+            Width::U64 => u64::MAX,
+            _ => (1 << (8 * self.bytes())) - 1,
+        }
+    }
+
+    /// Encode `value` in this width with the given byte order.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `value` is greater than `self.max()`.
+    pub fn encode(self, value: u64, endian: Endian) -> Vec<u8> {
+        assert!(value <= self.max(), "{} does not fit in {:?}", value, self);
+
+        let len = self.bytes();
+        match endian {
+            Endian::Big => value.to_be_bytes()[8 - len..].to_vec(),
+            Endian::Little => value.to_le_bytes()[..len].to_vec(),
+        }
+    }
+}
+
+/// A checksum appended to a region by `checksummed()`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Checksum {
+    /// The sum of all bytes, modulo 256, in one byte.
+    Sum8,
+    /// The exclusive or of all bytes, in one byte.
+    Xor8,
+    /// Adler-32, as used by zlib, in four bytes.
+    Adler32,
+    /// CRC-32 with the polynomial used by Ethernet, zip, gzip and PNG, in
+    /// four bytes.
+    Crc32,
+}
+
+impl Checksum {
+    /// Return the width in which this checksum is encoded.
+    pub fn width(self) -> Width {
+        match self {
+            Checksum::Sum8 | Checksum::Xor8 => Width::U8,
+            Checksum::Adler32 | Checksum::Crc32 => Width::U32,
+        }
+    }
+
+    /// Compute this checksum of `data`.
+    pub fn compute(self, data: &[u8]) -> u32 {
+        match self {
+            Checksum::Sum8 => {
+                data.iter().fold(0u8, |sum, &b| sum.wrapping_add(b)).into()
+            }
+            Checksum::Xor8 => data.iter().fold(0u8, |sum, &b| sum ^ b).into(),
+            Checksum::Adler32 => {
+                const MOD: u32 = 65521;
+                let (a, b) = data.iter().fold((1, 0), |(a, b), &byte| {
+                    let a = (a + u32::from(byte)) % MOD;
+                    (a, (b + a) % MOD)
+                });
+                b << 16 | a
+            }
+            Checksum::Crc32 => {
+                let crc = data.iter().fold(!0u32, |mut crc, &byte| {
+                    crc ^= u32::from(byte);
+                    for _ in 0..8 {
+                        crc = (crc >> 1)
+                            ^ (0xEDB8_8320 & (crc & 1).wrapping_neg());
+                    }
+                    crc
+                });
+                !crc
+            }
+        }
+    }
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates binary data.
+    ///
+    /// Created by the functions in the same module.
+    #[derive(Clone, Debug)]
+    pub struct BinaryStrategy[][](SBoxedStrategy<Vec<u8>>)
+        -> BinaryValueTree;
+    /// `ValueTree` corresponding to `BinaryStrategy`.
+    pub struct BinaryValueTree[][](Box<dyn ValueTree<Value = Vec<u8>>>)
+        -> Vec<u8>;
+}
+
+/// Create a strategy which always generates `bytes`, such as the magic
+/// number at the start of a file format.
+pub fn magic(bytes: &[u8]) -> BinaryStrategy {
+    BinaryStrategy(Just(bytes.to_vec()).sboxed())
+}
+
+/// Create a strategy which generates arbitrary bytes, with a length within
+/// `size`.
+///
+/// Shrinks by removing bytes and towards zero bytes.
+pub fn bytes(size: impl Into<SizeRange>) -> BinaryStrategy {
+    BinaryStrategy(vec(any::<u8>(), size).sboxed())
+}
+
+/// Create a strategy which generates an integer from `values`, encoded in
+/// `width` with the given byte order.
+///
+/// Shrinks towards the start of `values`.
+///
+/// ## Panics
+///
+/// Panics if `values` includes integers which do not fit in `width`.
+pub fn uint(
+    width: Width,
+    endian: Endian,
+    values: RangeInclusive<u64>,
+) -> BinaryStrategy {
+    assert!(
+        *values.end() <= width.max(),
+        "{:?} does not fit in {:?}",
+        values,
+        width
+    );
+
+    BinaryStrategy(
+        values
+            .prop_map(move |value| width.encode(value, endian))
+            .sboxed(),
+    )
+}
+
+/// Create a strategy which generates the data generated by `body`, preceded
+/// by its length in bytes, encoded in `width` with the given byte order.
+///
+/// The length is computed from the data, so it stays correct as the data
+/// shrinks.
+///
+/// ## Panics
+///
+/// Generating a value panics if `body` generates data longer than fits in
+/// `width`.
+pub fn length_prefixed(
+    width: Width,
+    endian: Endian,
+    body: impl Strategy<Value = Vec<u8>> + Send + Sync + 'static,
+) -> BinaryStrategy {
+    BinaryStrategy(
+        body.prop_map(move |body| {
+            let mut data = width.encode(body.len() as u64, endian);
+            data.extend(body);
+            data
+        })
+        .sboxed(),
+    )
+}
+
+/// Create a strategy which generates the data generated by `body`, followed
+/// by its `checksum`, encoded with the given byte order.
+///
+/// The checksum is computed from the data, so it stays correct as the data
+/// shrinks.
+pub fn checksummed(
+    checksum: Checksum,
+    endian: Endian,
+    body: impl Strategy<Value = Vec<u8>> + Send + Sync + 'static,
+) -> BinaryStrategy {
+    BinaryStrategy(
+        body.prop_map(move |mut body| {
+            let sum = checksum.compute(&body);
+            body.extend(checksum.width().encode(sum.into(), endian));
+            body
+        })
+        .sboxed(),
+    )
+}
+
+/// Create a strategy which generates the data generated by each of `parts`
+/// in order, one after another.
+///
+/// Shrinks each part in turn.
+pub fn concat(parts: Vec<BinaryStrategy>) -> BinaryStrategy {
+    BinaryStrategy(parts.prop_map(|parts| parts.concat()).sboxed())
+}
+
+/// Create a strategy which generates a number of items within `count`, each
+/// generated by `item`, one after another.
+///
+/// The count is not encoded; wrap the result in `length_prefixed()`, or put
+/// a `uint()` before it, if the format needs one.
+///
+/// Shrinks by removing items and simplifying the remaining ones.
+pub fn repeat(
+    item: impl Strategy<Value = Vec<u8>> + Send + Sync + 'static,
+    count: impl Into<SizeRange>,
+) -> BinaryStrategy {
+    BinaryStrategy(vec(item, count).prop_map(|items| items.concat()).sboxed())
+}
+
+/// Create a strategy which generates the data generated by `body`, but with
+/// probability `probability` flips some bits of one of its bytes.
+///
+/// This turns a well-formed field into a mostly well-formed one, such as a
+/// checksummed region whose checksum sometimes doesn't match, so that the
+/// error paths of a parser are tested too. Empty data is never corrupted.
+///
+/// Shrinks towards the uncorrupted data.
+///
+/// ## Panics
+///
+/// Panics if `probability` is not between 0.0 and 1.0 inclusive.
+pub fn corrupt(
+    body: impl Strategy<Value = Vec<u8>> + Send + Sync + 'static,
+    probability: f64,
+) -> BinaryStrategy {
+    BinaryStrategy(
+        (
+            body,
+            option::weighted(probability, (any::<Index>(), 1..=255u8)),
+        )
+            .prop_map(|(mut body, corruption)| {
+                if let (Some((index, mask)), false) =
+                    (corruption, body.is_empty())
+                {
+                    let index = index.index(body.len());
+                    body[index] ^= mask;
+                }
+                body
+            })
+            .sboxed(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn integers_are_encoded() {
+        assert_eq!(vec![0x12, 0x34], Width::U16.encode(0x1234, Endian::Big));
+        assert_eq!(vec![0x34, 0x12], Width::U16.encode(0x1234, Endian::Little));
+        assert_eq!(vec![0xFF; 8], Width::U64.encode(u64::MAX, Endian::Big));
+        assert_eq!(0xFFFF_FFFF, Width::U32.max());
+    }
+
+    #[test]
+    fn checksums_match_reference_values() {
+        assert_eq!(0xCBF4_3926, Checksum::Crc32.compute(b"123456789"));
+        assert_eq!(0x11E6_0398, Checksum::Adler32.compute(b"Wikipedia"));
+        assert_eq!(0, Checksum::Crc32.compute(b""));
+        assert_eq!(1, Checksum::Adler32.compute(b""));
+        assert_eq!(0x06, Checksum::Sum8.compute(&[0xFF, 0x07]));
+        assert_eq!(0xF8, Checksum::Xor8.compute(&[0xFF, 0x07]));
+    }
+
+    #[test]
+    fn structure_is_kept_while_shrinking() {
+        let record = checksummed(
+            Checksum::Sum8,
+            Endian::Big,
+            length_prefixed(Width::U8, Endian::Big, bytes(0..16)),
+        );
+        let input = concat(vec![magic(b"MG"), repeat(record, 0..6)]);
+
+        fn valid(mut data: &[u8]) -> bool {
+            if !data.starts_with(b"MG") {
+                return false;
+            }
+            data = &data[2..];
+            while let Some(&len) = data.first() {
+                let end = 1 + len as usize;
+                match data.get(end) {
+                    Some(&sum)
+                        if sum
+                            == Checksum::Sum8.compute(&data[..end]) as u8 =>
+                    {
+                        data = &data[end + 1..]
+                    }
+                    _ => return false,
+                }
+            }
+            true
+        }
+
+        let mut runner = TestRunner::deterministic();
+        let result = runner.run(&input, |data| {
+            prop_assert!(valid(&data), "invalid: {:?}", data);
+            prop_assert!(data.len() < 20);
+            Ok(())
+        });
+        match result {
+            Err(TestError::Fail(reason, data)) => {
+                assert!(valid(&data), "{}", reason);
+                assert_eq!(20, data.len(), "{:?}", data);
+            }
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn corruption_is_occasional() {
+        let input = corrupt(
+            checksummed(Checksum::Crc32, Endian::Big, bytes(1..8)),
+            0.25,
+        );
+        let mut runner = TestRunner::deterministic();
+        let mut corrupted = 0;
+        for _ in 0..1024 {
+            let data = input.new_tree(&mut runner).unwrap().current();
+            let (body, crc) = data.split_at(data.len() - 4);
+            corrupted +=
+                (Checksum::Crc32.compute(body).to_be_bytes() != crc) as u32;
+        }
+        assert!(corrupted > 192 && corrupted < 320, "{}", corrupted);
+        assert_eq!(vec![0, 0xD2, 0x02, 0xEF, 0x8D], input.minimal(&mut runner));
+    }
+}
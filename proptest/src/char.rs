@@ -14,9 +14,12 @@
 //! circumstances.
 //!
 //! The main things of interest are `any()` to generate truly arbitrary
-//! characters, and `range()` and `ranges()` to select characters from
-//! inclusive ranges.
+//! characters, `range()` and `ranges()` to select characters from
+//! inclusive ranges, and `category()` and `script()` to select characters
+//! with particular Unicode properties.
 
+#[cfg(feature = "std")]
+use crate::std_facade::Arc;
 use crate::std_facade::Cow;
 use core::ops::RangeInclusive;
 
@@ -316,6 +319,157 @@ impl ValueTree for CharValueTree {
     }
 }
 
+/// Strategy for generating `char`s with a Unicode property, as returned by
+/// `category()` and `script()`.
+///
+/// Characters are selected with the same biases as `CharStrategy`, but unlike
+/// it, shrinking crosses ranges, moving towards the lowest character with the
+/// property. For properties including ASCII characters, values therefore
+/// shrink towards ASCII.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug, Clone)]
+#[must_use = "strategies do nothing unless used"]
+pub struct UnicodeClassStrategy {
+    class: regex_syntax::hir::ClassUnicode,
+    ranges: Arc<[CharRange]>,
+}
+
+#[cfg(feature = "std")]
+impl UnicodeClassStrategy {
+    fn from_property(property: &str, name: &str) -> Self {
+        use regex_syntax::hir::{
+            Class, ClassUnicode, ClassUnicodeRange, HirKind,
+        };
+
+        // Don't let the name escape from the `\p{...}` it is put in.
+        let hir = if name
+            .chars()
+            .all(|c| c.is_alphanumeric() || "_- ".contains(c))
+        {
+            regex_syntax::parse(&format!("\\p{{{}={}}}", property, name)).ok()
+        } else {
+            None
+        };
+        let class = match hir.as_ref().map(|hir| hir.kind()) {
+            Some(HirKind::Class(Class::Unicode(class))) => class.clone(),
+            // Single-character classes are parsed as literals.
+            Some(HirKind::Literal(literal)) => {
+                let ch = std::str::from_utf8(&literal.0)
+                    .ok()
+                    .and_then(|s| s.chars().next())
+                    .expect("non-UTF-8 literal from Unicode class");
+                ClassUnicode::new([ClassUnicodeRange::new(ch, ch)])
+            }
+            _ => panic!("Unknown Unicode {} {:?}", property, name),
+        };
+
+        UnicodeClassStrategy::from_class(class)
+    }
+
+    fn from_class(class: regex_syntax::hir::ClassUnicode) -> Self {
+        let ranges = class.iter().map(|r| r.start()..=r.end()).collect();
+        UnicodeClassStrategy { class, ranges }
+    }
+
+    /// Returns a strategy for characters with either this strategy's property
+    /// or `other`'s.
+    pub fn union(&self, other: &UnicodeClassStrategy) -> Self {
+        let mut class = self.class.clone();
+        class.union(&other.class);
+        UnicodeClassStrategy::from_class(class)
+    }
+}
+
+/// Creates a `UnicodeClassStrategy` which selects characters from the Unicode
+/// general category `name`, such as `"Letter"`, `"L"` or `"Lu"`.
+///
+/// ## Panics
+///
+/// Panics if `name` is not a general category known to `regex-syntax`.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn category(name: &str) -> UnicodeClassStrategy {
+    UnicodeClassStrategy::from_property("General_Category", name)
+}
+
+/// Creates a `UnicodeClassStrategy` which selects characters from the Unicode
+/// script `name`, such as `"Cyrillic"` or `"Cyrl"`.
+///
+/// ## Panics
+///
+/// Panics if `name` is not a script known to `regex-syntax`.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn script(name: &str) -> UnicodeClassStrategy {
+    UnicodeClassStrategy::from_property("Script", name)
+}
+
+/// The `ValueTree` corresponding to `UnicodeClassStrategy`.
+///
+/// This searches over the position of the character within the class rather
+/// than over the character itself.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug, Clone)]
+pub struct UnicodeClassValueTree {
+    ranges: Arc<[CharRange]>,
+    index: num::u32::BinarySearch,
+}
+
+#[cfg(feature = "std")]
+impl Strategy for UnicodeClassStrategy {
+    type Tree = UnicodeClassValueTree;
+    type Value = char;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let (base, offset) = select_range_index(
+            runner.rng(),
+            DEFAULT_SPECIAL_CHARS,
+            DEFAULT_PREFERRED_RANGES,
+            &self.ranges,
+        );
+
+        let before: u32 = self
+            .ranges
+            .iter()
+            .take_while(|r| (*r.start() as u32) < base)
+            .map(|r| *r.end() as u32 - *r.start() as u32 + 1)
+            .sum();
+
+        Ok(UnicodeClassValueTree {
+            ranges: Arc::clone(&self.ranges),
+            index: num::u32::BinarySearch::new(before + offset),
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl ValueTree for UnicodeClassValueTree {
+    type Value = char;
+
+    fn current(&self) -> char {
+        let mut index = self.index.current();
+        for range in self.ranges.iter() {
+            let len = *range.end() as u32 - *range.start() as u32 + 1;
+            if index < len {
+                return ::core::char::from_u32(*range.start() as u32 + index)
+                    .expect("Generated non-char value");
+            }
+            index -= len;
+        }
+        panic!("Character index out of range")
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.index.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.index.complicate()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::cmp::{max, min};
@@ -406,4 +560,77 @@ mod test {
             }),
         );
     }
+
+    #[test]
+    fn category_generates_only_that_category() {
+        let mut runner = TestRunner::deterministic();
+        let strategy = category("Uppercase_Letter");
+        for _ in 0..1024 {
+            let ch = strategy.sample(&mut runner);
+            assert!(ch.is_uppercase(), "{:?} is not uppercase", ch);
+        }
+    }
+
+    #[test]
+    fn script_generates_only_that_script() {
+        let mut runner = TestRunner::deterministic();
+        let strategy = script("Cyrillic");
+        for _ in 0..1024 {
+            let ch = strategy.sample(&mut runner);
+            assert!(
+                ('\u{0400}'..='\u{052F}').contains(&ch)
+                    || ('\u{1C80}'..='\u{1C8F}').contains(&ch)
+                    || ('\u{1D2B}'..='\u{1D78}').contains(&ch)
+                    || ('\u{2DE0}'..='\u{2DFF}').contains(&ch)
+                    || ('\u{A640}'..='\u{A69F}').contains(&ch)
+                    || ('\u{FE2E}'..='\u{FE2F}').contains(&ch)
+                    || ('\u{1E030}'..='\u{1E08F}').contains(&ch),
+                "{:?} is not Cyrillic",
+                ch
+            );
+        }
+    }
+
+    #[test]
+    fn unicode_class_shrinks_towards_ascii_across_ranges() {
+        let mut runner = TestRunner::deterministic();
+        let strategy = category("Letter").union(&script("Greek"));
+        for _ in 0..256 {
+            let mut value = strategy.new_tree(&mut runner).unwrap();
+            while value.simplify() {}
+            assert_eq!('A', value.current());
+        }
+
+        let mut value = script("Cyrillic").new_tree(&mut runner).unwrap();
+        while value.simplify() {}
+        assert_eq!('\u{0400}', value.current());
+    }
+
+    #[test]
+    fn unicode_class_union_contains_both() {
+        let mut runner = TestRunner::deterministic();
+        let strategy = script("Greek").union(&category("Nd"));
+        let (mut greek, mut digits) = (0, 0);
+        for _ in 0..1024 {
+            let ch = strategy.sample(&mut runner);
+            if ch.is_numeric() {
+                digits += 1;
+            } else {
+                greek += 1;
+            }
+        }
+        assert!(greek > 0 && digits > 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown Unicode Script")]
+    fn unknown_script_panics() {
+        let _ = script("Klingon");
+    }
+
+    #[test]
+    fn unicode_class_sanity() {
+        check_strategy_sanity(category("L"), None);
+        check_strategy_sanity(script("Latin"), None);
+    }
 }
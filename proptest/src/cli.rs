@@ -0,0 +1,197 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating command-line arguments.
+//!
+//! `args()` generates argument vectors which look like what a shell passes
+//! to a program: short and long flags, `--key=value` options, the `--`
+//! separator, positional arguments, and the awkward cases which command-line
+//! parsers tend to mishandle, such as empty arguments, negative numbers,
+//! leftover shell quoting, invalid UTF-8 and extremely long arguments.
+
+use std::ffi::OsString;
+
+use crate::char;
+use crate::collection::{vec, SizeRange};
+use crate::ffi::not_unicode_os_string;
+use crate::std_facade::{Box, String, Vec};
+use crate::strategy::*;
+use crate::string::string_regex;
+use crate::test_runner::*;
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates single command-line arguments.
+    ///
+    /// Created by the `arg()` function in the same module.
+    #[derive(Clone, Debug)]
+    pub struct ArgStrategy[][](SBoxedStrategy<OsString>)
+        -> ArgValueTree;
+    /// `ValueTree` corresponding to `ArgStrategy`.
+    pub struct ArgValueTree[][](Box<dyn ValueTree<Value = OsString>>)
+        -> OsString;
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates argument vectors.
+    ///
+    /// Created by the `args()` and `argv()` functions in the same module.
+    #[derive(Clone, Debug)]
+    pub struct ArgsStrategy[][](SBoxedStrategy<Vec<OsString>>)
+        -> ArgsValueTree;
+    /// `ValueTree` corresponding to `ArgsStrategy`.
+    pub struct ArgsValueTree[][](Box<dyn ValueTree<Value = Vec<OsString>>>)
+        -> Vec<OsString>;
+}
+
+fn regex(regex: &str) -> SBoxedStrategy<OsString> {
+    string_regex(regex)
+        .unwrap()
+        .prop_map(OsString::from)
+        .sboxed()
+}
+
+/// Creates a strategy which generates a single command-line argument.
+///
+/// Arguments are, from most to least likely:
+///
+/// - positional words, such as `file.txt`;
+/// - short flags, alone (`-v`) or bundled (`-xvf`), and long flags
+///   (`--verbose`);
+/// - options with a value, such as `--level=3`, `--name=` and `--x=a=b`;
+/// - the `--` separator and a lone `-`;
+/// - negative numbers, such as `-1` and `-0.5`, which look like flags;
+/// - strings which still contain shell quoting, spaces, globs or
+///   variables, such as `"a b"`, `'*'` and `$HOME`;
+/// - the empty string;
+/// - flags and values which are not valid Unicode, as generated by
+///   `ffi::not_unicode_os_string()`;
+/// - arguments of 1 KiB to 64 KiB.
+///
+/// Shrinks towards short positional words.
+pub fn arg() -> ArgStrategy {
+    let long = (1024..=65536usize, char::range('a', 'z'))
+        .prop_map(|(len, c)| OsString::from(String::from(c).repeat(len)));
+
+    ArgStrategy(
+        prop_oneof![
+            6 => regex("[a-z][a-z0-9_./]{0,11}"),
+            4 => regex("-[a-zA-Z]|-[a-z]{2,4}"),
+            3 => regex("--[a-z][a-z0-9-]{0,15}"),
+            3 => regex("--[a-z][a-z-]{0,7}=([a-z0-9]{0,8}|[a-z]=[a-z]|)"),
+            2 => prop_oneof![Just("--"), Just("-")].prop_map(OsString::from),
+            1 => regex("-[1-9][0-9]{0,3}(\\.[0-9]{1,2})?"),
+            1 => prop_oneof![
+                regex("\"[a-z ]{0,8}\"|'[a-z*?]{0,8}'"),
+                regex("[a-z]{1,4} [a-z]{1,4}"),
+                regex("\\$[A-Z]{1,6}|[a-z]{0,4}\\*|\\\\[a-z]"),
+            ],
+            1 => Just(OsString::new()),
+            1 => not_unicode_os_string("(--[a-z]{1,8}=)?[a-z]{0,8}".into()),
+            1 => long,
+        ]
+        .sboxed(),
+    )
+}
+
+/// Creates a strategy which generates argument vectors of a number of
+/// arguments within `size`, each as generated by `arg()`.
+///
+/// The vectors do not include a program name; see `argv()` for parsers which
+/// expect one.
+///
+/// Shrinks by removing arguments and simplifying the remaining ones.
+///
+/// ## Example
+///
+/// ```
+/// use std::ffi::OsString;
+///
+/// use proptest::cli::args;
+/// use proptest::prelude::*;
+///
+/// // Collects the `--key=value` options before any `--`.
+/// fn options(args: &[OsString]) -> Vec<(&str, &str)> {
+///     args.iter()
+///         .take_while(|arg| *arg != "--")
+///         .filter_map(|arg| arg.to_str()?.strip_prefix("--")?.split_once('='))
+///         .collect()
+/// }
+///
+/// proptest! {
+///     # /*
+///     #[test]
+///     # */
+///     fn options_have_keys(args in args(0..10)) {
+///         for (key, _) in options(&args) {
+///             prop_assert!(!key.is_empty());
+///         }
+///     }
+/// }
+/// #
+/// # fn main() { options_have_keys(); }
+/// ```
+pub fn args(size: impl Into<SizeRange>) -> ArgsStrategy {
+    ArgsStrategy(vec(arg(), size).sboxed())
+}
+
+/// Like `args()`, but with `program` as the first element, as in the
+/// arguments passed to `main()` and expected by many command-line parsers.
+pub fn argv(program: &str, size: impl Into<SizeRange>) -> ArgsStrategy {
+    let program = OsString::from(program);
+    ArgsStrategy(
+        vec(arg(), size)
+            .prop_map(move |args| {
+                core::iter::once(program.clone()).chain(args).collect()
+            })
+            .sboxed(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn args_include_tricky_cases() {
+        let mut runner = TestRunner::deterministic();
+        let mut saw = [false; 6];
+        for _ in 0..256 {
+            let args = args(0..10).sample(&mut runner);
+            assert!(args.len() < 10);
+            for arg in &args {
+                saw[0] |= arg == "--";
+                saw[1] |= arg.is_empty();
+                saw[2] |= arg.len() >= 1024;
+                saw[3] |= arg
+                    .to_str()
+                    .map_or(false, |s| s.starts_with("--") && s.contains('='));
+                saw[4] |= arg.to_str().map_or(false, |s| {
+                    s.starts_with('-') && s[1..].parse::<f64>().is_ok()
+                });
+                saw[5] |= arg.to_str().is_none() || !cfg!(any(unix, windows));
+            }
+        }
+        assert_eq!([true; 6], saw);
+    }
+
+    #[test]
+    fn argv_starts_with_program() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..64 {
+            let argv = argv("prog", 0..4).sample(&mut runner);
+            assert_eq!("prog", argv[0]);
+            assert!((1..5).contains(&argv.len()));
+        }
+        assert_eq!(
+            vec![OsString::from("prog")],
+            argv("prog", 0..4).minimal(&mut runner)
+        );
+        assert_eq!(OsString::from("a"), arg().minimal(&mut runner));
+    }
+}
@@ -10,7 +10,11 @@
 //! Strategies for generating `std::collections` of values.
 
 use core::cmp::Ord;
+#[cfg(feature = "std")]
+use core::hash::BuildHasher;
 use core::hash::Hash;
+#[cfg(feature = "std")]
+use core::marker::PhantomData;
 use core::ops::{Add, Range, RangeInclusive, RangeTo, RangeToInclusive};
 use core::usize;
 
@@ -19,7 +23,7 @@ use crate::std_facade::{
 };
 
 #[cfg(feature = "std")]
-use crate::std_facade::{HashMap, HashSet};
+use crate::std_facade::{hash_map::RandomState, HashMap, HashSet};
 
 use crate::bits::{BitSetLike, VarBitSet};
 use crate::num::sample_uniform_incl;
@@ -37,6 +41,13 @@ use crate::tuple::TupleValueTree;
 /// A value like `0..=std::usize::MAX` will still be accepted but will silently
 /// truncate the maximum to `std::usize::MAX - 1`.
 ///
+/// Sets and maps, such as those from `hash_set()` and `btree_map()`, count
+/// distinct elements or keys. Duplicates are redrawn until the chosen size
+/// is reached, giving up after four times as many duplicates as that size.
+/// The result is then smaller than chosen, but never smaller than the lower
+/// bound: if that many distinct values were not found, the whole collection
+/// is locally rejected and generated again.
+///
 /// The `Default` is `0..PROPTEST_MAX_DEFAULT_SIZE_RANGE`. The max can be set with
 /// the `PROPTEST_MAX_DEFAULT_SIZE_RANGE` env var, which defaults to `100`.
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
@@ -304,59 +315,182 @@ where
     BinaryHeapStrategy(statics::Map::new(vec(element, size), VecToBinHeap))
 }
 
-mapfn! {
-    {#[cfg(feature = "std")]}
-    [] fn VecToHashSet[<T : fmt::Debug + Hash + Eq>](vec: Vec<T>)
-                                                     -> HashSet<T> {
-        vec.into_iter().collect()
-    }
+/// Collects a `Vec` into a hash collection built with the hasher `S`.
+macro_rules! hasher_mapfn {
+    ($name:ident, [$($gen:tt)*]($input:ty) -> $output:ty) => {
+        #[cfg(feature = "std")]
+        struct $name<S>(PhantomData<fn() -> S>);
+
+        #[cfg(feature = "std")]
+        impl<S> $name<S> {
+            fn new() -> Self {
+                $name(PhantomData)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<S> Clone for $name<S> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<S> Copy for $name<S> {}
+
+        #[cfg(feature = "std")]
+        impl<S> fmt::Debug for $name<S> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(stringify!($name))
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<$($gen)*, S: BuildHasher + Default> statics::MapFn<$input>
+            for $name<S>
+        {
+            type Output = $output;
+            fn apply(&self, vec: $input) -> $output {
+                vec.into_iter().collect()
+            }
+        }
+    };
 }
 
+/// Implements `Clone` and `Debug` for a hash collection strategy or value
+/// tree, without requiring them of its hasher.
+macro_rules! hasher_wrapper_impls {
+    ($name:ident[$($gen:ident),*] where $($bound:tt)*) => {
+        #[cfg(feature = "std")]
+        impl<$($gen: Clone,)* S> Clone for $name<$($gen,)* S>
+        where
+            $($bound)*
+        {
+            fn clone(&self) -> Self {
+                $name(self.0.clone())
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<$($gen: fmt::Debug,)* S> fmt::Debug for $name<$($gen,)* S>
+        where
+            $($bound)*
+        {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.0).finish()
+            }
+        }
+    };
+}
+
+hasher_mapfn!(VecToHashSet, [T: fmt::Debug + Hash + Eq](Vec<T>)
+    -> HashSet<T, S>);
+
 #[derive(Debug, Clone, Copy)]
 struct MinSize(usize);
 
 #[cfg(feature = "std")]
-impl<T: Eq + Hash> statics::FilterFn<HashSet<T>> for MinSize {
-    fn apply(&self, set: &HashSet<T>) -> bool {
+impl<T: Eq + Hash, S: BuildHasher> statics::FilterFn<HashSet<T, S>>
+    for MinSize
+{
+    fn apply(&self, set: &HashSet<T, S>) -> bool {
         set.len() >= self.0
     }
 }
 
-opaque_strategy_wrapper! {
-    {#[cfg(feature = "std")]}
-    {#[cfg_attr(docsrs, doc(cfg(feature = "std")))]}
-    /// Strategy to create `HashSet`s with a length in a certain range.
-    ///
-    /// Created by the `hash_set()` function in the same module.
-    #[derive(Clone, Debug)]
-    pub struct HashSetStrategy[<T>][where T : Strategy, T::Value : Hash + Eq](
-        statics::Filter<statics::Map<VecStrategy<T>, VecToHashSet>, MinSize>)
-        -> HashSetValueTree<T::Tree>;
-    /// `ValueTree` corresponding to `HashSetStrategy`.
-    #[derive(Clone, Debug)]
-    pub struct HashSetValueTree[<T>][where T : ValueTree, T::Value : Hash + Eq](
-        statics::Filter<statics::Map<VecValueTree<T>, VecToHashSet>, MinSize>)
-        -> HashSet<T::Value>;
+/// Strategy to create `HashSet`s with a length in a certain range.
+///
+/// Created by the `hash_set()` and `hash_set_with_hasher()` functions in the
+/// same module.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[must_use = "strategies do nothing unless used"]
+pub struct HashSetStrategy<T, S = RandomState>(
+    statics::Filter<
+        statics::Map<DistinctVecStrategy<T, DistinctHash>, VecToHashSet<S>>,
+        MinSize,
+    >,
+)
+where
+    T: Strategy,
+    T::Value: Hash + Eq;
+
+/// `ValueTree` corresponding to `HashSetStrategy`.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct HashSetValueTree<T, S = RandomState>(
+    statics::Filter<statics::Map<VecValueTree<T>, VecToHashSet<S>>, MinSize>,
+)
+where
+    T: ValueTree,
+    T::Value: Hash + Eq;
+
+hasher_wrapper_impls!(HashSetStrategy[T]
+    where T: Strategy, T::Value: Hash + Eq);
+hasher_wrapper_impls!(HashSetValueTree[T]
+    where T: ValueTree, T::Value: Hash + Eq);
+
+#[cfg(feature = "std")]
+impl<T, S> Strategy for HashSetStrategy<T, S>
+where
+    T: Strategy,
+    T::Value: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    type Tree = HashSetValueTree<T::Tree, S>;
+    type Value = HashSet<T::Value, S>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        self.0.new_tree(runner).map(HashSetValueTree)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, S> ValueTree for HashSetValueTree<T, S>
+where
+    T: ValueTree,
+    T::Value: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    type Value = HashSet<T::Value, S>;
+
+    delegate_vt_0!();
 }
 
 /// Create a strategy to generate `HashSet`s containing elements drawn from
 /// `element` and with a size range given by `size`.
 ///
-/// This strategy will implicitly do local rejects to ensure that the `HashSet`
-/// has at least the minimum number of elements, in case `element` should
-/// produce duplicate values.
+/// Duplicate elements drawn from `element` are replaced by new ones until the
+/// chosen size is reached, as described for `SizeRange`.
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub fn hash_set<T: Strategy>(
     element: T,
     size: impl Into<SizeRange>,
 ) -> HashSetStrategy<T>
+where
+    T::Value: Hash + Eq,
+{
+    hash_set_with_hasher(element, size)
+}
+
+/// Like `hash_set()`, but generates `HashSet`s which use the hasher `S`,
+/// such as `BuildHasherDefault<FxHasher>`, created with `S::default()`.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn hash_set_with_hasher<T: Strategy, S: BuildHasher + Default>(
+    element: T,
+    size: impl Into<SizeRange>,
+) -> HashSetStrategy<T, S>
 where
     T::Value: Hash + Eq,
 {
     let size = size.into();
     HashSetStrategy(statics::Filter::new(
-        statics::Map::new(vec(element, size.clone()), VecToHashSet),
+        statics::Map::new(
+            distinct_vec(element, size.clone(), DistinctHash),
+            VecToHashSet::new(),
+        ),
         "HashSet minimum size".into(),
         MinSize(size.start()),
     ))
@@ -381,7 +515,8 @@ opaque_strategy_wrapper! {
     /// Created by the `btree_set()` function in the same module.
     #[derive(Clone, Debug)]
     pub struct BTreeSetStrategy[<T>][where T : Strategy, T::Value : Ord](
-        statics::Filter<statics::Map<VecStrategy<T>, VecToBTreeSet>, MinSize>)
+        statics::Filter<statics::Map<DistinctVecStrategy<T, DistinctOrd>,
+            VecToBTreeSet>, MinSize>)
         -> BTreeSetValueTree<T::Tree>;
     /// `ValueTree` corresponding to `BTreeSetStrategy`.
     #[derive(Clone, Debug)]
@@ -393,9 +528,8 @@ opaque_strategy_wrapper! {
 /// Create a strategy to generate `BTreeSet`s containing elements drawn from
 /// `element` and with a size range given by `size`.
 ///
-/// This strategy will implicitly do local rejects to ensure that the
-/// `BTreeSet` has at least the minimum number of elements, in case `element`
-/// should produce duplicate values.
+/// Duplicate elements drawn from `element` are replaced by new ones until the
+/// chosen size is reached, as described for `SizeRange`.
 pub fn btree_set<T: Strategy>(
     element: T,
     size: impl Into<SizeRange>,
@@ -405,56 +539,103 @@ where
 {
     let size = size.into();
     BTreeSetStrategy(statics::Filter::new(
-        statics::Map::new(vec(element, size.clone()), VecToBTreeSet),
+        statics::Map::new(
+            distinct_vec(element, size.clone(), DistinctOrd),
+            VecToBTreeSet,
+        ),
         "BTreeSet minimum size".into(),
         MinSize(size.start()),
     ))
 }
 
-mapfn! {
-    {#[cfg(feature = "std")]}
-    [] fn VecToHashMap[<K : fmt::Debug + Hash + Eq, V : fmt::Debug>]
-        (vec: Vec<(K, V)>) -> HashMap<K, V>
-    {
-        vec.into_iter().collect()
+hasher_mapfn!(VecToHashMap,
+    [K: fmt::Debug + Hash + Eq, V: fmt::Debug](Vec<(K, V)>)
+    -> HashMap<K, V, S>);
+
+#[cfg(feature = "std")]
+impl<K: Hash + Eq, V, S: BuildHasher> statics::FilterFn<HashMap<K, V, S>>
+    for MinSize
+{
+    fn apply(&self, map: &HashMap<K, V, S>) -> bool {
+        map.len() >= self.0
     }
 }
 
+/// Strategy to create `HashMap`s with a length in a certain range.
+///
+/// Created by the `hash_map()` and `hash_map_with_hasher()` functions in the
+/// same module.
 #[cfg(feature = "std")]
-impl<K: Hash + Eq, V> statics::FilterFn<HashMap<K, V>> for MinSize {
-    fn apply(&self, map: &HashMap<K, V>) -> bool {
-        map.len() >= self.0
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[must_use = "strategies do nothing unless used"]
+pub struct HashMapStrategy<K, V, S = RandomState>(
+    statics::Filter<
+        statics::Map<
+            DistinctVecStrategy<(K, V), DistinctHashKeys>,
+            VecToHashMap<S>,
+        >,
+        MinSize,
+    >,
+)
+where
+    K: Strategy,
+    V: Strategy,
+    K::Value: Hash + Eq;
+
+/// `ValueTree` corresponding to `HashMapStrategy`.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct HashMapValueTree<K, V, S = RandomState>(
+    statics::Filter<
+        statics::Map<VecValueTree<TupleValueTree<(K, V)>>, VecToHashMap<S>>,
+        MinSize,
+    >,
+)
+where
+    K: ValueTree,
+    V: ValueTree,
+    K::Value: Hash + Eq;
+
+hasher_wrapper_impls!(HashMapStrategy[K, V]
+    where K: Strategy, V: Strategy, K::Value: Hash + Eq);
+hasher_wrapper_impls!(HashMapValueTree[K, V]
+    where K: ValueTree, V: ValueTree, K::Value: Hash + Eq);
+
+#[cfg(feature = "std")]
+impl<K, V, S> Strategy for HashMapStrategy<K, V, S>
+where
+    K: Strategy,
+    V: Strategy,
+    K::Value: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    type Tree = HashMapValueTree<K::Tree, V::Tree, S>;
+    type Value = HashMap<K::Value, V::Value, S>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        self.0.new_tree(runner).map(HashMapValueTree)
     }
 }
 
-opaque_strategy_wrapper! {
-    {#[cfg(feature = "std")]}
-    {#[cfg_attr(docsrs, doc(cfg(feature = "std")))]}
-    /// Strategy to create `HashMap`s with a length in a certain range.
-    ///
-    /// Created by the `hash_map()` function in the same module.
-    #[derive(Clone, Debug)]
-    pub struct HashMapStrategy[<K, V>]
-        [where K : Strategy, V : Strategy, K::Value : Hash + Eq](
-            statics::Filter<statics::Map<VecStrategy<(K,V)>,
-            VecToHashMap>, MinSize>)
-        -> HashMapValueTree<K::Tree, V::Tree>;
-    /// `ValueTree` corresponding to `HashMapStrategy`.
-    #[derive(Clone, Debug)]
-    pub struct HashMapValueTree[<K, V>]
-        [where K : ValueTree, V : ValueTree, K::Value : Hash + Eq](
-            statics::Filter<statics::Map<VecValueTree<TupleValueTree<(K, V)>>,
-            VecToHashMap>, MinSize>)
-        -> HashMap<K::Value, V::Value>;
+#[cfg(feature = "std")]
+impl<K, V, S> ValueTree for HashMapValueTree<K, V, S>
+where
+    K: ValueTree,
+    V: ValueTree,
+    K::Value: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    type Value = HashMap<K::Value, V::Value, S>;
+
+    delegate_vt_0!();
 }
 
 /// Create a strategy to generate `HashMap`s containing keys and values drawn
 /// from `key` and `value` respectively, and with a size within the given
 /// range.
 ///
-/// This strategy will implicitly do local rejects to ensure that the `HashMap`
-/// has at least the minimum number of elements, in case `key` should produce
-/// duplicate values.
+/// Duplicate keys drawn from `key` are replaced by new ones until the
+/// chosen size is reached, as described for `SizeRange`.
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub fn hash_map<K: Strategy, V: Strategy>(
@@ -462,12 +643,34 @@ pub fn hash_map<K: Strategy, V: Strategy>(
     value: V,
     size: impl Into<SizeRange>,
 ) -> HashMapStrategy<K, V>
+where
+    K::Value: Hash + Eq,
+{
+    hash_map_with_hasher(key, value, size)
+}
+
+/// Like `hash_map()`, but generates `HashMap`s which use the hasher `S`,
+/// such as `BuildHasherDefault<FxHasher>`, created with `S::default()`.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn hash_map_with_hasher<
+    K: Strategy,
+    V: Strategy,
+    S: BuildHasher + Default,
+>(
+    key: K,
+    value: V,
+    size: impl Into<SizeRange>,
+) -> HashMapStrategy<K, V, S>
 where
     K::Value: Hash + Eq,
 {
     let size = size.into();
     HashMapStrategy(statics::Filter::new(
-        statics::Map::new(vec((key, value), size.clone()), VecToHashMap),
+        statics::Map::new(
+            distinct_vec((key, value), size.clone(), DistinctHashKeys),
+            VecToHashMap::new(),
+        ),
         "HashMap minimum size".into(),
         MinSize(size.start()),
     ))
@@ -494,7 +697,8 @@ opaque_strategy_wrapper! {
     #[derive(Clone, Debug)]
     pub struct BTreeMapStrategy[<K, V>]
         [where K : Strategy, V : Strategy, K::Value : Ord](
-            statics::Filter<statics::Map<VecStrategy<(K,V)>,
+            statics::Filter<statics::Map<
+            DistinctVecStrategy<(K, V), DistinctOrdKeys>,
             VecToBTreeMap>, MinSize>)
         -> BTreeMapValueTree<K::Tree, V::Tree>;
     /// `ValueTree` corresponding to `BTreeMapStrategy`.
@@ -510,9 +714,8 @@ opaque_strategy_wrapper! {
 /// from `key` and `value` respectively, and with a size within the given
 /// range.
 ///
-/// This strategy will implicitly do local rejects to ensure that the
-/// `BTreeMap` has at least the minimum number of elements, in case `key`
-/// should produce duplicate values.
+/// Duplicate keys drawn from `key` are replaced by new ones until the
+/// chosen size is reached, as described for `SizeRange`.
 pub fn btree_map<K: Strategy, V: Strategy>(
     key: K,
     value: V,
@@ -523,12 +726,126 @@ where
 {
     let size = size.into();
     BTreeMapStrategy(statics::Filter::new(
-        statics::Map::new(vec((key, value), size.clone()), VecToBTreeMap),
+        statics::Map::new(
+            distinct_vec((key, value), size.clone(), DistinctOrdKeys),
+            VecToBTreeMap,
+        ),
         "BTreeMap minimum size".into(),
         MinSize(size.start()),
     ))
 }
 
+opaque_strategy_wrapper! {
+    /// Strategy to create `Vec`s of distinct elements with a length in a
+    /// certain range.
+    ///
+    /// Created by the `vec_unique()` function in the same module.
+    #[derive(Clone, Debug)]
+    pub struct VecUniqueStrategy[<T>][where T : Strategy, T::Value : PartialEq](
+        statics::Filter<DistinctVecStrategy<T, DistinctEq>, DistinctEq>)
+        -> VecUniqueValueTree<T::Tree>;
+    /// `ValueTree` corresponding to `VecUniqueStrategy`.
+    #[derive(Clone, Debug)]
+    pub struct VecUniqueValueTree[<T>][where T : ValueTree, T::Value : PartialEq](
+        statics::Filter<VecValueTree<T>, DistinctEq>)
+        -> Vec<T::Value>;
+}
+
+/// Create a strategy to generate `Vec`s of distinct elements drawn from
+/// `element`, with a size range given by `size`.
+///
+/// Elements only need to be `PartialEq`, not `Hash` or `Ord`, and keep the
+/// order in which they were generated. Duplicates are redrawn as described
+/// for `SizeRange`, and shrinking never makes two elements equal.
+///
+/// Checking for duplicates takes time quadratic in the length, so for long
+/// `Vec`s of hashable elements, `hash_set()` is faster.
+pub fn vec_unique<T: Strategy>(
+    element: T,
+    size: impl Into<SizeRange>,
+) -> VecUniqueStrategy<T>
+where
+    T::Value: PartialEq,
+{
+    VecUniqueStrategy(statics::Filter::new(
+        distinct_vec(element, size, DistinctEq),
+        "Vec elements distinct".into(),
+        DistinctEq,
+    ))
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy to create `Vec`s of elements with distinct keys and a length
+    /// in a certain range.
+    ///
+    /// Created by the `vec_unique_by_key()` function in the same module.
+    #[derive(Clone, Debug)]
+    pub struct VecUniqueByKeyStrategy[<T, K>]
+        [where T : Strategy, K : PartialEq + fmt::Debug](
+        statics::Filter<DistinctVecStrategy<T, DistinctByKey<T::Value, K>>,
+            DistinctByKey<T::Value, K>>)
+        -> VecUniqueByKeyValueTree<T::Tree, K>;
+    /// `ValueTree` corresponding to `VecUniqueByKeyStrategy`.
+    #[derive(Clone, Debug)]
+    pub struct VecUniqueByKeyValueTree[<T, K>]
+        [where T : ValueTree, K : PartialEq + fmt::Debug](
+        statics::Filter<VecValueTree<T>, DistinctByKey<T::Value, K>>)
+        -> Vec<T::Value>;
+}
+
+/// Like `vec_unique()`, but elements are distinct when `key` maps them to
+/// distinct values, such as `|user: &User| user.id`.
+pub fn vec_unique_by_key<T: Strategy, K: PartialEq + fmt::Debug>(
+    element: T,
+    size: impl Into<SizeRange>,
+    key: fn(&T::Value) -> K,
+) -> VecUniqueByKeyStrategy<T, K> {
+    VecUniqueByKeyStrategy(statics::Filter::new(
+        distinct_vec(element, size, DistinctByKey(key)),
+        "Vec element keys distinct".into(),
+        DistinctByKey(key),
+    ))
+}
+
+mapfn! {
+    [] fn VecToSorted[<T : fmt::Debug + Ord>](vec: Vec<T>) -> Vec<T> {
+        let mut vec = vec;
+        vec.sort();
+        vec
+    }
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy to create sorted `Vec`s with a length in a certain range.
+    ///
+    /// Created by the `sorted_vec()` function in the same module.
+    #[derive(Clone, Debug)]
+    pub struct SortedVecStrategy[<T>][where T : Strategy, T::Value : Ord](
+        statics::Map<VecStrategy<T>, VecToSorted>)
+        -> SortedVecValueTree<T::Tree>;
+    /// `ValueTree` corresponding to `SortedVecStrategy`.
+    #[derive(Clone, Debug)]
+    pub struct SortedVecValueTree[<T>][where T : ValueTree, T::Value : Ord](
+        statics::Map<VecValueTree<T>, VecToSorted>)
+        -> Vec<T::Value>;
+}
+
+/// Create a strategy to generate `Vec`s sorted in ascending order,
+/// containing elements drawn from `element` and with a size range given by
+/// `size`.
+///
+/// The `Vec` is sorted after each shrinking step, so elements shrink
+/// individually as they would with `vec()`. Duplicates are allowed.
+pub fn sorted_vec<T: Strategy>(
+    element: T,
+    size: impl Into<SizeRange>,
+) -> SortedVecStrategy<T>
+where
+    T::Value: Ord,
+{
+    SortedVecStrategy(statics::Map::new(vec(element, size), VecToSorted))
+}
+
 #[derive(Clone, Copy, Debug)]
 enum Shrink {
     DeleteElement(usize),
@@ -588,6 +905,192 @@ impl<T: Strategy> Strategy for Vec<T> {
     }
 }
 
+/// The number of duplicates `DistinctVecStrategy` draws, per element of the
+/// chosen size, before settling for a smaller collection.
+const MAX_DUPLICATES_PER_ELEMENT: usize = 4;
+
+/// How `DistinctVecStrategy` tells whether a value duplicates an earlier
+/// one.
+trait Distinct<T> {
+    type Seen: Default;
+
+    /// Records `value` in `seen`, returning whether it was new.
+    fn insert(&self, seen: &mut Self::Seen, value: T) -> bool;
+}
+
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug)]
+struct DistinctHash;
+
+#[cfg(feature = "std")]
+impl<T: Hash + Eq> Distinct<T> for DistinctHash {
+    type Seen = HashSet<T>;
+
+    fn insert(&self, seen: &mut HashSet<T>, value: T) -> bool {
+        seen.insert(value)
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug)]
+struct DistinctHashKeys;
+
+#[cfg(feature = "std")]
+impl<K: Hash + Eq, V> Distinct<(K, V)> for DistinctHashKeys {
+    type Seen = HashSet<K>;
+
+    fn insert(&self, seen: &mut HashSet<K>, (key, _): (K, V)) -> bool {
+        seen.insert(key)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct DistinctOrd;
+
+impl<T: Ord> Distinct<T> for DistinctOrd {
+    type Seen = BTreeSet<T>;
+
+    fn insert(&self, seen: &mut BTreeSet<T>, value: T) -> bool {
+        seen.insert(value)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct DistinctOrdKeys;
+
+impl<K: Ord, V> Distinct<(K, V)> for DistinctOrdKeys {
+    type Seen = BTreeSet<K>;
+
+    fn insert(&self, seen: &mut BTreeSet<K>, (key, _): (K, V)) -> bool {
+        seen.insert(key)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct DistinctEq;
+
+impl<T: PartialEq> Distinct<T> for DistinctEq {
+    type Seen = Vec<T>;
+
+    fn insert(&self, seen: &mut Vec<T>, value: T) -> bool {
+        if seen.contains(&value) {
+            false
+        } else {
+            seen.push(value);
+            true
+        }
+    }
+}
+
+impl<T: PartialEq> statics::FilterFn<Vec<T>> for DistinctEq {
+    fn apply(&self, vec: &Vec<T>) -> bool {
+        all_distinct(vec)
+    }
+}
+
+struct DistinctByKey<T, K>(fn(&T) -> K);
+
+impl<T, K> Clone for DistinctByKey<T, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, K> Copy for DistinctByKey<T, K> {}
+
+impl<T, K> fmt::Debug for DistinctByKey<T, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("DistinctByKey")
+    }
+}
+
+impl<T, K: PartialEq> Distinct<T> for DistinctByKey<T, K> {
+    type Seen = Vec<K>;
+
+    fn insert(&self, seen: &mut Vec<K>, value: T) -> bool {
+        DistinctEq.insert(seen, (self.0)(&value))
+    }
+}
+
+impl<T, K: PartialEq> statics::FilterFn<Vec<T>> for DistinctByKey<T, K> {
+    fn apply(&self, vec: &Vec<T>) -> bool {
+        all_distinct(&vec.iter().map(self.0).collect::<Vec<_>>())
+    }
+}
+
+fn all_distinct<T: PartialEq>(values: &[T]) -> bool {
+    values
+        .iter()
+        .enumerate()
+        .all(|(ix, value)| !values[..ix].contains(value))
+}
+
+/// Strategy to create `Vec`s of distinct elements, used to build sets and
+/// maps of the requested size.
+///
+/// Elements which duplicate earlier ones are redrawn. If too many are
+/// duplicates, the `Vec` is kept as long as it meets the minimum size, and
+/// otherwise the whole `Vec` is locally rejected.
+#[derive(Clone, Debug)]
+struct DistinctVecStrategy<T, D> {
+    element: T,
+    size: SizeRange,
+    distinct: D,
+}
+
+fn distinct_vec<T: Strategy, D: Distinct<T::Value>>(
+    element: T,
+    size: impl Into<SizeRange>,
+    distinct: D,
+) -> DistinctVecStrategy<T, D> {
+    let size = size.into();
+    size.assert_nonempty();
+    DistinctVecStrategy {
+        element,
+        size,
+        distinct,
+    }
+}
+
+impl<T, D> Strategy for DistinctVecStrategy<T, D>
+where
+    T: Strategy,
+    D: Distinct<T::Value> + Clone + fmt::Debug,
+{
+    type Tree = VecValueTree<T::Tree>;
+    type Value = Vec<T::Value>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let (start, end) = self.size.start_end_incl();
+        loop {
+            let max_size = sample_uniform_incl(runner, start, end);
+            let max_duplicates = max_size * MAX_DUPLICATES_PER_ELEMENT;
+            let mut seen = D::Seen::default();
+            let mut elements = Vec::with_capacity(max_size);
+            let mut duplicates = 0;
+            while elements.len() < max_size && duplicates <= max_duplicates {
+                let element = self.element.new_tree(runner)?;
+                if self.distinct.insert(&mut seen, element.current()) {
+                    elements.push(element);
+                } else {
+                    duplicates += 1;
+                }
+            }
+
+            if elements.len() >= start {
+                return Ok(VecValueTree {
+                    included_elements: VarBitSet::saturated(elements.len()),
+                    elements,
+                    min_size: start,
+                    shrink: Shrink::DeleteElement(0),
+                    prev_shrink: None,
+                });
+            }
+            runner.reject_local("too many duplicate elements")?;
+        }
+    }
+}
+
 impl<T: ValueTree> ValueTree for VecValueTree<T> {
     type Value = Vec<T::Value>;
 
@@ -776,4 +1279,117 @@ mod test {
             assert_eq!(2, v.len());
         }
     }
+
+    #[test]
+    fn test_sets_and_maps_reach_chosen_size() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..256 {
+            // Only 10 possible elements, so collisions are common.
+            assert_eq!(
+                10,
+                btree_set(0..10u8, 10)
+                    .new_tree(&mut runner)
+                    .unwrap()
+                    .current()
+                    .len()
+            );
+            assert_eq!(
+                10,
+                btree_map(0..10u8, 0..1u8, 10)
+                    .new_tree(&mut runner)
+                    .unwrap()
+                    .current()
+                    .len()
+            );
+            #[cfg(feature = "std")]
+            assert_eq!(
+                10,
+                hash_set(0..10u8, 10)
+                    .new_tree(&mut runner)
+                    .unwrap()
+                    .current()
+                    .len()
+            );
+
+            // Only 3 possible elements, so larger sizes cannot be reached.
+            let len = btree_set(0..3u8, 2..6)
+                .new_tree(&mut runner)
+                .unwrap()
+                .current()
+                .len();
+            assert!((2..4).contains(&len), "{}", len);
+        }
+    }
+
+    #[test]
+    fn test_vec_unique() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..256 {
+            let mut tree =
+                vec_unique(0..10u8, 5..10).new_tree(&mut runner).unwrap();
+            loop {
+                let vec = tree.current();
+                assert!((5..10).contains(&vec.len()));
+                assert!(all_distinct(&vec), "{:?}", vec);
+                if !tree.simplify() {
+                    break;
+                }
+            }
+        }
+        let minimal = vec_unique(0..10u8, 5..10).minimal(&mut runner);
+        assert_eq!(5, minimal.len());
+        assert!(all_distinct(&minimal), "{:?}", minimal);
+
+        let pairs = vec_unique_by_key((0..4u8, 0..4u8), 4, |&(key, _)| key)
+            .sample(&mut runner);
+        assert!(all_distinct(&pairs.iter().map(|p| p.0).collect::<Vec<_>>()));
+    }
+
+    #[test]
+    fn test_sorted_vec() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..256 {
+            let mut tree =
+                sorted_vec(0..100i32, 0..10).new_tree(&mut runner).unwrap();
+            loop {
+                let vec = tree.current();
+                assert!(vec.windows(2).all(|w| w[0] <= w[1]), "{:?}", vec);
+                if !tree.simplify() {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_custom_hasher() {
+        use std::collections::hash_map::DefaultHasher;
+
+        use crate::arbitrary::any_with;
+
+        // Neither `Clone` nor `Debug`.
+        #[derive(Default)]
+        struct Hasher;
+
+        impl BuildHasher for Hasher {
+            type Hasher = DefaultHasher;
+
+            fn build_hasher(&self) -> DefaultHasher {
+                DefaultHasher::new()
+            }
+        }
+
+        let mut runner = TestRunner::deterministic();
+        let set = hash_set_with_hasher::<_, Hasher>("[ab]{3}", 2..3);
+        let map = any_with::<HashMap<u8, u8, Hasher>>(((1..4).into(), (), ()));
+        for _ in 0..256 {
+            assert_eq!(
+                2,
+                set.clone().new_tree(&mut runner).unwrap().current().len()
+            );
+            let len = map.new_tree(&mut runner).unwrap().current().len();
+            assert!((1..4).contains(&len), "{}", len);
+        }
+    }
 }
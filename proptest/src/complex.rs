@@ -0,0 +1,338 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating `num_complex::Complex` values.
+//!
+//! Complex numbers can be generated in rectangular form, from ranges of their
+//! real and imaginary parts, or in polar form, from ranges of their modulus
+//! and argument. The polar strategies give control over the distribution of
+//! values which a pair of independent parts can't, such as putting every
+//! value on the unit circle, or spreading values uniformly over a disk.
+//!
+//! The strategies for `Complex<f32>` and `Complex<f64>` are in the `f32` and
+//! `f64` modules respectively. All of them shrink each part, or the modulus
+//! and argument, towards zero.
+//!
+//! ## Example
+//!
+//! ```
+//! use proptest::complex::f64::{disk, unit_circle};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     # /*
+//!     #[test]
+//!     # */
+//!     fn phase_factors_keep_modulus(z in disk(10.0), phase in unit_circle()) {
+//!         let rotated = z * phase;
+//!         prop_assert!((rotated.norm_sqr() - z.norm_sqr()).abs() < 1e-9);
+//!     }
+//! }
+//! #
+//! # fn main() { phase_factors_keep_modulus(); }
+//! ```
+
+macro_rules! complex_strategies {
+    ($typ:ident) => {
+        #[doc = concat!(
+            "Strategies for generating `Complex<", stringify!($typ), ">` values."
+        )]
+        pub mod $typ {
+            use core::$typ::consts::PI;
+            use core::ops::RangeInclusive;
+
+            use ::num_complex::Complex;
+            #[cfg(not(feature = "std"))]
+            use num_traits::float::Float;
+
+            use crate::num;
+            use crate::strategy::*;
+            use crate::test_runner::*;
+            use crate::tuple::TupleValueTree;
+
+            mapfn! {
+                [] fn FromParts[](parts: ($typ, $typ)) -> Complex<$typ> {
+                    Complex::new(parts.0, parts.1)
+                }
+            }
+
+            /// Turns a modulus, or its square, and an argument into a
+            /// complex number.
+            #[derive(Clone, Copy, Debug)]
+            struct FromPolar {
+                squared: bool,
+            }
+
+            impl statics::MapFn<($typ, $typ)> for FromPolar {
+                type Output = Complex<$typ>;
+
+                fn apply(
+                    &self,
+                    (modulus, argument): ($typ, $typ),
+                ) -> Complex<$typ> {
+                    let modulus = if self.squared {
+                        modulus.sqrt()
+                    } else {
+                        modulus
+                    };
+                    Complex::new(
+                        modulus * argument.cos(),
+                        modulus * argument.sin(),
+                    )
+                }
+            }
+
+            /// Turns an argument into a complex number on a circle around
+            /// the origin.
+            #[derive(Clone, Copy, Debug)]
+            struct OnCircle {
+                radius: $typ,
+            }
+
+            impl statics::MapFn<$typ> for OnCircle {
+                type Output = Complex<$typ>;
+
+                fn apply(&self, argument: $typ) -> Complex<$typ> {
+                    Complex::new(
+                        self.radius * argument.cos(),
+                        self.radius * argument.sin(),
+                    )
+                }
+            }
+
+            opaque_strategy_wrapper! {
+                /// Strategy which generates complex numbers from ranges of
+                /// their real and imaginary parts.
+                ///
+                /// Created by `rectangular()`.
+                #[derive(Clone, Debug)]
+                pub struct RectangularStrategy[][](
+                    statics::Map<(RangeInclusive<$typ>, RangeInclusive<$typ>),
+                                 FromParts>)
+                    -> RectangularValueTree;
+                /// `ValueTree` corresponding to `RectangularStrategy`.
+                #[derive(Clone, Debug)]
+                pub struct RectangularValueTree[][](
+                    statics::Map<TupleValueTree<(num::$typ::BinarySearch,
+                                                 num::$typ::BinarySearch)>,
+                                 FromParts>)
+                    -> Complex<$typ>;
+            }
+
+            opaque_strategy_wrapper! {
+                /// Strategy which generates complex numbers from ranges of
+                /// their modulus and argument.
+                ///
+                /// Created by `polar()` and `disk()`.
+                #[derive(Clone, Debug)]
+                pub struct PolarStrategy[][](
+                    statics::Map<(RangeInclusive<$typ>, RangeInclusive<$typ>),
+                                 FromPolar>)
+                    -> PolarValueTree;
+                /// `ValueTree` corresponding to `PolarStrategy`.
+                #[derive(Clone, Debug)]
+                pub struct PolarValueTree[][](
+                    statics::Map<TupleValueTree<(num::$typ::BinarySearch,
+                                                 num::$typ::BinarySearch)>,
+                                 FromPolar>)
+                    -> Complex<$typ>;
+            }
+
+            opaque_strategy_wrapper! {
+                /// Strategy which generates complex numbers on a circle
+                /// around the origin.
+                ///
+                /// Created by `circle()` and `unit_circle()`.
+                #[derive(Clone, Debug)]
+                pub struct CircleStrategy[][](
+                    statics::Map<RangeInclusive<$typ>, OnCircle>)
+                    -> CircleValueTree;
+                /// `ValueTree` corresponding to `CircleStrategy`.
+                #[derive(Clone, Debug)]
+                pub struct CircleValueTree[][](
+                    statics::Map<num::$typ::BinarySearch, OnCircle>)
+                    -> Complex<$typ>;
+            }
+
+            /// Create a strategy for complex numbers whose real part is
+            /// chosen uniformly from `re` and whose imaginary part is chosen
+            /// uniformly from `im`, independently.
+            pub fn rectangular(
+                re: RangeInclusive<$typ>,
+                im: RangeInclusive<$typ>,
+            ) -> RectangularStrategy {
+                RectangularStrategy(statics::Map::new((re, im), FromParts))
+            }
+
+            /// Create a strategy for complex numbers whose modulus is chosen
+            /// uniformly from `modulus` and whose argument, in radians, is
+            /// chosen uniformly from `argument`.
+            ///
+            /// Note that a uniform modulus puts as many values near the
+            /// origin as far from it; use `disk()` to spread values evenly
+            /// over an area instead, or `circle()` for a fixed modulus.
+            ///
+            /// ## Panics
+            ///
+            /// Panics if `modulus` includes negative numbers, or is a single
+            /// value.
+            pub fn polar(
+                modulus: RangeInclusive<$typ>,
+                argument: RangeInclusive<$typ>,
+            ) -> PolarStrategy {
+                assert!(
+                    *modulus.start() >= 0.0,
+                    "Complex modulus must not be negative"
+                );
+
+                PolarStrategy(statics::Map::new(
+                    (modulus, argument),
+                    FromPolar { squared: false },
+                ))
+            }
+
+            /// Create a strategy for complex numbers on the circle of the
+            /// given `radius` around the origin, that is with a modulus of
+            /// `radius` and any argument.
+            ///
+            /// Values shrink towards `radius` on the positive real axis.
+            ///
+            /// ## Panics
+            ///
+            /// Panics if `radius` is negative or not finite.
+            pub fn circle(radius: $typ) -> CircleStrategy {
+                assert!(
+                    radius >= 0.0 && radius.is_finite(),
+                    "Circle radius must be finite and not negative"
+                );
+
+                CircleStrategy(statics::Map::new(
+                    -PI..=PI,
+                    OnCircle { radius },
+                ))
+            }
+
+            /// Create a strategy for complex numbers on the unit circle, such
+            /// as phase factors and normalised signals.
+            ///
+            /// This is `circle(1.0)`.
+            pub fn unit_circle() -> CircleStrategy {
+                circle(1.0)
+            }
+
+            /// Create a strategy for complex numbers distributed uniformly
+            /// over the disk of the given `radius` around the origin,
+            /// including its boundary.
+            ///
+            /// ## Panics
+            ///
+            /// Panics if `radius` is not positive and finite.
+            pub fn disk(radius: $typ) -> PolarStrategy {
+                assert!(
+                    radius > 0.0 && radius.is_finite(),
+                    "Disk radius must be positive and finite"
+                );
+
+                // The area within a modulus grows with its square, so
+                // choosing the square uniformly spreads values evenly.
+                PolarStrategy(statics::Map::new(
+                    (0.0..=radius * radius, -PI..=PI),
+                    FromPolar { squared: true },
+                ))
+            }
+        }
+    };
+}
+
+complex_strategies!(f32);
+complex_strategies!(f64);
+
+#[cfg(test)]
+mod test {
+    use crate::strategy::*;
+    use crate::test_runner::*;
+
+    use ::num_complex::Complex;
+
+    #[test]
+    fn rectangular_parts_are_within_ranges() {
+        let mut runner = TestRunner::deterministic();
+        let input = super::f64::rectangular(-1.0..=2.0, 3.0..=4.0);
+        for _ in 0..256 {
+            let value = input.new_tree(&mut runner).unwrap().current();
+            assert!((-1.0..=2.0).contains(&value.re), "{}", value);
+            assert!((3.0..=4.0).contains(&value.im), "{}", value);
+        }
+        assert_eq!(Complex::new(0.0, 3.0), input.minimal(&mut runner));
+    }
+
+    #[test]
+    fn unit_circle_values_have_modulus_one() {
+        let mut runner = TestRunner::deterministic();
+        let mut quadrants = [0; 4];
+        for _ in 0..256 {
+            let value = super::f64::unit_circle()
+                .new_tree(&mut runner)
+                .unwrap()
+                .current();
+            assert!((value.re.hypot(value.im) - 1.0).abs() < 1e-12);
+            let quadrant = (value.re < 0.0, value.im < 0.0);
+            quadrants[quadrant.0 as usize * 2 + quadrant.1 as usize] += 1;
+
+            let value = super::f32::unit_circle()
+                .new_tree(&mut runner)
+                .unwrap()
+                .current();
+            assert!((value.re.hypot(value.im) - 1.0).abs() < 1e-6);
+        }
+        assert!(quadrants.iter().all(|&n| n > 32), "{:?}", quadrants);
+        assert_eq!(
+            Complex::new(1.0, 0.0),
+            super::f64::unit_circle().minimal(&mut runner)
+        );
+    }
+
+    #[test]
+    fn disk_values_are_spread_over_area() {
+        let mut runner = TestRunner::deterministic();
+        let input = super::f64::disk(2.0);
+        let mut inner = 0;
+        for _ in 0..1024 {
+            let value = input.new_tree(&mut runner).unwrap().current();
+            let modulus = value.re.hypot(value.im);
+            assert!(modulus <= 2.0 + 1e-12, "{}", value);
+            inner += (modulus < 1.0) as u32;
+        }
+        // A quarter of the area of the disk is within half its radius.
+        assert!(inner > 192 && inner < 320, "{}", inner);
+        assert_eq!(Complex::new(0.0, 0.0), input.minimal(&mut runner));
+    }
+
+    #[test]
+    fn polar_values_are_within_ranges() {
+        let mut runner = TestRunner::deterministic();
+        let input = super::f32::polar(1.0..=3.0, 0.0..=1.0);
+        for _ in 0..256 {
+            let value = input.new_tree(&mut runner).unwrap().current();
+            let (modulus, argument) =
+                (value.re.hypot(value.im), value.im.atan2(value.re));
+            assert!((0.99999..=3.00001).contains(&modulus), "{}", modulus);
+            assert!((0.0..=1.000001).contains(&argument), "{}", argument);
+        }
+    }
+
+    #[test]
+    fn complex_sanity() {
+        check_strategy_sanity(
+            super::f64::rectangular(-1.0..=1.0, -1.0..=1.0),
+            None,
+        );
+        check_strategy_sanity(super::f32::disk(1.0), None);
+    }
+}
@@ -0,0 +1,255 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating thread interleavings.
+//!
+//! Real threads are scheduled by the operating system, so a failing
+//! interleaving can neither be reproduced nor shrunk. Instead, the code under
+//! test is split into simulated threads, each of which is run one step at a
+//! time by `Schedule::run()` in the order chosen by a generated `Schedule`.
+//! Schedules shrink towards fewer context switches, so a minimal failing case
+//! shows the fewest preemptions needed to trigger the bug.
+
+use core::ops::{Range, RangeInclusive};
+
+use crate::collection::{vec, VecStrategy, VecValueTree};
+use crate::num;
+use crate::std_facade::Vec;
+use crate::strategy::*;
+use crate::test_runner::*;
+use crate::tuple::TupleValueTree;
+
+/// An order in which to interleave the steps of a number of simulated
+/// threads.
+///
+/// A schedule is a sequence of runs, each of which gives a thread and the
+/// number of steps to run it for before switching to the next run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Schedule {
+    threads: usize,
+    runs: Vec<(usize, usize)>,
+}
+
+impl Schedule {
+    /// Return the number of threads this schedule interleaves.
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// Return the `(thread, steps)` runs making up this schedule.
+    pub fn runs(&self) -> &[(usize, usize)] {
+        &self.runs
+    }
+
+    /// Return the number of times this schedule switches from one thread to
+    /// another, not counting switches made because a thread finished.
+    pub fn preemptions(&self) -> usize {
+        self.runs
+            .windows(2)
+            .filter(|pair| pair[0].0 != pair[1].0)
+            .count()
+    }
+
+    /// Run simulated threads according to this schedule until all of them
+    /// have finished.
+    ///
+    /// `step(thread)` should run the next step of `thread`, which is between
+    /// 0 and `threads() - 1`, and return whether that thread has more steps
+    /// to run. Every thread is assumed to have at least one step.
+    ///
+    /// If the thread chosen for a run has already finished, the next
+    /// unfinished thread is run instead. Once the schedule is used up, the
+    /// remaining threads are run to completion one after another, without
+    /// further preemption.
+    pub fn run(&self, mut step: impl FnMut(usize) -> bool) {
+        let mut finished = vec![false; self.threads];
+        let mut remaining = self.threads;
+        let mut current = 0;
+
+        let next_unfinished = |finished: &[bool], from: usize| {
+            (0..finished.len())
+                .map(|offset| (from + offset) % finished.len())
+                .find(|&thread| !finished[thread])
+        };
+
+        for &(thread, steps) in &self.runs {
+            current = match next_unfinished(&finished, thread) {
+                Some(thread) => thread,
+                None => return,
+            };
+
+            for _ in 0..steps {
+                if !step(current) {
+                    finished[current] = true;
+                    remaining -= 1;
+                    break;
+                }
+            }
+        }
+
+        while remaining > 0 {
+            current = match next_unfinished(&finished, current) {
+                Some(thread) => thread,
+                None => return,
+            };
+            while step(current) {}
+            finished[current] = true;
+            remaining -= 1;
+        }
+    }
+}
+
+mapfn! {
+    [] fn ToSchedule[](args: (usize, Vec<(usize, usize)>)) -> Schedule {
+        Schedule {
+            threads: args.0,
+            runs: args.1,
+        }
+    }
+}
+
+type RunsStrategy = VecStrategy<(Range<usize>, RangeInclusive<usize>)>;
+type RunsValueTree = VecValueTree<
+    TupleValueTree<(num::usize::BinarySearch, num::usize::BinarySearch)>,
+>;
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `Schedule`s.
+    ///
+    /// Created by `schedule()`.
+    #[derive(Clone, Debug)]
+    pub struct ScheduleStrategy[][](
+        statics::Map<(Just<usize>, RunsStrategy), ToSchedule>)
+        -> ScheduleValueTree;
+    /// `ValueTree` corresponding to `ScheduleStrategy`.
+    #[derive(Clone, Debug)]
+    pub struct ScheduleValueTree[][](
+        statics::Map<TupleValueTree<(Just<usize>, RunsValueTree)>, ToSchedule>)
+        -> Schedule;
+}
+
+/// Create a strategy for `Schedule`s interleaving `threads` simulated threads
+/// with at most `max_preemptions` preemptions, running a thread for at most
+/// `max_steps` steps between them.
+///
+/// Schedules shrink by removing runs, which removes preemptions, and then
+/// towards running lower-numbered threads for fewer steps at a time.
+///
+/// ## Panics
+///
+/// Panics if `threads` or `max_steps` is zero.
+///
+/// ## Example
+///
+/// ```
+/// use std::cell::Cell;
+///
+/// use proptest::concurrency::schedule;
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     # /*
+///     #[test]
+///     # */
+///     fn increments_are_not_lost(sched in schedule(2, 3, 4)) {
+///         let counter = Cell::new(0);
+///         let mut loaded = [0; 2];
+///         let mut pc = [0; 2];
+///         // Each thread increments with a separate load and store, so
+///         // checking `counter == 2` here would find a lost update.
+///         sched.run(|thread| {
+///             match pc[thread] {
+///                 0 => loaded[thread] = counter.get(),
+///                 _ => counter.set(loaded[thread] + 1),
+///             }
+///             pc[thread] += 1;
+///             pc[thread] < 2
+///         });
+///         prop_assert!(counter.get() == 1 || counter.get() == 2);
+///     }
+/// }
+/// #
+/// # fn main() { increments_are_not_lost(); }
+/// ```
+pub fn schedule(
+    threads: usize,
+    max_preemptions: usize,
+    max_steps: usize,
+) -> ScheduleStrategy {
+    assert!(threads > 0, "Cannot schedule zero threads");
+    assert!(max_steps > 0, "Runs must have at least one step");
+
+    ScheduleStrategy(statics::Map::new(
+        (
+            Just(threads),
+            vec((0..threads, 1..=max_steps), 0..=max_preemptions + 1),
+        ),
+        ToSchedule,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn trace(schedule: &Schedule, steps: &[usize]) -> Vec<usize> {
+        let mut left = steps.to_vec();
+        let mut trace = Vec::new();
+        schedule.run(|thread| {
+            trace.push(thread);
+            left[thread] -= 1;
+            left[thread] > 0
+        });
+        trace
+    }
+
+    #[test]
+    fn run_follows_schedule() {
+        let schedule = Schedule {
+            threads: 3,
+            runs: vec![(1, 2), (0, 1), (1, 5), (2, 1)],
+        };
+        assert_eq!(3, schedule.preemptions());
+        // Thread 1 finishes early, and thread 2 is then run to completion
+        // before thread 0 resumes.
+        assert_eq!(vec![1, 1, 0, 1, 2, 2, 0, 0], trace(&schedule, &[3, 3, 2]));
+    }
+
+    #[test]
+    fn schedules_respect_bounds() {
+        let mut runner = TestRunner::deterministic();
+        let input = schedule(3, 4, 5);
+        for _ in 0..256 {
+            let schedule = input.new_tree(&mut runner).unwrap().current();
+            assert_eq!(3, schedule.threads());
+            assert!(schedule.preemptions() <= 4, "{:?}", schedule);
+            for &(thread, steps) in schedule.runs() {
+                assert!(thread < 3 && steps >= 1 && steps <= 5);
+            }
+            assert_eq!(12, trace(&schedule, &[4, 4, 4]).len());
+        }
+    }
+
+    #[test]
+    fn schedules_shrink_to_fewer_preemptions() {
+        let mut runner = TestRunner::deterministic();
+        // Fail whenever thread 1 starts before thread 0 finishes.
+        let result = runner.run(&schedule(2, 10, 3), |s| {
+            prop_assert_eq!(&trace(&s, &[3, 3])[..3], &[0, 0, 0]);
+            Ok(())
+        });
+
+        match result {
+            Err(TestError::Fail(_, value)) => {
+                assert_eq!(&[(1, 1)], value.runs());
+            }
+            e => panic!("Unexpected result: {:?}", e),
+        }
+    }
+}
@@ -0,0 +1,322 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating configuration, as read by programs from their
+//! environment and from configuration files.
+//!
+//! `env_vars()` generates sets of environment variables, and `section()`
+//! generates nested trees of settings like those in TOML or INI files. Both
+//! favour the inputs which configuration loaders tend to get wrong: empty
+//! values, values which look like another type, path lists with empty
+//! entries, keys which need quoting, and strings which are not valid Unicode.
+
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::OsString;
+
+use crate::bool;
+use crate::collection::{btree_map, hash_map, vec, SizeRange};
+use crate::ffi::not_unicode_os_string;
+use crate::num;
+use crate::sample::select;
+use crate::std_facade::{Box, String};
+use crate::strategy::*;
+use crate::string::string_regex;
+use crate::test_runner::*;
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates sets of environment variables.
+    ///
+    /// Created by the `env_vars()` function in the same module.
+    #[derive(Clone, Debug)]
+    pub struct EnvVarsStrategy[][](
+        SBoxedStrategy<HashMap<OsString, OsString>>)
+        -> EnvVarsValueTree;
+    /// `ValueTree` corresponding to `EnvVarsStrategy`.
+    pub struct EnvVarsValueTree[][](
+        Box<dyn ValueTree<Value = HashMap<OsString, OsString>>>)
+        -> HashMap<OsString, OsString>;
+}
+
+fn os_regex(regex: &str) -> SBoxedStrategy<OsString> {
+    string_regex(regex)
+        .unwrap()
+        .prop_map(OsString::from)
+        .sboxed()
+}
+
+fn env_key() -> SBoxedStrategy<OsString> {
+    prop_oneof![
+        4 => os_regex("[A-Z][A-Z0-9_]{0,15}"),
+        2 => select(&[
+            "PATH", "HOME", "USER", "LANG", "LC_ALL", "TERM", "SHELL",
+            "TMPDIR", "XDG_CONFIG_HOME", "RUST_LOG", "RUST_BACKTRACE",
+            "NO_COLOR", "CI",
+        ][..])
+        .prop_map(OsString::from),
+        1 => os_regex("_[A-Z0-9_]{0,8}|[a-z][a-zA-Z0-9_]{0,8}"),
+    ]
+    .sboxed()
+}
+
+/// Generates a list of paths in the form of `PATH`, with some empty entries.
+fn path_list() -> SBoxedStrategy<OsString> {
+    let (entry, separator) = if cfg!(windows) {
+        ("C:(\\\\[a-z]{1,6}){1,3}|", ";")
+    } else {
+        ("(/[a-z]{1,6}){1,3}|", ":")
+    };
+    vec(string_regex(entry).unwrap(), 1..5)
+        .prop_map(move |entries| OsString::from(entries.join(separator)))
+        .sboxed()
+}
+
+fn env_value() -> SBoxedStrategy<OsString> {
+    prop_oneof![
+        4 => os_regex("[a-zA-Z0-9_./-]{1,16}"),
+        2 => Just(OsString::new()),
+        2 => path_list(),
+        1 => select(&[
+            "1", "0", "true", "false", "TRUE", "yes", "no", "on", "off",
+        ][..])
+        .prop_map(OsString::from),
+        1 => os_regex("-?[0-9]{1,6}"),
+        1 => os_regex(
+            "[a-z]{1,4}=[a-z]{0,4}| [a-z ]{0,8} |[a-z]{1,4}\n[a-z]{1,4}",
+        ),
+        1 => not_unicode_os_string("[a-z/]{0,12}".into()),
+    ]
+    .sboxed()
+}
+
+/// Creates a strategy which generates sets of environment variables with a
+/// number of variables within `size`.
+///
+/// Names are mostly upper snake case, including well-known ones such as
+/// `PATH` and `HOME`, with some in lower or mixed case. Values are plain
+/// words, empty strings, `PATH`-style lists with empty entries, flags such
+/// as `1`, `off` and `TRUE`, numbers, strings containing `=`, spaces or line
+/// breaks, and strings which are not valid Unicode.
+///
+/// Names never contain `=` or NUL, and values never contain NUL, so every
+/// variable can be passed to a child process, for example with
+/// `std::process::Command::envs()`.
+///
+/// Shrinks by removing variables and simplifying the remaining ones.
+pub fn env_vars(size: impl Into<SizeRange>) -> EnvVarsStrategy {
+    EnvVarsStrategy(hash_map(env_key(), env_value(), size).sboxed())
+}
+
+/// A value in a configuration tree.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// A string.
+    String(String),
+    /// An integer.
+    Integer(i64),
+    /// A finite floating-point number.
+    Float(f64),
+    /// A boolean.
+    Boolean(bool),
+    /// A nested section of settings, by name.
+    Section(BTreeMap<String, Value>),
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates sections of configuration trees.
+    ///
+    /// Created by the `section()` function in the same module.
+    #[derive(Debug)]
+    pub struct SectionStrategy[][](BoxedStrategy<BTreeMap<String, Value>>)
+        -> SectionValueTree;
+    /// `ValueTree` corresponding to `SectionStrategy`.
+    pub struct SectionValueTree[][](
+        Box<dyn ValueTree<Value = BTreeMap<String, Value>>>)
+        -> BTreeMap<String, Value>;
+}
+
+fn config_key() -> BoxedStrategy<String> {
+    prop_oneof![
+        6 => string_regex("[a-z][a-z0-9_]{0,11}").unwrap(),
+        1 => string_regex("[a-z]{1,4}[-.][a-z]{1,4}|[a-z]{1,4} [a-z]{1,4}")
+            .unwrap(),
+        1 => string_regex("[0-9]{1,3}|\\PC{1,6}|").unwrap(),
+    ]
+    .boxed()
+}
+
+fn config_string() -> BoxedStrategy<String> {
+    prop_oneof![
+        4 => string_regex("[a-zA-Z0-9 _./-]{0,16}").unwrap(),
+        2 => select(&[
+            "", " ", "true", "false", "0", "-3", "1.5", "1e3", "null",
+            "0x1F", "2024-01-01",
+        ][..])
+        .prop_map(String::from),
+        1 => string_regex("[a-z]{0,4}[\"'\\\\#=;\\[\\]\n][a-z]{0,4}")
+            .unwrap(),
+        1 => string_regex("\\PC{0,16}").unwrap(),
+    ]
+    .boxed()
+}
+
+fn config_scalar() -> BoxedStrategy<Value> {
+    prop_oneof![
+        config_string().prop_map(Value::String),
+        prop_oneof![-1000..1000i64, num::i64::ANY].prop_map(Value::Integer),
+        prop_oneof![-1000.0..1000.0, num::f64::FINITE].prop_map(Value::Float),
+        bool::ANY.prop_map(Value::Boolean),
+    ]
+    .boxed()
+}
+
+/// Creates a strategy which generates sections of configuration trees, with
+/// a number of settings within `size` in each section and sections nested up
+/// to `depth` levels below the top.
+///
+/// Settings are strings, integers, finite floating-point numbers, booleans
+/// or nested sections. Keys are mostly lower snake case, with some which
+/// need quoting in formats such as TOML: keys containing dots, dashes or
+/// spaces, numeric keys, non-ASCII keys and empty keys. Strings include
+/// empty and blank ones, ones which look like numbers, booleans or dates,
+/// and ones containing quotes, comment characters or line breaks.
+///
+/// Shrinks by removing settings and simplifying the remaining values.
+///
+/// ## Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use proptest::config::{section, Value};
+/// use proptest::prelude::*;
+///
+/// // Flattens a tree into dotted keys, as some loaders do. This is only
+/// // unambiguous for keys without dots, so it is tested with nesting alone.
+/// fn flatten(prefix: &str, section: &BTreeMap<String, Value>) -> Vec<String> {
+///     let mut keys = Vec::new();
+///     for (key, value) in section {
+///         let key = format!("{}{}", prefix, key);
+///         match value {
+///             Value::Section(inner) => keys.extend(flatten(&(key + "."), inner)),
+///             _ => keys.push(key),
+///         }
+///     }
+///     keys
+/// }
+///
+/// proptest! {
+///     # /*
+///     #[test]
+///     # */
+///     fn flattened_keys_keep_their_prefix(config in section(3, 0..6)) {
+///         for (key, value) in &config {
+///             if let Value::Section(inner) = value {
+///                 for flat in flatten(&format!("{}.", key), inner) {
+///                     prop_assert!(flat.starts_with(key.as_str()));
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// #
+/// # fn main() { flattened_keys_keep_their_prefix(); }
+/// ```
+pub fn section(depth: u32, size: impl Into<SizeRange>) -> SectionStrategy {
+    let size = size.into();
+    let (lo, hi) = size.start_end_incl();
+    let branch_size = ((lo + hi) / 2).max(1) as u32;
+
+    let value = {
+        let size = size.clone();
+        config_scalar().prop_recursive(depth, 64, branch_size, move |inner| {
+            btree_map(config_key(), inner, size.clone())
+                .prop_map(Value::Section)
+        })
+    };
+
+    SectionStrategy(btree_map(config_key(), value, size).boxed())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn env_vars_can_be_passed_to_processes() {
+        let mut runner = TestRunner::deterministic();
+        let (mut saw_empty, mut saw_list) = (false, false);
+        for _ in 0..256 {
+            let vars = env_vars(0..8).sample(&mut runner);
+            assert!(vars.len() < 8);
+            for (key, value) in &vars {
+                let key = key.to_string_lossy();
+                assert!(!key.is_empty() && !key.contains(['=', '\0']));
+                assert!(!value.to_string_lossy().contains('\0'));
+                saw_empty |= value.is_empty();
+                saw_list |= value.to_str().map_or(false, |v| {
+                    v.contains(if cfg!(windows) { ';' } else { ':' })
+                });
+            }
+        }
+        assert!(saw_empty && saw_list);
+    }
+
+    fn depth(section: &BTreeMap<String, Value>) -> u32 {
+        section
+            .values()
+            .map(|value| match value {
+                Value::Section(inner) => 1 + depth(inner),
+                _ => 0,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn for_each_value(
+        section: &BTreeMap<String, Value>,
+        f: &mut impl FnMut(&Value),
+    ) {
+        for value in section.values() {
+            f(value);
+            if let Value::Section(inner) = value {
+                for_each_value(inner, f);
+            }
+        }
+    }
+
+    #[test]
+    fn sections_respect_depth_and_size() {
+        let mut runner = TestRunner::deterministic();
+        let mut max_depth = 0;
+        let mut kinds = [false; 5];
+        for _ in 0..256 {
+            let config = section(2, 1..5).sample(&mut runner);
+            max_depth = max_depth.max(depth(&config));
+            assert!((1..5).contains(&config.len()));
+            for_each_value(&config, &mut |value| {
+                kinds[match value {
+                    Value::String(_) => 0,
+                    Value::Integer(_) => 1,
+                    Value::Float(f) => {
+                        assert!(f.is_finite());
+                        2
+                    }
+                    Value::Boolean(_) => 3,
+                    Value::Section(inner) => {
+                        assert!((1..5).contains(&inner.len()));
+                        4
+                    }
+                }] = true;
+            });
+        }
+        assert_eq!(2, max_depth);
+        assert_eq!([true; 5], kinds);
+        assert!(section(2, 0..5).minimal(&mut runner).is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for `chrono` dates and times.
+
+use ::chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+use super::{days, timestamps, DateTimeParams};
+use crate::std_facade::Box;
+use crate::strategy::*;
+use crate::test_runner::*;
+
+/// The day number of 1970-01-01 counted from 0001-01-01 as day 1.
+const UNIX_EPOCH_DAYS_FROM_CE: i64 = 719_163;
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `NaiveDate`s.
+    ///
+    /// Created by the `naive_date()` function in the same module.
+    #[derive(Clone, Debug)]
+    pub struct NaiveDateStrategy[][](SBoxedStrategy<NaiveDate>)
+        -> NaiveDateValueTree;
+    /// `ValueTree` corresponding to `NaiveDateStrategy`.
+    pub struct NaiveDateValueTree[][](Box<dyn ValueTree<Value = NaiveDate>>)
+        -> NaiveDate;
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `NaiveDateTime`s.
+    ///
+    /// Created by the `naive_date_time()` function in the same module.
+    #[derive(Clone, Debug)]
+    pub struct NaiveDateTimeStrategy[][](SBoxedStrategy<NaiveDateTime>)
+        -> NaiveDateTimeValueTree;
+    /// `ValueTree` corresponding to `NaiveDateTimeStrategy`.
+    pub struct NaiveDateTimeValueTree[][](
+        Box<dyn ValueTree<Value = NaiveDateTime>>) -> NaiveDateTime;
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `DateTime<Utc>`s.
+    ///
+    /// Created by the `date_time_utc()` function in the same module.
+    #[derive(Clone, Debug)]
+    pub struct DateTimeUtcStrategy[][](SBoxedStrategy<DateTime<Utc>>)
+        -> DateTimeUtcValueTree;
+    /// `ValueTree` corresponding to `DateTimeUtcStrategy`.
+    pub struct DateTimeUtcValueTree[][](
+        Box<dyn ValueTree<Value = DateTime<Utc>>>) -> DateTime<Utc>;
+}
+
+/// Creates a strategy which generates `NaiveDate`s as described by
+/// `params`.
+pub fn naive_date(params: DateTimeParams) -> NaiveDateStrategy {
+    NaiveDateStrategy(
+        days(&params)
+            .prop_map(|days| {
+                NaiveDate::from_num_days_from_ce_opt(
+                    (days + UNIX_EPOCH_DAYS_FROM_CE) as i32,
+                )
+                .expect("date out of range")
+            })
+            .sboxed(),
+    )
+}
+
+/// Creates a strategy which generates `NaiveDateTime`s as described by
+/// `params`.
+pub fn naive_date_time(params: DateTimeParams) -> NaiveDateTimeStrategy {
+    NaiveDateTimeStrategy(
+        date_time_utc(params)
+            .prop_map(|date_time| date_time.naive_utc())
+            .sboxed(),
+    )
+}
+
+/// Creates a strategy which generates `DateTime<Utc>`s as described by
+/// `params`.
+pub fn date_time_utc(params: DateTimeParams) -> DateTimeUtcStrategy {
+    DateTimeUtcStrategy(
+        timestamps(&params)
+            .prop_map(|(secs, nanos)| {
+                DateTime::from_timestamp(secs, nanos)
+                    .expect("time out of range")
+            })
+            .sboxed(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use ::chrono::{Datelike, Timelike};
+
+    use super::*;
+
+    #[test]
+    fn dates_cover_leap_days_and_shrink_to_epoch() {
+        let mut runner = TestRunner::deterministic();
+        let epoch = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        let params = DateTimeParams::default().with_epoch(946_684_800);
+        let mut leap_days = 0;
+        for _ in 0..512 {
+            let date = naive_date(params).sample(&mut runner);
+            leap_days += (date.month() == 2 && date.day() == 29) as u32;
+        }
+        assert!(leap_days > 5, "{}", leap_days);
+        assert_eq!(epoch, naive_date(params).minimal(&mut runner));
+    }
+
+    #[test]
+    fn leap_seconds_are_opt_in() {
+        let mut runner = TestRunner::deterministic();
+        let mut leap_seconds = 0;
+        for _ in 0..512 {
+            let time =
+                naive_date_time(DateTimeParams::default()).sample(&mut runner);
+            assert!(time.nanosecond() < 1_000_000_000);
+            let time = date_time_utc(
+                DateTimeParams::default().with_leap_seconds(true),
+            )
+            .sample(&mut runner);
+            leap_seconds += (time.nanosecond() >= 1_000_000_000) as u32;
+        }
+        assert!(leap_seconds > 5, "{}", leap_seconds);
+    }
+}
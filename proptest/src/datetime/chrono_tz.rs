@@ -0,0 +1,197 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for time-zone-aware `chrono` dates and times, using the IANA
+//! time zone database from `chrono-tz`.
+
+use ::chrono::{DateTime, FixedOffset, Offset, TimeZone, Utc};
+use ::chrono_tz::{Tz, TZ_VARIANTS};
+
+use super::{timestamps, DateTimeParams, SECS_PER_DAY};
+use crate::sample::select;
+use crate::std_facade::{Box, Vec};
+use crate::strategy::*;
+use crate::test_runner::*;
+
+/// Zones with unusual offsets or rules, which are chosen more often than
+/// the rest. UTC comes first so that zones shrink towards it.
+const UNUSUAL_ZONES: &[&str] = &[
+    "UTC",
+    // Offsets which are not whole hours.
+    "Asia/Kolkata",
+    "Asia/Kathmandu",
+    "Australia/Eucla",
+    "Pacific/Chatham",
+    "America/St_Johns",
+    "Pacific/Marquesas",
+    // Half-hour daylight saving time.
+    "Australia/Lord_Howe",
+    // Negative daylight saving time in winter.
+    "Europe/Dublin",
+    // Daylight saving time suspended during Ramadan.
+    "Africa/Casablanca",
+    // The extremes of the offset range, and a skipped day in 2011.
+    "Pacific/Kiritimati",
+    "Etc/GMT+12",
+    "Pacific/Apia",
+    // Southern hemisphere daylight saving time.
+    "America/Santiago",
+    "Australia/Sydney",
+    // Common zones with daylight saving time transitions.
+    "America/New_York",
+    "Europe/London",
+    "Europe/Berlin",
+];
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `Tz`s.
+    ///
+    /// Created by the `tz()` function in the same module.
+    #[derive(Clone, Debug)]
+    pub struct TzStrategy[][](SBoxedStrategy<Tz>) -> TzValueTree;
+    /// `ValueTree` corresponding to `TzStrategy`.
+    pub struct TzValueTree[][](Box<dyn ValueTree<Value = Tz>>) -> Tz;
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `(DateTime<Tz>, Tz)` pairs.
+    ///
+    /// Created by the `date_time_tz()` function in the same module.
+    #[derive(Clone, Debug)]
+    pub struct DateTimeTzStrategy[][](SBoxedStrategy<(DateTime<Tz>, Tz)>)
+        -> DateTimeTzValueTree;
+    /// `ValueTree` corresponding to `DateTimeTzStrategy`.
+    pub struct DateTimeTzValueTree[][](
+        Box<dyn ValueTree<Value = (DateTime<Tz>, Tz)>>) -> (DateTime<Tz>, Tz);
+}
+
+/// Creates a strategy which generates time zones from the IANA database.
+///
+/// About a quarter of the zones are chosen from a list with unusual offsets
+/// or rules, such as `Asia/Kathmandu` and `Australia/Lord_Howe`. Zones
+/// shrink towards UTC.
+pub fn tz() -> TzStrategy {
+    let unusual: Vec<Tz> = UNUSUAL_ZONES
+        .iter()
+        .map(|name| name.parse().expect("unknown time zone"))
+        .collect();
+    TzStrategy(
+        prop_oneof![1 => select(unusual), 3 => select(TZ_VARIANTS.to_vec())]
+            .sboxed(),
+    )
+}
+
+/// Creates a strategy which generates times in time zones from the IANA
+/// database, paired with their zone.
+///
+/// Instants are generated as described by `params`, and zones as by `tz()`.
+/// With edge cases enabled, half of the instants are moved to the nearest
+/// offset transition within the following year, such as the start or end of
+/// daylight saving time, or to one second either side of it.
+pub fn date_time_tz(params: DateTimeParams) -> DateTimeTzStrategy {
+    let (min, max) = (params.min, params.max);
+    let shift = if params.edge_cases {
+        prop_oneof![
+            1 => Just(None),
+            1 => select(vec![0, -1, 1]).prop_map(Some),
+        ]
+        .sboxed()
+    } else {
+        Just(None).sboxed()
+    };
+
+    DateTimeTzStrategy(
+        (timestamps(&params), tz(), shift)
+            .prop_map(move |((secs, nanos), tz, shift)| {
+                let secs = match shift.and_then(|delta| {
+                    next_transition(&tz, secs)
+                        .map(|transition| transition + delta)
+                }) {
+                    Some(secs) => secs.max(min).min(max),
+                    None => secs,
+                };
+                let date_time = DateTime::from_timestamp(secs, nanos)
+                    .expect("time out of range")
+                    .with_timezone(&tz);
+                (date_time, tz)
+            })
+            .sboxed(),
+    )
+}
+
+fn offset_at(tz: &Tz, secs: i64) -> Option<FixedOffset> {
+    DateTime::<Utc>::from_timestamp(secs, 0)
+        .map(|utc| tz.offset_from_utc_datetime(&utc.naive_utc()).fix())
+}
+
+/// Finds the first instant within a year after `secs` at which the offset
+/// of `tz` changes.
+fn next_transition(tz: &Tz, secs: i64) -> Option<i64> {
+    let offset = offset_at(tz, secs)?;
+    let mut before = secs;
+    for day in 1..=366 {
+        let after = secs + day * SECS_PER_DAY;
+        if offset_at(tz, after)? != offset {
+            // The transition is in (before, after].
+            let (mut lo, mut hi) = (before, after);
+            while hi - lo > 1 {
+                let mid = lo + (hi - lo) / 2;
+                if offset_at(tz, mid)? == offset {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            return Some(hi);
+        }
+        before = after;
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unusual_zones_exist() {
+        for name in UNUSUAL_ZONES {
+            assert!(name.parse::<Tz>().is_ok(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn transitions_are_generated() {
+        let mut runner = TestRunner::deterministic();
+        let params = DateTimeParams::default()
+            .with_range(946_684_800..=1_893_456_000)
+            .with_epoch(946_684_800);
+        let mut transitions = 0;
+        for _ in 0..512 {
+            let (date_time, tz) = date_time_tz(params).sample(&mut runner);
+            assert_eq!(tz, date_time.timezone());
+            let secs = date_time.timestamp();
+            if offset_at(&tz, secs) != offset_at(&tz, secs - 1) {
+                transitions += 1;
+            }
+        }
+        assert!(transitions > 20, "{}", transitions);
+    }
+
+    #[test]
+    fn shrinks_to_utc_at_epoch() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..16 {
+            let (date_time, tz) =
+                date_time_tz(DateTimeParams::default()).minimal(&mut runner);
+            assert_eq!(Tz::UTC, tz);
+            assert_eq!(0, date_time.timestamp());
+        }
+    }
+}
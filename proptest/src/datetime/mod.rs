@@ -0,0 +1,290 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating dates and times from the `chrono` and `time`
+//! crates.
+//!
+//! Dates and times are generated directly from day numbers and Unix
+//! timestamps, so every generated value is valid and none are rejected.
+//! They shrink towards a configurable epoch.
+//!
+//! The strategies for each crate are in a submodule of the same name,
+//! enabled by the corresponding feature, and all take [`DateTimeParams`].
+//! Time-zone-aware strategies using `chrono-tz` are in `chrono_tz`.
+
+use core::ops::RangeInclusive;
+
+use crate::num;
+use crate::sample::select;
+use crate::strategy::*;
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+pub mod chrono;
+#[cfg(feature = "chrono-tz")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono-tz")))]
+pub mod chrono_tz;
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+pub mod time;
+
+const SECS_PER_DAY: i64 = 86_400;
+
+/// The Unix timestamp of 0001-01-01T00:00:00Z.
+const MIN_TIMESTAMP: i64 = -62_135_596_800;
+/// The Unix timestamp of 9999-12-31T23:59:59Z.
+const MAX_TIMESTAMP: i64 = 253_402_300_799;
+
+/// Parameters for the strategies in this module.
+///
+/// Ranges and epochs are given as Unix timestamps, in seconds, so that the
+/// same parameters apply to both crates. Dates are generated from the days
+/// containing those timestamps.
+///
+/// With edge cases enabled, as they are by default, about a quarter of the
+/// values fall on or next to the ends of years, leap days, and the ends of
+/// June and December, when leap seconds are inserted, and at the first or
+/// last instant of their day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DateTimeParams {
+    min: i64,
+    max: i64,
+    epoch: i64,
+    edge_cases: bool,
+    leap_seconds: bool,
+}
+
+impl DateTimeParams {
+    /// Gets the range of Unix timestamps to generate.
+    pub fn range(&self) -> RangeInclusive<i64> {
+        self.min..=self.max
+    }
+
+    /// Sets the range of Unix timestamps to generate. The default is from
+    /// the start of year 1 to the end of year 9999.
+    ///
+    /// The range must contain the epoch, and must lie within the range
+    /// supported by the crate the parameters are used with.
+    pub fn with_range(mut self, range: RangeInclusive<i64>) -> Self {
+        self.min = *range.start();
+        self.max = *range.end();
+        self
+    }
+
+    /// Gets the Unix timestamp which values shrink towards.
+    pub fn epoch(&self) -> i64 {
+        self.epoch
+    }
+
+    /// Sets the Unix timestamp which values shrink towards. The default is
+    /// 0, that is 1970-01-01T00:00:00Z.
+    pub fn with_epoch(mut self, epoch: i64) -> Self {
+        self.epoch = epoch;
+        self
+    }
+
+    /// Gets whether generation is biased towards edge cases.
+    pub fn edge_cases(&self) -> bool {
+        self.edge_cases
+    }
+
+    /// Sets whether generation is biased towards edge cases.
+    pub fn with_edge_cases(mut self, edge_cases: bool) -> Self {
+        self.edge_cases = edge_cases;
+        self
+    }
+
+    /// Gets whether leap seconds are generated.
+    pub fn leap_seconds(&self) -> bool {
+        self.leap_seconds
+    }
+
+    /// Sets whether edge cases include leap seconds, such as
+    /// `23:59:60.5`. This is off by default, and only has an effect on
+    /// `chrono` times, since the `time` crate cannot represent them.
+    pub fn with_leap_seconds(mut self, leap_seconds: bool) -> Self {
+        self.leap_seconds = leap_seconds;
+        self
+    }
+}
+
+impl Default for DateTimeParams {
+    fn default() -> Self {
+        Self {
+            min: MIN_TIMESTAMP,
+            max: MAX_TIMESTAMP,
+            epoch: 0,
+            edge_cases: true,
+            leap_seconds: false,
+        }
+    }
+}
+
+/// Generates day numbers, counted from 1970-01-01.
+///
+/// ## Panics
+///
+/// Panics if the epoch is outside the range.
+fn days(params: &DateTimeParams) -> SBoxedStrategy<i64> {
+    let (min, max) = (
+        params.min.div_euclid(SECS_PER_DAY),
+        params.max.div_euclid(SECS_PER_DAY),
+    );
+    let uniform = num::i64::shrink_towards(
+        min..=max,
+        params.epoch.div_euclid(SECS_PER_DAY),
+    );
+    if !params.edge_cases {
+        return uniform.sboxed();
+    }
+
+    let (min_year, max_year) = (civil_from_days(min).0, civil_from_days(max).0);
+    let edge = (
+        min_year..=max_year,
+        select(vec![
+            (1, 1),
+            (2, 28),
+            (2, 29),
+            (3, 1),
+            (6, 30),
+            (7, 1),
+            (12, 31),
+        ]),
+    )
+        .prop_map(move |(year, (month, day))| {
+            // Move leap days to the closest earlier leap year.
+            let year = if month == 2 && day == 29 {
+                let mut year = year - year.rem_euclid(4);
+                if year.rem_euclid(100) == 0 && year.rem_euclid(400) != 0 {
+                    year -= 4;
+                }
+                year
+            } else {
+                year
+            };
+            days_from_civil(year, month, day).max(min).min(max)
+        });
+    prop_oneof![3 => uniform, 1 => edge].sboxed()
+}
+
+/// Generates Unix timestamps as seconds and nanoseconds. The nanoseconds
+/// exceed 999,999,999 only for leap seconds.
+fn timestamps(params: &DateTimeParams) -> SBoxedStrategy<(i64, u32)> {
+    let uniform = (
+        num::i64::shrink_towards(params.min..=params.max, params.epoch),
+        0..1_000_000_000u32,
+    );
+    if !params.edge_cases {
+        return uniform.sboxed();
+    }
+
+    let mut instants = vec![(0, 0), (SECS_PER_DAY - 1, 999_999_999)];
+    if params.leap_seconds {
+        instants.push((SECS_PER_DAY - 1, 1_000_000_000));
+        instants.push((SECS_PER_DAY - 1, 1_999_999_999));
+    }
+    let (min, max) = (params.min, params.max);
+    let edge = (days(params), select(instants)).prop_map(
+        move |(day, (secs, nanos))| {
+            let timestamp = day * SECS_PER_DAY + secs;
+            if timestamp < min {
+                (min, 0)
+            } else if timestamp > max {
+                (max, 999_999_999)
+            } else {
+                (timestamp, nanos)
+            }
+        },
+    );
+    prop_oneof![3 => uniform, 1 => edge].sboxed()
+}
+
+/// Converts a proleptic Gregorian date to a day number counted from
+/// 1970-01-01.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5
+            + i64::from(day)
+            - 1;
+    let day_of_era =
+        year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Converts a day number counted from 1970-01-01 to a proleptic Gregorian
+/// `(year, month, day)`.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524
+        - day_of_era / 146_096)
+        / 365;
+    let day_of_year =
+        day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_runner::*;
+
+    #[test]
+    fn civil_conversions_round_trip() {
+        assert_eq!(0, days_from_civil(1970, 1, 1));
+        assert_eq!(11_016, days_from_civil(2000, 2, 29));
+        assert_eq!(MIN_TIMESTAMP / SECS_PER_DAY, days_from_civil(1, 1, 1));
+        for days in (-800_000..3_000_000).step_by(97) {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days, days_from_civil(year, month, day));
+        }
+    }
+
+    #[test]
+    fn edge_cases_include_leap_days() {
+        let mut runner = TestRunner::deterministic();
+        let strategy = days(&DateTimeParams::default());
+        let mut leap_days = 0;
+        for _ in 0..1024 {
+            let (year, month, day) =
+                civil_from_days(strategy.sample(&mut runner));
+            if (month, day) == (2, 29) {
+                assert!(year % 4 == 0 && (year % 100 != 0 || year % 400 == 0));
+                leap_days += 1;
+            }
+        }
+        assert!(leap_days > 10, "{}", leap_days);
+    }
+
+    #[test]
+    fn timestamps_stay_in_range_and_shrink_to_epoch() {
+        let mut runner = TestRunner::deterministic();
+        let params = DateTimeParams::default()
+            .with_range(1_000_000_000..=1_100_000_000)
+            .with_epoch(1_050_000_000);
+        for _ in 0..256 {
+            let (secs, nanos) = timestamps(&params).sample(&mut runner);
+            assert!(params.range().contains(&secs));
+            assert!(nanos < 1_000_000_000);
+            assert_eq!(
+                (1_050_000_000, 0),
+                timestamps(&params).minimal(&mut runner)
+            );
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for `time` dates and times.
+//!
+//! The `time` crate cannot represent leap seconds, so
+//! `DateTimeParams::leap_seconds` has no effect on these strategies.
+
+use ::time::{Date, OffsetDateTime, PrimitiveDateTime};
+
+use super::{days, timestamps, DateTimeParams};
+use crate::std_facade::Box;
+use crate::strategy::*;
+use crate::test_runner::*;
+
+/// The Julian day number of 1970-01-01.
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `Date`s.
+    ///
+    /// Created by the `date()` function in the same module.
+    #[derive(Clone, Debug)]
+    pub struct DateStrategy[][](SBoxedStrategy<Date>) -> DateValueTree;
+    /// `ValueTree` corresponding to `DateStrategy`.
+    pub struct DateValueTree[][](Box<dyn ValueTree<Value = Date>>) -> Date;
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `PrimitiveDateTime`s.
+    ///
+    /// Created by the `primitive_date_time()` function in the same module.
+    #[derive(Clone, Debug)]
+    pub struct PrimitiveDateTimeStrategy[][](
+        SBoxedStrategy<PrimitiveDateTime>) -> PrimitiveDateTimeValueTree;
+    /// `ValueTree` corresponding to `PrimitiveDateTimeStrategy`.
+    pub struct PrimitiveDateTimeValueTree[][](
+        Box<dyn ValueTree<Value = PrimitiveDateTime>>) -> PrimitiveDateTime;
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `OffsetDateTime`s.
+    ///
+    /// Created by the `offset_date_time()` function in the same module.
+    #[derive(Clone, Debug)]
+    pub struct OffsetDateTimeStrategy[][](SBoxedStrategy<OffsetDateTime>)
+        -> OffsetDateTimeValueTree;
+    /// `ValueTree` corresponding to `OffsetDateTimeStrategy`.
+    pub struct OffsetDateTimeValueTree[][](
+        Box<dyn ValueTree<Value = OffsetDateTime>>) -> OffsetDateTime;
+}
+
+/// Creates a strategy which generates `Date`s as described by `params`.
+pub fn date(params: DateTimeParams) -> DateStrategy {
+    DateStrategy(
+        days(&params)
+            .prop_map(|days| {
+                Date::from_julian_day((days + UNIX_EPOCH_JULIAN_DAY) as i32)
+                    .expect("date out of range")
+            })
+            .sboxed(),
+    )
+}
+
+/// Creates a strategy which generates `PrimitiveDateTime`s as described by
+/// `params`.
+pub fn primitive_date_time(
+    params: DateTimeParams,
+) -> PrimitiveDateTimeStrategy {
+    PrimitiveDateTimeStrategy(
+        offset_date_time(params)
+            .prop_map(|date_time| {
+                PrimitiveDateTime::new(date_time.date(), date_time.time())
+            })
+            .sboxed(),
+    )
+}
+
+/// Creates a strategy which generates `OffsetDateTime`s in UTC as described
+/// by `params`.
+pub fn offset_date_time(params: DateTimeParams) -> OffsetDateTimeStrategy {
+    OffsetDateTimeStrategy(
+        timestamps(&params)
+            .prop_map(|(secs, nanos)| {
+                OffsetDateTime::from_unix_timestamp(secs)
+                    .and_then(|date_time| {
+                        date_time.replace_nanosecond(nanos.min(999_999_999))
+                    })
+                    .expect("time out of range")
+            })
+            .sboxed(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use ::time::Month;
+
+    use super::*;
+
+    #[test]
+    fn dates_cover_leap_days_and_shrink_to_epoch() {
+        let mut runner = TestRunner::deterministic();
+        let mut leap_days = 0;
+        for _ in 0..512 {
+            let date = date(DateTimeParams::default()).sample(&mut runner);
+            leap_days +=
+                (date.month() == Month::February && date.day() == 29) as u32;
+        }
+        assert!(leap_days > 5, "{}", leap_days);
+        assert_eq!(
+            Date::from_calendar_date(1970, Month::January, 1).unwrap(),
+            date(DateTimeParams::default()).minimal(&mut runner)
+        );
+    }
+
+    #[test]
+    fn leap_seconds_are_clamped() {
+        let mut runner = TestRunner::deterministic();
+        let params = DateTimeParams::default()
+            .with_range(0..=86_399)
+            .with_leap_seconds(true);
+        for _ in 0..256 {
+            let time = offset_date_time(params).sample(&mut runner);
+            assert_eq!(0, time.unix_timestamp() / 86_400);
+        }
+        assert_eq!(
+            OffsetDateTime::UNIX_EPOCH,
+            offset_date_time(params).minimal(&mut runner)
+        );
+    }
+}
@@ -0,0 +1,196 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating `rust_decimal::Decimal` values.
+//!
+//! A `Decimal` is an integer mantissa together with a scale, the number of
+//! digits after the decimal point. The strategies here generate the mantissa
+//! and scale directly, so that values such as monetary amounts can be given
+//! an exact number of decimal places, and shrink the mantissa towards zero.
+
+use core::ops::RangeInclusive;
+
+use ::rust_decimal::Decimal;
+
+use crate::num;
+use crate::sample::{
+    with_special_values, WithSpecialValues, WithSpecialValuesValueTree,
+};
+use crate::strategy::*;
+use crate::test_runner::*;
+
+/// The largest number of significant digits a `Decimal` can hold in full.
+const MAX_PRECISION: u32 = 28;
+
+/// The largest mantissa of a `Decimal`, `2^96 - 1`.
+const MAX_MANTISSA: i128 = (1 << 96) - 1;
+
+/// The probability of generating one of the edge cases of `decimal()`.
+const EDGE_CASE_PROBABILITY: f64 = 0.25;
+
+#[derive(Clone, Copy, Debug)]
+struct WithScale(u32);
+
+impl statics::MapFn<i128> for WithScale {
+    type Output = Decimal;
+
+    fn apply(&self, mantissa: i128) -> Decimal {
+        Decimal::from_i128_with_scale(mantissa, self.0)
+    }
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `Decimal`s with a fixed scale.
+    ///
+    /// Created by `decimal()`.
+    #[derive(Clone, Debug)]
+    pub struct DecimalStrategy[][](
+        statics::Map<WithSpecialValues<RangeInclusive<i128>>, WithScale>)
+        -> DecimalValueTree;
+    /// `ValueTree` corresponding to `DecimalStrategy`.
+    #[derive(Clone, Debug)]
+    pub struct DecimalValueTree[][](
+        statics::Map<WithSpecialValuesValueTree<RangeInclusive<i128>>,
+                     WithScale>)
+        -> Decimal;
+}
+
+/// Create a strategy for `Decimal`s with at most `precision` significant
+/// digits, of which exactly `scale` come after the decimal point.
+///
+/// For example, `decimal(7, 2)` generates amounts from `-99999.99` to
+/// `99999.99` in steps of `0.01`. A quarter of the values are edge cases:
+/// zero, the smallest step (`0.01`) and its negation, half of one whole
+/// unit (`0.50`), and the largest and smallest representable values.
+///
+/// Values shrink towards zero.
+///
+/// ## Panics
+///
+/// Panics if `precision` is zero or greater than 28, or if `scale` is
+/// greater than `precision`.
+///
+/// ## Example
+///
+/// ```
+/// use proptest::decimal::decimal;
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     # /*
+///     #[test]
+///     # */
+///     fn splitting_keeps_cents(amount in decimal(12, 2)) {
+///         let half = (amount / rust_decimal::Decimal::TWO).round_dp(2);
+///         prop_assert_eq!(amount, half + (amount - half));
+///     }
+/// }
+/// #
+/// # fn main() { splitting_keeps_cents(); }
+/// ```
+pub fn decimal(precision: u32, scale: u32) -> DecimalStrategy {
+    assert!(
+        precision > 0 && precision <= MAX_PRECISION,
+        "Decimal precision must be between 1 and {}",
+        MAX_PRECISION
+    );
+    assert!(scale <= precision, "Decimal scale exceeds precision");
+
+    let max = 10i128.pow(precision) - 1;
+    let mut edge_cases = vec![0, 1, -1, max, -max];
+    if scale > 0 {
+        edge_cases.push(5 * 10i128.pow(scale - 1));
+    }
+
+    DecimalStrategy(statics::Map::new(
+        with_special_values(-max..=max, edge_cases, EDGE_CASE_PROBABILITY),
+        WithScale(scale),
+    ))
+}
+
+#[derive(Clone, Copy, Debug)]
+struct FromParts;
+
+impl statics::MapFn<(i128, u32)> for FromParts {
+    type Output = Decimal;
+
+    fn apply(&self, (mantissa, scale): (i128, u32)) -> Decimal {
+        Decimal::from_i128_with_scale(mantissa, scale)
+    }
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates any `Decimal`.
+    ///
+    /// Created by `any()`, and used by `any::<Decimal>()`.
+    #[derive(Clone, Debug)]
+    pub struct AnyDecimal[][](
+        statics::Map<(RangeInclusive<i128>, RangeInclusive<u32>), FromParts>)
+        -> AnyDecimalValueTree;
+    /// `ValueTree` corresponding to `AnyDecimal`.
+    #[derive(Clone, Debug)]
+    pub struct AnyDecimalValueTree[][](
+        statics::Map<
+            crate::tuple::TupleValueTree<(num::i128::BinarySearch,
+                                          num::u32::BinarySearch)>,
+            FromParts>)
+        -> Decimal;
+}
+
+/// Create a strategy for any `Decimal`, with a mantissa and scale chosen
+/// uniformly from their full ranges.
+///
+/// Values shrink towards zero, and then towards a scale of zero.
+pub fn any() -> AnyDecimal {
+    AnyDecimal(statics::Map::new(
+        (-MAX_MANTISSA..=MAX_MANTISSA, 0..=MAX_PRECISION),
+        FromParts,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decimals_have_fixed_scale() {
+        let mut runner = TestRunner::deterministic();
+        let input = decimal(7, 2);
+        let max = Decimal::new(9_999_999, 2);
+        let mut edge_cases = 0;
+        for _ in 0..1024 {
+            let value = input.new_tree(&mut runner).unwrap().current();
+            assert_eq!(2, value.scale());
+            assert!(value.abs() <= max, "{}", value);
+            if value.abs() == max || value.abs() == Decimal::new(1, 2) {
+                edge_cases += 1;
+            }
+        }
+        assert!(edge_cases > 64, "{}", edge_cases);
+        assert_eq!(Decimal::new(0, 2), input.minimal(&mut runner));
+    }
+
+    #[test]
+    fn decimals_reach_full_precision() {
+        let mut runner = TestRunner::deterministic();
+        for &scale in &[0, 14, 28] {
+            let input = decimal(28, scale);
+            for _ in 0..256 {
+                let value = input.new_tree(&mut runner).unwrap().current();
+                assert_eq!(scale, value.scale());
+            }
+        }
+    }
+
+    #[test]
+    fn decimal_sanity() {
+        check_strategy_sanity(decimal(10, 4), None);
+        check_strategy_sanity(any(), None);
+    }
+}
@@ -0,0 +1,686 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating values of any type implementing
+//! `serde::Deserialize`.
+//!
+//! Many types from other crates implement `Deserialize` but not
+//! `Arbitrary`. `any_deserialize()` generates values of such types by
+//! deserializing them from a `Deserializer` which makes up the data as it
+//! is asked for: a number when the type asks for a number, a length and
+//! then elements when it asks for a sequence, a variant when it asks for an
+//! enum, and so on.
+//!
+//! The data is decoded from a list of generated choices, where a choice of
+//! zero always means the simplest option, such as `0`, `false`, `None`, an
+//! empty collection or the first variant of an enum. Shrinking removes and
+//! simplifies choices, so values shrink towards the simplest value of the
+//! type without any shrinking logic of their own.
+//!
+//! Types which reject some of the values they are given, such as types
+//! which validate their fields, are generated by rejecting those values, so
+//! types which reject most values are best generated some other way.
+//!
+//! ## Example
+//!
+//! ```
+//! use std::collections::BTreeMap;
+//!
+//! use proptest::deserialize::any_deserialize;
+//! use proptest::prelude::*;
+//! use serde::Deserialize;
+//!
+//! #[derive(Debug, Deserialize)]
+//! enum Setting {
+//!     Flag(bool),
+//!     Level(u8),
+//!     Names(Vec<String>),
+//! }
+//!
+//! #[derive(Debug, Deserialize)]
+//! struct Settings {
+//!     version: u32,
+//!     settings: BTreeMap<String, Setting>,
+//! }
+//!
+//! proptest! {
+//!     # /*
+//!     #[test]
+//!     # */
+//!     fn levels_fit_in_a_byte(settings in any_deserialize::<Settings>()) {
+//!         for setting in settings.settings.values() {
+//!             if let Setting::Level(level) = setting {
+//!                 prop_assert!(u32::from(*level) < 256);
+//!             }
+//!         }
+//!     }
+//! }
+//! #
+//! # fn main() { levels_fit_in_a_byte(); }
+//! ```
+
+use core::fmt;
+
+use ::serde::de::{
+    self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor,
+};
+
+use crate::arbitrary::any;
+use crate::collection::{vec, SizeRange};
+use crate::std_facade::{Box, String};
+use crate::strategy::*;
+use crate::test_runner::*;
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates values of a type implementing `Deserialize`.
+    ///
+    /// Created by `any_deserialize()` and `any_deserialize_with()`.
+    #[derive(Debug)]
+    pub struct DeserializeStrategy[<T>][where T: fmt::Debug](
+        SBoxedStrategy<T>)
+        -> DeserializeValueTree<T>;
+    /// `ValueTree` corresponding to `DeserializeStrategy`.
+    pub struct DeserializeValueTree[<T>][where T: fmt::Debug](
+        Box<dyn ValueTree<Value = T>>)
+        -> T;
+}
+
+impl<T: fmt::Debug> Clone for DeserializeStrategy<T> {
+    fn clone(&self) -> Self {
+        DeserializeStrategy(self.0.clone())
+    }
+}
+
+/// An error raised by a type which rejects the data it was given.
+#[derive(Debug)]
+struct Error(String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl de::Error for Error {
+    fn custom<M: fmt::Display>(msg: M) -> Self {
+        Error(format!("{}", msg))
+    }
+}
+
+/// The limits given to `any_deserialize_with()`.
+#[derive(Clone, Copy, Debug)]
+struct Limits {
+    min_len: usize,
+    max_len: usize,
+    depth: u32,
+}
+
+/// Choices smaller than this are decoded as numbers with a few fractional
+/// bits when a float is asked for, rather than as arbitrary bit patterns.
+const SMALL_FLOAT: u64 = 1 << 53;
+
+/// Decode a choice so that small choices are small positive and negative
+/// numbers: 0, -1, 1, -2, 2 and so on.
+fn zigzag(choice: u64) -> i64 {
+    (choice >> 1) as i64 ^ -((choice & 1) as i64)
+}
+
+/// A `Deserializer` which decodes data from a list of choices.
+struct Decoder<'a> {
+    choices: &'a [u64],
+    limits: Limits,
+    depth: u32,
+}
+
+impl<'a> Decoder<'a> {
+    /// Return the next choice, or zero if all choices have been used.
+    fn choice(&mut self) -> u64 {
+        match self.choices.split_first() {
+            Some((&choice, rest)) => {
+                self.choices = rest;
+                choice
+            }
+            None => 0,
+        }
+    }
+
+    /// Return whether compound values are nested as deeply as allowed, in
+    /// which case they are made as simple as possible.
+    fn too_deep(&self) -> bool {
+        self.depth > self.limits.depth
+    }
+
+    fn len(&mut self) -> usize {
+        let Limits {
+            min_len, max_len, ..
+        } = self.limits;
+        let choice = self.choice();
+        match ((max_len - min_len) as u64).checked_add(1) {
+            Some(range) => min_len + (choice % range) as usize,
+            None => min_len + choice as usize,
+        }
+    }
+
+    fn collection_len(&mut self) -> usize {
+        if self.too_deep() {
+            0
+        } else {
+            self.len()
+        }
+    }
+
+    fn float(&mut self) -> f64 {
+        let choice = self.choice();
+        if choice < SMALL_FLOAT {
+            zigzag(choice) as f64 / 8.0
+        } else {
+            f64::from_bits(choice)
+        }
+    }
+
+    fn char(&mut self) -> char {
+        match self.choice() {
+            choice @ 0..=25 => (b'a' + choice as u8) as char,
+            choice @ 26..=127 => choice as u8 as char,
+            choice => char::from_u32((choice % 0x11_0000) as u32)
+                .unwrap_or(char::REPLACEMENT_CHARACTER),
+        }
+    }
+
+    fn string(&mut self) -> String {
+        (0..self.len()).map(|_| self.char()).collect()
+    }
+
+    /// Run `f` one level deeper.
+    fn nested<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
+        self.depth += 1;
+        let result = f(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn seq<'de, V: Visitor<'de>>(
+        &mut self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.nested(|de| visitor.visit_seq(Elements { de, remaining: len }))
+    }
+}
+
+macro_rules! deserialize_number {
+    ($($method:ident => $visit:ident($typ:ty) from $source:expr;)*) => {$(
+        fn $method<V: Visitor<'de>>(
+            self,
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            let source: fn(&mut Decoder) -> $typ = $source;
+            visitor.$visit(source(self))
+        }
+    )*};
+}
+
+impl<'de, 'a, 'b> de::Deserializer<'de> for &'b mut Decoder<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.choice() % 7 {
+            0 => visitor.visit_unit(),
+            1 => self.deserialize_bool(visitor),
+            2 => self.deserialize_i64(visitor),
+            3 => self.deserialize_f64(visitor),
+            4 => self.deserialize_string(visitor),
+            5 => self.deserialize_seq(visitor),
+            _ => self.deserialize_map(visitor),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_bool(1 == self.choice() % 2)
+    }
+
+    deserialize_number! {
+        deserialize_i8 => visit_i8(i8) from |de| zigzag(de.choice()) as i8;
+        deserialize_i16 => visit_i16(i16) from |de| zigzag(de.choice()) as i16;
+        deserialize_i32 => visit_i32(i32) from |de| zigzag(de.choice()) as i32;
+        deserialize_i64 => visit_i64(i64) from |de| zigzag(de.choice());
+        deserialize_i128 => visit_i128(i128) from |de| zigzag(de.choice()).into();
+        deserialize_u8 => visit_u8(u8) from |de| de.choice() as u8;
+        deserialize_u16 => visit_u16(u16) from |de| de.choice() as u16;
+        deserialize_u32 => visit_u32(u32) from |de| de.choice() as u32;
+        deserialize_u64 => visit_u64(u64) from |de| de.choice();
+        deserialize_u128 => visit_u128(u128) from |de| de.choice().into();
+        deserialize_f64 => visit_f64(f64) from |de| de.float();
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let choice = self.choice();
+        visitor.visit_f32(if choice < SMALL_FLOAT {
+            zigzag(choice) as f32 / 8.0
+        } else {
+            f32::from_bits(choice as u32)
+        })
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_char(self.char())
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_string(self.string())
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_byte_buf(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let bytes = (0..self.len()).map(|_| self.choice() as u8).collect();
+        visitor.visit_byte_buf(bytes)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        if self.too_deep() || 0 == self.choice() % 2 {
+            visitor.visit_none()
+        } else {
+            self.nested(|de| visitor.visit_some(de))
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let len = self.collection_len();
+        self.seq(len, visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.seq(len, visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.seq(len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let len = self.collection_len();
+        self.nested(|de| visitor.visit_map(Elements { de, remaining: len }))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        // Derived implementations accept the fields in order as a sequence,
+        // which avoids having to name them.
+        self.seq(fields.len(), visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let index = if self.too_deep() {
+            0
+        } else {
+            self.choice() % variants.len().max(1) as u64
+        };
+        self.nested(|de| {
+            visitor.visit_enum(Variant {
+                de,
+                index: index as u32,
+            })
+        })
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_u64(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+}
+
+/// The elements of a sequence or the entries of a map.
+struct Elements<'a, 'b> {
+    de: &'b mut Decoder<'a>,
+    remaining: usize,
+}
+
+impl<'de, 'a, 'b> de::SeqAccess<'de> for Elements<'a, 'b> {
+    type Error = Error;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, Error> {
+        if 0 == self.remaining {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de, 'a, 'b> de::MapAccess<'de> for Elements<'a, 'b> {
+    type Error = Error;
+
+    fn next_key_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, Error> {
+        de::SeqAccess::next_element_seed(self, seed)
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<S::Value, Error> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+/// The chosen variant of an enum.
+struct Variant<'a, 'b> {
+    de: &'b mut Decoder<'a>,
+    index: u32,
+}
+
+impl<'de, 'a, 'b> de::EnumAccess<'de> for Variant<'a, 'b> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<S: DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<(S::Value, Self), Error> {
+        let index: de::value::U32Deserializer<Error> =
+            self.index.into_deserializer();
+        Ok((seed.deserialize(index)?, self))
+    }
+}
+
+impl<'de, 'a, 'b> de::VariantAccess<'de> for Variant<'a, 'b> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<S: DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<S::Value, Error> {
+        seed.deserialize(self.de)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.de.seq(len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.de.seq(fields.len(), visitor)
+    }
+}
+
+/// Create a strategy which generates values of any type `T` implementing
+/// `Deserialize`.
+///
+/// This is `any_deserialize_with(0..16, 3)`.
+pub fn any_deserialize<T>() -> DeserializeStrategy<T>
+where
+    T: DeserializeOwned + fmt::Debug + 'static,
+{
+    any_deserialize_with(0..16, 3)
+}
+
+/// Create a strategy which generates values of any type `T` implementing
+/// `Deserialize`, with a length within `size` for every string, byte string
+/// and collection, and with compound values nested up to `depth` levels
+/// below the top.
+///
+/// Below `depth`, collections are empty, options are `None` and enums take
+/// their first variant. Numbers are usually small, with a few fractional
+/// bits for floats, but may be anything including NaN. Characters are
+/// usually ASCII, and structs and enum variants are built from their fields
+/// in order, as derived implementations allow.
+///
+/// Values which `T` rejects, returning an error from `deserialize()`, are
+/// rejected by the strategy.
+///
+/// Shrinks towards the simplest value of `T`, such as `0`, `false`,
+/// `None`, empty strings and collections, and the first variant of enums.
+pub fn any_deserialize_with<T>(
+    size: impl Into<SizeRange>,
+    depth: u32,
+) -> DeserializeStrategy<T>
+where
+    T: DeserializeOwned + fmt::Debug + 'static,
+{
+    let (min_len, max_len) = size.into().start_end_incl();
+    let limits = Limits {
+        min_len,
+        max_len,
+        depth,
+    };
+    let choice = prop_oneof![3 => 0..16u64, 2 => 0..256u64, 1 => any::<u64>()];
+
+    DeserializeStrategy(
+        vec(choice, 0..128)
+            .prop_filter_map("value rejected by deserialize()", move |c| {
+                T::deserialize(&mut Decoder {
+                    choices: &c,
+                    limits,
+                    depth: 0,
+                })
+                .ok()
+            })
+            .sboxed(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+    use std::num::NonZeroU8;
+
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::std_facade::Vec;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Rect { width: u8, height: u8 },
+        Group(Vec<Shape>),
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Scene {
+        name: String,
+        id: Option<u32>,
+        shapes: Vec<Shape>,
+        tags: BTreeMap<String, i16>,
+        pair: (bool, char),
+    }
+
+    fn depth(shape: &Shape) -> u32 {
+        match shape {
+            Shape::Group(shapes) => {
+                1 + shapes.iter().map(depth).max().unwrap_or(0)
+            }
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn every_variant_is_generated() {
+        let mut runner = TestRunner::deterministic();
+        let input = any_deserialize_with::<Scene>(0..5, 3);
+        let mut variants = [false; 4];
+        let mut max_depth = 0;
+        for _ in 0..256 {
+            let scene = input.new_tree(&mut runner).unwrap().current();
+            assert!(scene.name.chars().count() < 5);
+            assert!(scene.tags.len() < 5);
+            for shape in &scene.shapes {
+                variants[match shape {
+                    Shape::Point => 0,
+                    Shape::Circle(_) => 1,
+                    Shape::Rect { .. } => 2,
+                    Shape::Group(_) => 3,
+                }] = true;
+                max_depth = max_depth.max(depth(shape));
+            }
+        }
+        assert_eq!([true; 4], variants);
+        // The scene, its list of shapes, each shape and the list in each
+        // group are a level each, so groups within depth 3 contain points.
+        assert_eq!(1, max_depth);
+    }
+
+    #[test]
+    fn values_shrink_to_simplest() {
+        let mut runner = TestRunner::deterministic();
+        assert_eq!(
+            Scene {
+                name: String::new(),
+                id: None,
+                shapes: vec![],
+                tags: BTreeMap::new(),
+                pair: (false, 'a'),
+            },
+            any_deserialize::<Scene>().minimal(&mut runner)
+        );
+
+        let result = runner.run(&any_deserialize::<Vec<Shape>>(), |shapes| {
+            let is_rect = |s: &Shape| matches!(s, Shape::Rect { .. });
+            prop_assert!(!shapes.iter().any(is_rect));
+            Ok(())
+        });
+        match result {
+            Err(TestError::Fail(_, shapes)) => assert_eq!(
+                vec![Shape::Rect {
+                    width: 0,
+                    height: 0
+                }],
+                shapes
+            ),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn rejected_values_are_skipped() {
+        let mut runner = TestRunner::deterministic();
+        let input = any_deserialize::<NonZeroU8>();
+        for _ in 0..256 {
+            assert_ne!(0, input.new_tree(&mut runner).unwrap().current().get());
+        }
+        assert_eq!(1, input.minimal(&mut runner).get());
+    }
+}
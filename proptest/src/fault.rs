@@ -0,0 +1,246 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for injecting faults into the code under test.
+//!
+//! These are meant for testing error paths, such as retry or rollback logic,
+//! where the interesting question is not _what_ goes wrong but _when_. All
+//! strategies here shrink towards injecting no failures at all, so a minimal
+//! failing case only contains the faults needed to trigger the bug.
+
+use core::fmt;
+use core::ops::Range;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::option::{self, OptionStrategy, OptionValueTree, Probability};
+use crate::result::{self, MaybeErr};
+use crate::strategy::*;
+use crate::test_runner::*;
+
+/// Create a strategy for `Result`s which are usually `Ok` values from `ok`,
+/// but with probability `probability_of_failure` are instead `Err` values
+/// from `err`.
+///
+/// This is `result::maybe_err_weighted()` under a name which reads better
+/// when the `Err` stands for an injected failure, such as the response of a
+/// mocked service. Generated values shrink to `Ok`.
+pub fn failing_sometimes<T: Strategy, E: Strategy>(
+    probability_of_failure: impl Into<Probability>,
+    ok: T,
+    err: E,
+) -> MaybeErr<T, E> {
+    result::maybe_err_weighted(probability_of_failure, ok, err)
+}
+
+/// The error returned by `FailPoint::hit()` for an injected failure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InjectedFault {
+    /// The zero-based index of the call which failed.
+    pub call: usize,
+}
+
+impl fmt::Display for InjectedFault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "injected fault on call {}", self.call)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InjectedFault {}
+
+/// A mock point of failure which fails deterministically on one particular
+/// call.
+///
+/// Code under test (or a mock it talks to) calls `hit()` wherever a real
+/// operation could fail, and bails out if it returns an error. The calls are
+/// counted from zero, and exactly the call chosen when the `FailPoint` was
+/// created fails; all others succeed.
+///
+/// Calls are counted atomically, so a `FailPoint` can be shared between
+/// threads, though the failing call is then only deterministic if the calls
+/// themselves happen in a deterministic order.
+///
+/// `FailPoint`s are usually generated by `fail_point()`.
+#[derive(Debug)]
+pub struct FailPoint {
+    fail_on: Option<usize>,
+    calls: AtomicUsize,
+}
+
+impl FailPoint {
+    /// Create a `FailPoint` which never fails.
+    pub fn never() -> Self {
+        FailPoint::new(None)
+    }
+
+    /// Create a `FailPoint` which fails on the call with zero-based index
+    /// `call`.
+    pub fn on_call(call: usize) -> Self {
+        FailPoint::new(Some(call))
+    }
+
+    fn new(fail_on: Option<usize>) -> Self {
+        FailPoint {
+            fail_on,
+            calls: AtomicUsize::new(0),
+        }
+    }
+
+    /// Return the zero-based index of the call which fails, if any.
+    pub fn fail_on(&self) -> Option<usize> {
+        self.fail_on
+    }
+
+    /// Return the number of times `hit()` has been called since this
+    /// `FailPoint` was created or last reset.
+    pub fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+
+    /// Record a call, returning an error if it is the one chosen to fail.
+    pub fn hit(&self) -> Result<(), InjectedFault> {
+        let call = self.calls.fetch_add(1, Ordering::SeqCst);
+        if Some(call) == self.fail_on {
+            Err(InjectedFault { call })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Reset the call count, so that the same sequence of calls fails in the
+    /// same place again.
+    pub fn reset(&self) {
+        self.calls.store(0, Ordering::SeqCst);
+    }
+}
+
+impl Clone for FailPoint {
+    fn clone(&self) -> Self {
+        FailPoint {
+            fail_on: self.fail_on,
+            calls: AtomicUsize::new(self.calls()),
+        }
+    }
+}
+
+mapfn! {
+    [] fn ToFailPoint[](fail_on: Option<usize>) -> FailPoint {
+        FailPoint::new(fail_on)
+    }
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `FailPoint`s.
+    ///
+    /// Created by `fail_point()`.
+    #[derive(Clone, Debug)]
+    pub struct FailPointStrategy[][](
+        statics::Map<OptionStrategy<Range<usize>>, ToFailPoint>)
+        -> FailPointValueTree;
+    /// `ValueTree` corresponding to `FailPointStrategy`.
+    #[derive(Clone, Debug)]
+    pub struct FailPointValueTree[][](
+        statics::Map<OptionValueTree<Range<usize>>, ToFailPoint>)
+        -> FailPoint;
+}
+
+/// Create a strategy for `FailPoint`s which, with probability
+/// `probability_of_failure`, fail on one of the calls in `calls`, and
+/// otherwise never fail.
+///
+/// Generated values shrink first to a `FailPoint` which never fails, and
+/// otherwise to one which fails on an earlier call.
+///
+/// ## Example
+///
+/// ```
+/// use proptest::fault::{fail_point, FailPoint, InjectedFault};
+/// use proptest::prelude::*;
+///
+/// fn copy_with_retry(
+///     fail: &FailPoint,
+///     data: &[u8],
+/// ) -> Result<Vec<u8>, InjectedFault> {
+///     let mut out = Vec::new();
+///     for &byte in data {
+///         // Retry each byte once before giving up.
+///         fail.hit().or_else(|_| fail.hit())?;
+///         out.push(byte);
+///     }
+///     Ok(out)
+/// }
+///
+/// proptest! {
+///     # /*
+///     #[test]
+///     # */
+///     fn retries_single_failures(
+///         data in prop::collection::vec(any::<u8>(), 0..10),
+///         fail in fail_point(0.5, 0..10),
+///     ) {
+///         prop_assert_eq!(data.clone(), copy_with_retry(&fail, &data).unwrap());
+///     }
+/// }
+/// #
+/// # fn main() { retries_single_failures(); }
+/// ```
+pub fn fail_point(
+    probability_of_failure: impl Into<Probability>,
+    calls: Range<usize>,
+) -> FailPointStrategy {
+    FailPointStrategy(statics::Map::new(
+        option::weighted(probability_of_failure, calls),
+        ToFailPoint,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fail_point_fails_once() {
+        let fail = FailPoint::on_call(2);
+        assert_eq!(Ok(()), fail.hit());
+        assert_eq!(Ok(()), fail.hit());
+        assert_eq!(Err(InjectedFault { call: 2 }), fail.hit());
+        assert_eq!(Ok(()), fail.hit());
+        assert_eq!(4, fail.calls());
+
+        fail.reset();
+        assert_eq!(Ok(()), fail.hit());
+        assert_eq!(1, fail.calls());
+
+        let never = FailPoint::never();
+        assert!((0..100).all(|_| never.hit().is_ok()));
+    }
+
+    #[test]
+    fn fail_points_shrink_to_no_failure() {
+        let mut runner = TestRunner::deterministic();
+        let input = fail_point(0.5, 3..10);
+        let mut failing = 0;
+        for _ in 0..256 {
+            let fail = input.new_tree(&mut runner).unwrap().current();
+            if let Some(call) = fail.fail_on() {
+                assert!((3..10).contains(&call), "{}", call);
+                failing += 1;
+            }
+        }
+        assert!(failing > 96 && failing < 160, "{}", failing);
+        assert_eq!(None, input.minimal(&mut runner).fail_on());
+    }
+
+    #[test]
+    fn failing_sometimes_shrinks_to_ok() {
+        let mut runner = TestRunner::deterministic();
+        let input = failing_sometimes(0.9, Just(1), Just("oops"));
+        assert_eq!(Ok(1), input.minimal(&mut runner));
+    }
+}
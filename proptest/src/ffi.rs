@@ -0,0 +1,301 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating [`std::ffi`] strings.
+
+use core::ops::RangeInclusive;
+use std::ffi::{CString, OsString};
+
+use crate::collection::{vec, SizeRange, VecStrategy, VecValueTree};
+use crate::num;
+use crate::sample::Index;
+use crate::std_facade::{Box, String, Vec};
+use crate::strategy::*;
+use crate::string::StringParam;
+use crate::test_runner::*;
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `CString`s.
+    ///
+    /// Created by `c_string()`, and used by `any::<CString>()`.
+    #[derive(Clone, Debug)]
+    pub struct CStringStrategy[][](
+        statics::Map<VecStrategy<RangeInclusive<u8>>, FromNonNulBytes>)
+        -> CStringValueTree;
+    /// `ValueTree` corresponding to `CStringStrategy`.
+    #[derive(Clone, Debug)]
+    pub struct CStringValueTree[][](
+        statics::Map<VecValueTree<num::u8::BinarySearch>, FromNonNulBytes>)
+        -> CString;
+}
+
+#[derive(Clone, Copy, Debug)]
+struct FromNonNulBytes;
+
+impl statics::MapFn<Vec<u8>> for FromNonNulBytes {
+    type Output = CString;
+
+    fn apply(&self, bytes: Vec<u8>) -> CString {
+        CString::new(bytes).expect("generated an interior NUL")
+    }
+}
+
+/// Creates a strategy which generates the contents of C strings: vectors of
+/// any bytes except NUL, with a length in `size`.
+///
+/// Every byte is generated directly from `1..=255`, so no values are
+/// rejected, and bytes shrink towards `0x01`.
+pub fn non_nul_bytes(
+    size: impl Into<SizeRange>,
+) -> VecStrategy<RangeInclusive<u8>> {
+    vec(1..=u8::MAX, size)
+}
+
+/// Creates a strategy which generates `CString`s with `size` bytes before
+/// the terminating NUL, as generated by `non_nul_bytes()`.
+///
+/// This is the strategy used by `any::<CString>()`.
+pub fn c_string(size: impl Into<SizeRange>) -> CStringStrategy {
+    CStringStrategy(statics::Map::new(non_nul_bytes(size), FromNonNulBytes))
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `OsString`s.
+    ///
+    /// Created by the functions in this module.
+    #[derive(Clone, Debug)]
+    pub struct OsStringStrategy[][](SBoxedStrategy<OsString>)
+        -> OsStringValueTree;
+    /// `ValueTree` corresponding to `OsStringStrategy`.
+    pub struct OsStringValueTree[][](Box<dyn ValueTree<Value = OsString>>)
+        -> OsString;
+}
+
+/// Creates a strategy which generates `OsString`s, half of which are not
+/// valid Unicode.
+///
+/// Valid strings match `regex`. The others are made by inserting invalid
+/// sequences between the characters of such strings, as described for
+/// `not_unicode_os_string()`. Generated strings shrink towards valid ones.
+///
+/// This is the strategy used by `any::<OsString>()`.
+///
+/// ## Panics
+///
+/// Panics if `regex` is not a valid regular expression for generation.
+pub fn os_string(regex: StringParam) -> OsStringStrategy {
+    let valid = regex_strategy(regex).prop_map(OsString::from);
+    OsStringStrategy(prop_oneof![valid, not_unicode_os_string(regex)].sboxed())
+}
+
+/// Creates a strategy which generates `OsString`s which are never valid
+/// Unicode, so that `OsString::into_string()` always fails.
+///
+/// The strings are built from strings matching `regex`, with one to three
+/// invalid sequences inserted between characters:
+///
+/// - On Unix, byte sequences which are not UTF-8, such as lone continuation
+///   bytes, overlong and truncated encodings, and encoded surrogates.
+/// - On Windows, unpaired surrogates.
+///
+/// On other platforms, which cannot represent such strings, the strings are
+/// valid Unicode.
+///
+/// ## Panics
+///
+/// Panics if `regex` is not a valid regular expression for generation.
+pub fn not_unicode_os_string(regex: StringParam) -> OsStringStrategy {
+    OsStringStrategy(
+        (
+            regex_strategy(regex),
+            vec((any_index(), invalid_unit()), 1..4),
+        )
+            .prop_map(|(string, insertions)| insert_invalid(string, insertions))
+            .sboxed(),
+    )
+}
+
+fn regex_strategy(regex: StringParam) -> SBoxedStrategy<String> {
+    let regex: &'static str = regex.into();
+    crate::string::string_regex(regex).unwrap().sboxed()
+}
+
+fn any_index() -> SBoxedStrategy<Index> {
+    crate::arbitrary::any::<Index>().sboxed()
+}
+
+/// Inserts each sequence at a different character boundary of `string`,
+/// so that no two sequences can combine into a valid one.
+fn insert_invalid<T>(
+    string: String,
+    insertions: Vec<(Index, Vec<T>)>,
+) -> OsString
+where
+    OsString: FromUnits<T>,
+{
+    let boundaries: Vec<usize> = string
+        .char_indices()
+        .map(|(ix, _)| ix)
+        .chain(Some(string.len()))
+        .collect();
+    let mut insertions: Vec<(usize, Vec<T>)> = insertions
+        .into_iter()
+        .map(|(ix, units)| (boundaries[ix.index(boundaries.len())], units))
+        .collect();
+    insertions.sort_by_key(|&(pos, _)| pos);
+    insertions.dedup_by_key(|&mut (pos, _)| pos);
+    OsString::from_units(&string, &insertions)
+}
+
+/// Builds an `OsString` from a string and sequences of platform code units
+/// to insert at byte offsets into it.
+trait FromUnits<T> {
+    fn from_units(string: &str, insertions: &[(usize, Vec<T>)]) -> Self;
+}
+
+#[cfg(unix)]
+fn invalid_unit() -> SBoxedStrategy<Vec<u8>> {
+    crate::sample::select(vec![
+        // Lone continuation bytes.
+        vec![0x80],
+        vec![0xBF],
+        // Overlong encodings.
+        vec![0xC0, 0x80],
+        vec![0xC1, 0xBF],
+        vec![0xE0, 0x80, 0x80],
+        // A truncated three-byte sequence.
+        vec![0xE2, 0x82],
+        // An encoded surrogate, as used by WTF-8.
+        vec![0xED, 0xA0, 0x80],
+        // Beyond U+10FFFF.
+        vec![0xF4, 0x90, 0x80, 0x80],
+        // Bytes which never occur in UTF-8.
+        vec![0xF5],
+        vec![0xFF],
+    ])
+    .sboxed()
+}
+
+#[cfg(unix)]
+impl FromUnits<u8> for OsString {
+    fn from_units(string: &str, insertions: &[(usize, Vec<u8>)]) -> Self {
+        use std::os::unix::ffi::OsStringExt;
+
+        let string = string.as_bytes();
+        let mut bytes = Vec::with_capacity(string.len() + 4 * insertions.len());
+        let mut start = 0;
+        for (pos, units) in insertions {
+            bytes.extend_from_slice(&string[start..*pos]);
+            bytes.extend_from_slice(units);
+            start = *pos;
+        }
+        bytes.extend_from_slice(&string[start..]);
+        OsString::from_vec(bytes)
+    }
+}
+
+#[cfg(windows)]
+fn invalid_unit() -> SBoxedStrategy<Vec<u16>> {
+    // A single surrogate between two whole characters is always unpaired.
+    (0xD800..=0xDFFFu16).prop_map(|unit| vec![unit]).sboxed()
+}
+
+#[cfg(windows)]
+impl FromUnits<u16> for OsString {
+    fn from_units(string: &str, insertions: &[(usize, Vec<u16>)]) -> Self {
+        use std::os::windows::ffi::OsStringExt;
+
+        let mut wide = Vec::with_capacity(string.len() + insertions.len());
+        let mut start = 0;
+        for (pos, units) in insertions {
+            wide.extend(string[start..*pos].encode_utf16());
+            wide.extend_from_slice(units);
+            start = *pos;
+        }
+        wide.extend(string[start..].encode_utf16());
+        OsString::from_wide(&wide)
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+fn invalid_unit() -> SBoxedStrategy<Vec<()>> {
+    Just(Vec::new()).sboxed()
+}
+
+#[cfg(not(any(unix, windows)))]
+impl FromUnits<()> for OsString {
+    fn from_units(string: &str, _: &[(usize, Vec<()>)]) -> Self {
+        OsString::from(string)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(any(unix, windows))]
+    #[test]
+    fn not_unicode_is_never_unicode() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..1024 {
+            let mut tree = not_unicode_os_string(StringParam::default())
+                .new_tree(&mut runner)
+                .unwrap();
+            loop {
+                assert!(tree.current().into_string().is_err());
+                if !tree.simplify() {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn os_string_mixes_valid_and_invalid() {
+        let mut runner = TestRunner::deterministic();
+        let mut valid = 0;
+        for _ in 0..1024 {
+            let s = os_string("[a-z]{0,8}".into()).sample(&mut runner);
+            valid += s.to_str().is_some() as u32;
+        }
+        if cfg!(any(unix, windows)) {
+            assert!((400..624).contains(&valid), "{}", valid);
+        }
+    }
+
+    #[test]
+    fn c_string_respects_size() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..256 {
+            let mut tree = c_string(2..8).new_tree(&mut runner).unwrap();
+            loop {
+                let len = tree.current().as_bytes().len();
+                assert!((2..8).contains(&len), "{}", len);
+                if !tree.simplify() {
+                    break;
+                }
+            }
+            assert_eq!(
+                CString::new(vec![1; 2]).unwrap(),
+                c_string(2..8).minimal(&mut runner)
+            );
+        }
+    }
+
+    #[test]
+    fn os_string_shrinks_to_empty() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..64 {
+            assert_eq!(
+                OsString::new(),
+                os_string(StringParam::default()).minimal(&mut runner)
+            );
+        }
+    }
+}
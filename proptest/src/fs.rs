@@ -0,0 +1,352 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating directory trees on disk.
+//!
+//! `dir_tree()` generates a `DirTree`, a description of a directory with
+//! nested subdirectories and files, and `temp_dir()` writes each generated
+//! tree into a fresh temporary directory, which is deleted again when the
+//! test case is done with it. This is useful for testing code which walks,
+//! copies or synchronises directories.
+//!
+//! Trees shrink by removing entries and truncating files, so failures are
+//! reported with as few files as possible.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tempfile::TempDir;
+
+use crate::arbitrary::any;
+use crate::collection::{btree_map, vec, SizeRange};
+use crate::std_facade::{Box, String, Vec};
+use crate::strategy::*;
+use crate::test_runner::*;
+
+/// An entry in a `DirTree`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Entry {
+    /// A file with the given contents.
+    File(Vec<u8>),
+    /// A subdirectory.
+    Dir(DirTree),
+}
+
+/// A description of a directory and everything in it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DirTree {
+    entries: BTreeMap<String, Entry>,
+}
+
+impl DirTree {
+    /// Return the entries directly in this directory, by name.
+    pub fn entries(&self) -> &BTreeMap<String, Entry> {
+        &self.entries
+    }
+
+    /// Return the number of entries in this directory and all of its
+    /// subdirectories.
+    pub fn len(&self) -> usize {
+        self.entries
+            .values()
+            .map(|entry| match entry {
+                Entry::File(_) => 1,
+                Entry::Dir(dir) => 1 + dir.len(),
+            })
+            .sum()
+    }
+
+    /// Return whether this directory is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Return the paths of all files in this directory and its
+    /// subdirectories, relative to this directory, with their contents.
+    pub fn files(&self) -> Vec<(PathBuf, &[u8])> {
+        let mut files = Vec::new();
+        self.collect_files(Path::new(""), &mut files);
+        files
+    }
+
+    fn collect_files<'a>(
+        &'a self,
+        prefix: &Path,
+        files: &mut Vec<(PathBuf, &'a [u8])>,
+    ) {
+        for (name, entry) in &self.entries {
+            let path = prefix.join(name);
+            match entry {
+                Entry::File(contents) => files.push((path, contents)),
+                Entry::Dir(dir) => dir.collect_files(&path, files),
+            }
+        }
+    }
+
+    /// Create the entries of this tree inside the existing directory `root`.
+    pub fn write_to(&self, root: &Path) -> io::Result<()> {
+        for (name, entry) in &self.entries {
+            let path = root.join(name);
+            match entry {
+                Entry::File(contents) => fs::write(&path, contents)?,
+                Entry::Dir(dir) => {
+                    fs::create_dir(&path)?;
+                    dir.write_to(&path)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Write this tree into a new temporary directory, which is deleted when
+    /// the returned `TempDir` is dropped.
+    pub fn materialize(&self) -> io::Result<TempDir> {
+        let dir = tempfile::Builder::new().prefix("proptest-").tempdir()?;
+        self.write_to(dir.path())?;
+        Ok(dir)
+    }
+}
+
+/// A `DirTree` written into a temporary directory, which is deleted when
+/// this is dropped.
+///
+/// Generated by `temp_dir()`.
+#[derive(Debug)]
+pub struct TempTree {
+    dir: TempDir,
+    tree: DirTree,
+}
+
+impl TempTree {
+    /// Return the path of the temporary directory.
+    pub fn path(&self) -> &Path {
+        self.dir.path()
+    }
+
+    /// Return the tree which was written into the temporary directory.
+    pub fn tree(&self) -> &DirTree {
+        &self.tree
+    }
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `DirTree`s.
+    ///
+    /// Created by the `dir_tree()` function in the same module.
+    #[derive(Clone, Debug)]
+    pub struct DirTreeStrategy[][](BoxedStrategy<DirTree>)
+        -> DirTreeValueTree;
+    /// `ValueTree` corresponding to `DirTreeStrategy`.
+    pub struct DirTreeValueTree[][](Box<dyn ValueTree<Value = DirTree>>)
+        -> DirTree;
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `TempTree`s.
+    ///
+    /// Created by the `temp_dir()` function in the same module.
+    #[derive(Debug)]
+    pub struct TempDirStrategy[][](BoxedStrategy<TempTree>)
+        -> TempDirValueTree;
+    /// `ValueTree` corresponding to `TempDirStrategy`.
+    pub struct TempDirValueTree[][](Box<dyn ValueTree<Value = TempTree>>)
+        -> TempTree;
+}
+
+/// Return whether `name` can't be used as a file name on Windows, where
+/// device names are reserved with any extension.
+fn reserved_on_windows(name: &str) -> bool {
+    let stem = name.split('.').next().unwrap_or(name);
+    let device = matches!(stem, "con" | "prn" | "aux" | "nul")
+        || ((stem.starts_with("com") || stem.starts_with("lpt"))
+            && stem.len() == 4
+            && stem.as_bytes()[3].is_ascii_digit());
+    device || name.ends_with('.')
+}
+
+/// Generates names which are valid for files on all common platforms, and
+/// are distinct even on case-insensitive file systems.
+fn entry_name() -> impl Strategy<Value = String> {
+    "[a-z0-9_][a-z0-9_.-]{0,11}"
+        .prop_filter("name is reserved on Windows", |name| {
+            !reserved_on_windows(name)
+        })
+}
+
+/// Create a strategy for `DirTree`s with subdirectories nested up to
+/// `depth` levels below the root, where each directory has a number of
+/// entries within `entries` and each file a size in bytes within
+/// `file_size`.
+///
+/// Entry names are short, lower case and portable, and file contents are
+/// arbitrary bytes. The total number of entries is kept to a few hundred
+/// regardless of `depth`.
+///
+/// Trees shrink by removing entries and truncating files.
+pub fn dir_tree(
+    depth: u32,
+    entries: impl Into<SizeRange>,
+    file_size: impl Into<SizeRange>,
+) -> DirTreeStrategy {
+    let entries = entries.into();
+    let (lo, hi) = entries.start_end_incl();
+    let branch_size = ((lo + hi) / 2).max(1) as u32;
+
+    let file = vec(any::<u8>(), file_size).prop_map(Entry::File);
+    let entry = {
+        let entries = entries.clone();
+        file.prop_recursive(depth, 256, branch_size, move |entry| {
+            btree_map(entry_name(), entry, entries.clone())
+                .prop_map(|entries| Entry::Dir(DirTree { entries }))
+        })
+    };
+
+    DirTreeStrategy(
+        btree_map(entry_name(), entry, entries)
+            .prop_map(|entries| DirTree { entries })
+            .boxed(),
+    )
+}
+
+/// Create a strategy which writes each `DirTree` generated by `tree` into a
+/// new temporary directory.
+///
+/// The directory is deleted when the generated `TempTree` is dropped, which
+/// normally happens at the end of each test case.
+///
+/// ## Panics
+///
+/// Generating a value panics if the tree can't be written, such as when the
+/// disk is full.
+///
+/// ## Example
+///
+/// ```
+/// use proptest::fs::{dir_tree, temp_dir};
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     # /*
+///     #[test]
+///     # */
+///     fn walk_finds_every_file(dir in temp_dir(dir_tree(3, 0..5, 0..64))) {
+///         fn count_files(path: &std::path::Path) -> usize {
+///             std::fs::read_dir(path).unwrap().map(|entry| {
+///                 let entry = entry.unwrap();
+///                 if entry.file_type().unwrap().is_dir() {
+///                     count_files(&entry.path())
+///                 } else {
+///                     1
+///                 }
+///             }).sum()
+///         }
+///
+///         prop_assert_eq!(dir.tree().files().len(), count_files(dir.path()));
+///     }
+/// }
+/// #
+/// # fn main() { walk_finds_every_file(); }
+/// ```
+pub fn temp_dir(tree: DirTreeStrategy) -> TempDirStrategy {
+    TempDirStrategy(
+        tree.prop_map(|tree| {
+            let dir =
+                tree.materialize().expect("failed to write directory tree");
+            TempTree { dir, tree }
+        })
+        .boxed(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn read_tree(path: &Path) -> DirTree {
+        let mut entries = BTreeMap::new();
+        for entry in fs::read_dir(path).unwrap() {
+            let entry = entry.unwrap();
+            let name = entry.file_name().into_string().unwrap();
+            let entry = if entry.file_type().unwrap().is_dir() {
+                Entry::Dir(read_tree(&entry.path()))
+            } else {
+                Entry::File(fs::read(entry.path()).unwrap())
+            };
+            entries.insert(name, entry);
+        }
+        DirTree { entries }
+    }
+
+    #[test]
+    fn temp_dirs_match_trees_and_are_removed() {
+        let mut runner = TestRunner::deterministic();
+        let strategy = temp_dir(dir_tree(3, 0..5, 0..32));
+        let mut max_len = 0;
+        for _ in 0..64 {
+            let temp = strategy.new_tree(&mut runner).unwrap().current();
+            assert_eq!(temp.tree(), &read_tree(temp.path()));
+            max_len = max_len.max(temp.tree().len());
+
+            let path = temp.path().to_path_buf();
+            drop(temp);
+            assert!(!path.exists());
+        }
+        assert!(max_len > 5);
+    }
+
+    #[test]
+    fn dir_trees_respect_limits() {
+        fn check(tree: &DirTree, depth: u32) {
+            assert!((1..4).contains(&tree.entries().len()));
+            for (name, entry) in tree.entries() {
+                assert!(!reserved_on_windows(name) && !name.starts_with('.'));
+                match entry {
+                    Entry::File(contents) => assert!(contents.len() <= 8),
+                    Entry::Dir(dir) => {
+                        assert!(depth > 0);
+                        check(dir, depth - 1);
+                    }
+                }
+            }
+        }
+
+        let mut runner = TestRunner::deterministic();
+        let strategy = dir_tree(2, 1..4, 0..=8);
+        for _ in 0..256 {
+            check(&strategy.new_tree(&mut runner).unwrap().current(), 2);
+        }
+    }
+
+    #[test]
+    fn dir_trees_shrink_by_removing_entries() {
+        let mut runner = TestRunner::deterministic();
+        let result = runner.run(&dir_tree(3, 0..5, 0..32), |tree| {
+            prop_assert!(tree.files().len() < 3);
+            Ok(())
+        });
+        match result {
+            Err(TestError::Fail(_, tree)) => {
+                assert_eq!(3, tree.files().len(), "{:?}", tree);
+                assert!(tree.files().iter().all(|(_, c)| c.is_empty()));
+            }
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn reserved_names() {
+        assert!(reserved_on_windows("con"));
+        assert!(reserved_on_windows("lpt1.txt"));
+        assert!(reserved_on_windows("trailing."));
+        assert!(!reserved_on_windows("console"));
+        assert!(!reserved_on_windows("com"));
+    }
+}
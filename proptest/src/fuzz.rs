@@ -0,0 +1,370 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Support for running properties under coverage-guided fuzzers.
+//!
+//! A fuzzer drives generation through `TestRunner::from_bytes()`: every
+//! choice a strategy makes is read from the fuzzer's input, so the fuzzer
+//! explores the space of values by mutating bytes, while proptest keeps
+//! shrinking failures to a minimal value.
+//!
+//! `fuzz_target!` wraps a property into a `cargo fuzz` (libFuzzer) entry
+//! point. The fuzz crate must depend on `libfuzzer-sys` itself, which the
+//! macro expands to.
+//!
+//! ```ignore
+//! #![no_main]
+//! use proptest::prelude::*;
+//!
+//! proptest::fuzz_target!(|(s in "[a-z]{0,8}", n in 0..100usize)| {
+//!     prop_assert!(s.len() < 8 || n < 99);
+//! });
+//! ```
+//!
+//! When the fuzzer finds an input which fails the property, the value it
+//! decodes to is shrunk as in a normal proptest run, and the minimal failing
+//! value is reported in the panic which makes the fuzzer record the crash.
+//!
+//! With the `afl` feature, `afl_fuzz!` does the same for a persistent-mode
+//! AFL loop.
+//!
+//! Inputs saved by a fuzzer can be replayed outside of it, for instance in a
+//! regular test: `decode()` returns the value an input generates, and
+//! `replay()` and `replay_file()` run the property on it and shrink the value
+//! if it fails.
+
+#[cfg(not(feature = "handle-panics"))]
+use std::boxed::Box;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+
+use crate::strategy::{Strategy, ValueTree};
+#[cfg(feature = "handle-panics")]
+use crate::test_runner::scoped_panic_hook;
+use crate::test_runner::{
+    Config, Reason, TestCaseResult, TestError, TestRunner,
+};
+
+/// Runs `test` on the value `strategy` generates from `data`, the input of a
+/// fuzzer.
+///
+/// Inputs which do not generate a value, or whose value the test rejects,
+/// are ignored. If the test fails, the value is shrunk, and this panics with
+/// the minimal failing value, which fuzzers record as a crash of `data`.
+///
+/// Panics within the test are caught as usual, without being passed to the
+/// panic hook: fuzzers install hooks which abort the process, and this
+/// would abort before shrinking.
+///
+/// This is what `fuzz_target!` calls for each input.
+///
+/// ```
+/// use proptest::prelude::*;
+/// use proptest::fuzz::run_input;
+/// use proptest::test_runner::Config;
+///
+/// fn fuzz_target(data: &[u8]) {
+///     run_input(Config::default(), data, &(0..100u32, 0..100u32), |(a, b)| {
+///         prop_assert_eq!(a + b, b + a);
+///         Ok(())
+///     });
+/// }
+///
+/// fuzz_target(b"");
+/// fuzz_target(b"some bytes from a fuzzer");
+/// ```
+pub fn run_input<S: Strategy>(
+    config: Config,
+    data: &[u8],
+    strategy: &S,
+    test: impl Fn(S::Value) -> TestCaseResult,
+) {
+    let result = silence_panics(|| replay(config, data, strategy, test));
+
+    match result {
+        Ok(Err(TestError::Fail(why, what))) => {
+            panic!("{}", TestError::Fail(why, what))
+        }
+        Ok(_) => (),
+        Err(what) => panic::resume_unwind(what),
+    }
+}
+
+/// Runs `body` without passing its panics to the panic hook.
+#[cfg(feature = "handle-panics")]
+fn silence_panics<R>(body: impl FnOnce() -> R) -> std::thread::Result<R> {
+    scoped_panic_hook::with_hook(
+        |_| { /* Silence out panic backtrace */ },
+        || panic::catch_unwind(AssertUnwindSafe(body)),
+    )
+}
+
+/// Runs `body` without passing its panics to the panic hook.
+///
+/// Without `handle-panics` there is no scoped hook, so the global one is
+/// replaced for the duration of `body`. It cannot be restored from a drop
+/// guard, since the hook cannot be changed while unwinding, so panics are
+/// caught and returned instead.
+#[cfg(not(feature = "handle-panics"))]
+fn silence_panics<R>(body: impl FnOnce() -> R) -> std::thread::Result<R> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| { /* Silence out panic backtrace */ }));
+    let result = panic::catch_unwind(AssertUnwindSafe(body));
+    panic::set_hook(hook);
+    result
+}
+
+/// Returns the value `strategy` generates from `data`, the input of a
+/// fuzzer, or the reason the strategy did not generate one.
+///
+/// This is the value a fuzz target defined with `fuzz_target!` or
+/// `afl_fuzz!` checks for `data`, if it was given the same strategy and
+/// configuration.
+pub fn decode<S: Strategy>(
+    config: Config,
+    data: &[u8],
+    strategy: &S,
+) -> Result<S::Value, Reason> {
+    let mut runner = TestRunner::from_bytes(config, data);
+    strategy.new_tree(&mut runner).map(|case| case.current())
+}
+
+/// Runs `test` on the value `strategy` generates from `data`, such as a
+/// crashing input saved by a fuzzer, shrinking the value if the test fails.
+///
+/// Returns `TestError::Fail` with the minimal failing value if the test
+/// fails, and `TestError::Abort` if `data` does not generate a value. A
+/// value the test rejects counts as passing.
+///
+/// ```
+/// use proptest::prelude::*;
+/// use proptest::fuzz::replay;
+/// use proptest::test_runner::{Config, TestError};
+///
+/// let result = replay(Config::default(), &[0xff; 8], &(0..1000u32), |n| {
+///     prop_assert!(n < 100);
+///     Ok(())
+/// });
+/// match result {
+///     Err(TestError::Fail(_, n)) => assert_eq!(100, n),
+///     result => panic!("Unexpected result: {:?}", result),
+/// }
+/// ```
+pub fn replay<S: Strategy>(
+    config: Config,
+    data: &[u8],
+    strategy: &S,
+    test: impl Fn(S::Value) -> TestCaseResult,
+) -> Result<(), TestError<S::Value>> {
+    let mut runner = TestRunner::from_bytes(config, data);
+    let case = strategy.new_tree(&mut runner).map_err(TestError::Abort)?;
+    runner.run_one(case, test).map(|_| ())
+}
+
+/// Like `replay()`, but reads the input from the file at `path`, such as a
+/// file from the `crashes` directory of AFL or the `artifacts` directory of
+/// `cargo fuzz`.
+///
+/// Returns `TestError::Abort` if the file cannot be read.
+pub fn replay_file<S: Strategy>(
+    config: Config,
+    path: impl AsRef<Path>,
+    strategy: &S,
+    test: impl Fn(S::Value) -> TestCaseResult,
+) -> Result<(), TestError<S::Value>> {
+    let path = path.as_ref();
+    let data = fs::read(path).map_err(|err| {
+        TestError::Abort(
+            format!("Failed to read {}: {}", path.display(), err).into(),
+        )
+    })?;
+    replay(config, &data, strategy, test)
+}
+
+/// Defines a `cargo fuzz` (libFuzzer) entry point which checks a property
+/// against values generated from the fuzzer's input.
+///
+/// The property is written in the closure style of `proptest!`, optionally
+/// preceded by a configuration. Values are generated with
+/// `TestRunner::from_bytes()`, and failures are shrunk and reported as
+/// described for `fuzz::run_input()`.
+///
+/// The fuzz crate must depend on `libfuzzer-sys`.
+///
+/// ```ignore
+/// #![no_main]
+/// use proptest::prelude::*;
+///
+/// proptest::fuzz_target!(
+///     #![proptest_config(ProptestConfig {
+///         max_shrink_iters: 256, .. ProptestConfig::default()
+///     })]
+///     |(v in prop::collection::vec(any::<u8>(), 0..64))| {
+///         let decoded = my_crate::decode(&my_crate::encode(&v));
+///         prop_assert_eq!(decoded, v);
+///     }
+/// );
+/// ```
+#[macro_export]
+macro_rules! fuzz_target {
+    (#![proptest_config($config:expr)]
+     |($($parm:pat in $strategy:expr),+ $(,)?)| $body:block) => {
+        ::libfuzzer_sys::fuzz_target!(|data: &[u8]| {
+            $crate::fuzz_target!(@_RUN $config, data,
+                                 ($($parm in $strategy),+) $body);
+        });
+    };
+
+    (|($($parm:pat in $strategy:expr),+ $(,)?)| $body:block) => {
+        $crate::fuzz_target!(
+            #![proptest_config($crate::test_runner::Config::default())]
+            |($($parm in $strategy),+)| $body);
+    };
+
+    (@_RUN $config:expr, $data:expr,
+     ($($parm:pat in $strategy:expr),+) $body:block) => {{
+        let names = $crate::proptest_helper!(@_WRAPSTR ($($parm),*));
+        $crate::fuzz::run_input(
+            $config,
+            $data,
+            &$crate::strategy::Strategy::prop_map(
+                $crate::proptest_helper!(@_WRAP ($($strategy)*)),
+                |values| $crate::sugar::NamedArguments(names, values)),
+            |$crate::sugar::NamedArguments(
+                _, $crate::proptest_helper!(@_WRAPPAT ($($parm),*)))|
+            {
+                $crate::proptest_helper!(@_RESULT [] $body)
+            });
+    }};
+}
+
+/// Runs a persistent-mode AFL loop which checks a property against values
+/// generated from each of AFL's inputs.
+///
+/// This is the AFL counterpart of `fuzz_target!`, and takes the property in
+/// the same form. It is used in place of `afl::fuzz!` in the `main` function
+/// of a program built with `cargo afl build`, which must depend on `afl`.
+///
+/// Failing inputs are shrunk, and the minimal failing value is reported
+/// before the crash is recorded. AFL does not show the report while fuzzing;
+/// use `replay_file()` to see it for a file from the `crashes` directory.
+///
+/// ```ignore
+/// use proptest::prelude::*;
+///
+/// fn main() {
+///     proptest::afl_fuzz!(|(v in prop::collection::vec(any::<u8>(), 0..64))| {
+///         let decoded = my_crate::decode(&my_crate::encode(&v));
+///         prop_assert_eq!(decoded, v);
+///     });
+/// }
+/// ```
+#[cfg(feature = "afl")]
+#[cfg_attr(docsrs, doc(cfg(feature = "afl")))]
+#[macro_export]
+macro_rules! afl_fuzz {
+    (#![proptest_config($config:expr)]
+     |($($parm:pat in $strategy:expr),+ $(,)?)| $body:block) => {
+        ::afl::fuzz!(|data: &[u8]| {
+            $crate::fuzz_target!(@_RUN $config, data,
+                                 ($($parm in $strategy),+) $body);
+        });
+    };
+
+    (|($($parm:pat in $strategy:expr),+ $(,)?)| $body:block) => {
+        $crate::afl_fuzz!(
+            #![proptest_config($crate::test_runner::Config::default())]
+            |($($parm in $strategy),+)| $body);
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decode, replay_file, run_input};
+    use crate::collection::vec;
+    use crate::strategy::{Just, NewTree, Strategy};
+    use crate::test_runner::{Config, TestCaseError, TestError, TestRunner};
+    use std::panic::catch_unwind;
+    use std::string::String;
+    use std::{env, fs, process};
+
+    fn panic_message(data: &[u8]) -> Option<String> {
+        catch_unwind(|| {
+            crate::fuzz_target!(@_RUN Config::default(), data,
+                                (a in 0..1000u32, b in 0..1000u32) {
+                prop_assert!(a < 100 || b < 100);
+            });
+        })
+        .err()
+        .map(|what| *what.downcast::<String>().unwrap())
+    }
+
+    #[test]
+    fn passing_inputs_return() {
+        assert_eq!(None, panic_message(b""));
+        run_input(Config::default(), b"\xff\xff", &(0..10u8), |_| {
+            Err(TestCaseError::reject("ignored"))
+        });
+    }
+
+    #[test]
+    fn failing_inputs_panic_with_minimal_value() {
+        let message = panic_message(&[0xff; 32]).unwrap();
+        assert!(message.contains("Test failed"), "{}", message);
+        assert!(message.contains("a = 100"), "{}", message);
+        assert!(message.contains("b = 100"), "{}", message);
+    }
+
+    #[derive(Debug)]
+    struct PanickingStrategy;
+
+    impl Strategy for PanickingStrategy {
+        type Tree = Just<u8>;
+        type Value = u8;
+
+        fn new_tree(&self, _: &mut TestRunner) -> NewTree<Self> {
+            panic!("strategy panicked")
+        }
+    }
+
+    #[test]
+    fn strategy_panics_are_propagated() {
+        let what = catch_unwind(|| {
+            run_input(Config::default(), b"", &PanickingStrategy, |_| Ok(()))
+        })
+        .unwrap_err();
+        assert_eq!(Some(&"strategy panicked"), what.downcast_ref::<&str>());
+    }
+
+    #[test]
+    fn saved_inputs_replay_and_shrink() {
+        let strategy = vec(0..100u8, 0..8);
+        let data = [0xff; 64];
+        let value = decode(Config::default(), &data, &strategy).unwrap();
+        assert_eq!(vec![99; 7], value);
+
+        let path = env::temp_dir()
+            .join(format!("proptest-fuzz-crash-{}", process::id()));
+        fs::write(&path, data).unwrap();
+        let result = replay_file(Config::default(), &path, &strategy, |v| {
+            prop_assert!(v.iter().all(|&n| n < 50));
+            Ok(())
+        });
+        fs::remove_file(&path).unwrap();
+        match result {
+            Err(TestError::Fail(_, v)) => assert_eq!(vec![50], v),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+
+        let result =
+            replay_file(Config::default(), &path, &strategy, |_| Ok(()));
+        assert!(matches!(result, Err(TestError::Abort(_))));
+    }
+}
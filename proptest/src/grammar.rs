@@ -0,0 +1,856 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating strings from context-free grammars.
+//!
+//! A [`Grammar`] is a set of named rules, each of which is an [`Expr`] built
+//! from literals, regular expressions, references to rules, sequences,
+//! choices and repetitions. Grammars can be built with the constructors on
+//! `Expr`, or parsed from an EBNF-like notation with [`Grammar::parse`]:
+//!
+//! ```
+//! use proptest::grammar::Grammar;
+//! use proptest::prelude::*;
+//!
+//! let arithmetic = Grammar::parse(r#"
+//!     expr   = term (("+" | "-") term)* ;
+//!     term   = factor (("*" | "/") factor)* ;
+//!     factor = /[0-9]{1,3}/ | "(" expr ")" ;
+//! "#).unwrap();
+//!
+//! proptest!(|(s in arithmetic.strategy("expr").unwrap())| {
+//!     prop_assert!(!s.is_empty());
+//! });
+//! ```
+//!
+//! Recursion is bounded by `GrammarConfig::max_depth`: once the depth is
+//! reached, only the alternatives which can finish soonest are generated.
+//! Shrinking replaces productions by simpler alternatives and removes
+//! repeated items.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::collection::vec;
+use crate::std_facade::{Box, String, ToOwned, Vec};
+use crate::strategy::*;
+use crate::string::{self, string_regex};
+use crate::test_runner::*;
+
+/// Errors which may occur when parsing a grammar or preparing a strategy
+/// from it.
+#[derive(Debug)]
+pub enum Error {
+    /// The grammar text is not syntactically valid. Contains the byte
+    /// offset of the error and a description of it.
+    Syntax(usize, &'static str),
+    /// A rule is referenced but never defined.
+    UndefinedRule(String),
+    /// A rule can never finish expanding, as every alternative refers back
+    /// to itself.
+    NonTerminating(String),
+    /// A regular expression in the grammar cannot be used for generation.
+    Regex(Box<string::Error>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Syntax(offset, message) => {
+                write!(f, "syntax error at byte {}: {}", offset, message)
+            }
+            Error::UndefinedRule(name) => {
+                write!(f, "rule `{}` is not defined", name)
+            }
+            Error::NonTerminating(name) => {
+                write!(f, "rule `{}` can never finish expanding", name)
+            }
+            Error::Regex(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Regex(err) => Some(&**err),
+            _ => None,
+        }
+    }
+}
+
+impl From<string::Error> for Error {
+    fn from(err: string::Error) -> Error {
+        Error::Regex(Box::new(err))
+    }
+}
+
+/// An expression on the right-hand side of a grammar rule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    /// Generates exactly the given string.
+    Literal(String),
+    /// Generates strings matching the given regular expression, as with
+    /// `string::string_regex()`.
+    Regex(String),
+    /// Generates strings from the rule with the given name.
+    Rule(String),
+    /// Generates each expression in turn and concatenates the results.
+    Seq(Vec<Expr>),
+    /// Generates one of the expressions.
+    Choice(Vec<Expr>),
+    /// Generates the expression repeatedly and concatenates the results.
+    Repeat {
+        /// The expression to repeat.
+        expr: Box<Expr>,
+        /// The minimum number of repetitions.
+        min: usize,
+        /// The maximum number of repetitions, or `None` if unbounded, in
+        /// which case `GrammarConfig::max_repeats` applies.
+        max: Option<usize>,
+    },
+}
+
+impl Expr {
+    /// Creates an `Expr::Literal`.
+    pub fn literal(s: impl Into<String>) -> Self {
+        Expr::Literal(s.into())
+    }
+
+    /// Creates an `Expr::Regex`.
+    pub fn regex(regex: impl Into<String>) -> Self {
+        Expr::Regex(regex.into())
+    }
+
+    /// Creates an `Expr::Rule`.
+    pub fn rule(name: impl Into<String>) -> Self {
+        Expr::Rule(name.into())
+    }
+
+    /// Creates an `Expr::Seq`.
+    pub fn seq(exprs: impl IntoIterator<Item = Expr>) -> Self {
+        Expr::Seq(exprs.into_iter().collect())
+    }
+
+    /// Creates an `Expr::Choice`.
+    pub fn choice(exprs: impl IntoIterator<Item = Expr>) -> Self {
+        Expr::Choice(exprs.into_iter().collect())
+    }
+
+    /// Creates an `Expr::Repeat` of this expression with between `min` and
+    /// `max` repetitions.
+    pub fn repeat(self, min: usize, max: Option<usize>) -> Self {
+        Expr::Repeat {
+            expr: Box::new(self),
+            min,
+            max,
+        }
+    }
+
+    /// Makes this expression optional, like `?` in the text notation.
+    pub fn optional(self) -> Self {
+        self.repeat(0, Some(1))
+    }
+
+    /// Repeats this expression any number of times, like `*` in the text
+    /// notation.
+    pub fn many(self) -> Self {
+        self.repeat(0, None)
+    }
+
+    /// Repeats this expression at least once, like `+` in the text notation.
+    pub fn many1(self) -> Self {
+        self.repeat(1, None)
+    }
+}
+
+/// Configuration for the strategies created from a `Grammar`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GrammarConfig {
+    /// The number of nested rule expansions after which only the
+    /// alternatives which finish soonest are chosen. Those alternatives also
+    /// become more likely the deeper the expansion is. The default is 8.
+    pub max_depth: u32,
+    /// The maximum number of repetitions for `*`, `+` and other unbounded
+    /// repetitions, unless their minimum is higher. The default is 4.
+    pub max_repeats: usize,
+}
+
+impl Default for GrammarConfig {
+    fn default() -> Self {
+        GrammarConfig {
+            max_depth: 8,
+            max_repeats: 4,
+        }
+    }
+}
+
+/// A set of named rules describing a language.
+///
+/// See the [module documentation](index.html) for an overview.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Grammar {
+    rules: Vec<(String, Expr)>,
+}
+
+impl Grammar {
+    /// Creates a grammar without any rules.
+    pub fn new() -> Self {
+        Grammar::default()
+    }
+
+    /// Adds a rule to the grammar. If a rule with the same name already
+    /// exists, `expr` becomes an additional alternative of it.
+    pub fn rule(mut self, name: impl Into<String>, expr: Expr) -> Self {
+        let name = name.into();
+        match self.rules.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, Expr::Choice(alts))) => alts.push(expr),
+            Some((_, existing)) => {
+                let first = core::mem::replace(existing, Expr::Seq(vec![]));
+                *existing = Expr::Choice(vec![first, expr]);
+            }
+            None => self.rules.push((name, expr)),
+        }
+        self
+    }
+
+    /// Parses a grammar from an EBNF-like notation.
+    ///
+    /// A grammar is a list of rules of the form `name = expr ;`, where `::=`
+    /// may be used instead of `=` and names may be written as `<name>`.
+    /// Expressions are made of:
+    ///
+    /// - `"text"` or `'text'`, a literal, with `\\`, `\"`, `\'`, `\n`, `\r`
+    ///   and `\t` escapes;
+    /// - `/regex/`, a regular expression, where `\/` stands for `/`;
+    /// - `name`, a reference to a rule;
+    /// - `a b`, a sequence, and `a | b`, a choice;
+    /// - `( ... )` for grouping, `[ ... ]` for an optional expression and
+    ///   `{ ... }` for zero or more repetitions;
+    /// - the postfix operators `?`, `*` and `+`.
+    ///
+    /// Comments start with `#` and extend to the end of the line.
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let mut parser = Parser { text, pos: 0 };
+        let mut grammar = Grammar::new();
+        while !parser.at_end() {
+            let name = parser
+                .name()?
+                .ok_or(Error::Syntax(parser.pos, "expected a rule name"))?;
+            if !parser.eat("::=") && !parser.eat("=") {
+                return Err(Error::Syntax(parser.pos, "expected `=`"));
+            }
+            let expr = parser.choice()?;
+            if !parser.eat(";") {
+                return Err(Error::Syntax(parser.pos, "expected `;`"));
+            }
+            grammar = grammar.rule(name, expr);
+        }
+        Ok(grammar)
+    }
+
+    /// Creates a strategy which generates strings from the rule `start`,
+    /// using the default `GrammarConfig`.
+    pub fn strategy(&self, start: &str) -> Result<GrammarStrategy, Error> {
+        self.strategy_with_config(start, &GrammarConfig::default())
+    }
+
+    /// Like `strategy()`, but with an explicit configuration.
+    ///
+    /// Fails if `start` or any rule referenced by the grammar is not
+    /// defined, if any rule can never finish expanding, or if a regular
+    /// expression is invalid or unsupported.
+    pub fn strategy_with_config(
+        &self,
+        start: &str,
+        config: &GrammarConfig,
+    ) -> Result<GrammarStrategy, Error> {
+        let start = self
+            .rules
+            .iter()
+            .position(|(name, _)| name == start)
+            .ok_or_else(|| Error::UndefinedRule(start.to_owned()))?;
+        let mut builder = Builder::new(self, config)?;
+        Ok(GrammarStrategy(builder.rule(start, config.max_depth)?))
+    }
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates strings from a grammar.
+    ///
+    /// Created by `Grammar::strategy()`.
+    #[derive(Clone, Debug)]
+    pub struct GrammarStrategy[][](SBoxedStrategy<String>)
+        -> GrammarValueTree;
+    /// `ValueTree` corresponding to `GrammarStrategy`.
+    pub struct GrammarValueTree[][](Box<dyn ValueTree<Value = String>>)
+        -> String;
+}
+
+/// Sentinel height of rules which never finish expanding.
+const INFINITE: u32 = u32::MAX;
+
+struct Builder<'a> {
+    grammar: &'a Grammar,
+    config: &'a GrammarConfig,
+    indices: HashMap<&'a str, usize>,
+    /// The minimum number of nested rule expansions needed to finish
+    /// expanding each rule.
+    heights: Vec<u32>,
+    regexes: HashMap<&'a str, SBoxedStrategy<String>>,
+    rules: HashMap<(usize, u32), SBoxedStrategy<String>>,
+}
+
+impl<'a> Builder<'a> {
+    fn new(
+        grammar: &'a Grammar,
+        config: &'a GrammarConfig,
+    ) -> Result<Self, Error> {
+        let mut builder = Builder {
+            grammar,
+            config,
+            indices: grammar
+                .rules
+                .iter()
+                .enumerate()
+                .map(|(ix, (name, _))| (name.as_str(), ix))
+                .collect(),
+            heights: vec![INFINITE; grammar.rules.len()],
+            regexes: HashMap::new(),
+            rules: HashMap::new(),
+        };
+        for (_, expr) in &grammar.rules {
+            builder.check(expr)?;
+        }
+
+        // Iterate to a fixed point; every pass finalises at least one more
+        // rule, so this takes at most as many passes as there are rules.
+        loop {
+            let mut changed = false;
+            for ix in 0..grammar.rules.len() {
+                let height = builder.height(&grammar.rules[ix].1);
+                if height < builder.heights[ix] {
+                    builder.heights[ix] = height;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        if let Some(ix) = builder.heights.iter().position(|&h| h == INFINITE) {
+            return Err(Error::NonTerminating(grammar.rules[ix].0.clone()));
+        }
+
+        Ok(builder)
+    }
+
+    /// Checks that all referenced rules exist and compiles the regexes.
+    fn check(&mut self, expr: &'a Expr) -> Result<(), Error> {
+        match expr {
+            Expr::Literal(_) => (),
+            Expr::Regex(regex) => {
+                if !self.regexes.contains_key(regex.as_str()) {
+                    let strategy = string_regex(regex)?.sboxed();
+                    self.regexes.insert(regex, strategy);
+                }
+            }
+            Expr::Rule(name) => {
+                if !self.indices.contains_key(name.as_str()) {
+                    return Err(Error::UndefinedRule(name.clone()));
+                }
+            }
+            Expr::Seq(exprs) | Expr::Choice(exprs) => {
+                for expr in exprs {
+                    self.check(expr)?;
+                }
+            }
+            Expr::Repeat { expr, .. } => self.check(expr)?,
+        }
+        Ok(())
+    }
+
+    fn height(&self, expr: &Expr) -> u32 {
+        match expr {
+            Expr::Literal(_) | Expr::Regex(_) => 0,
+            Expr::Rule(name) => {
+                self.heights[self.indices[name.as_str()]].saturating_add(1)
+            }
+            Expr::Seq(exprs) => {
+                exprs.iter().map(|e| self.height(e)).max().unwrap_or(0)
+            }
+            Expr::Choice(exprs) => {
+                exprs.iter().map(|e| self.height(e)).min().unwrap_or(0)
+            }
+            Expr::Repeat { min: 0, .. } => 0,
+            Expr::Repeat { expr, .. } => self.height(expr),
+        }
+    }
+
+    fn rule(
+        &mut self,
+        ix: usize,
+        depth: u32,
+    ) -> Result<SBoxedStrategy<String>, Error> {
+        if let Some(strategy) = self.rules.get(&(ix, depth)) {
+            return Ok(strategy.clone());
+        }
+
+        let grammar = self.grammar;
+        let strategy =
+            self.expr(&grammar.rules[ix].1, depth.saturating_sub(1))?;
+        self.rules.insert((ix, depth), strategy.clone());
+        Ok(strategy)
+    }
+
+    /// Builds the strategy for `expr`, which may use `depth` more nested
+    /// rule expansions.
+    fn expr(
+        &mut self,
+        expr: &Expr,
+        depth: u32,
+    ) -> Result<SBoxedStrategy<String>, Error> {
+        Ok(match expr {
+            Expr::Literal(s) => Just(s.clone()).sboxed(),
+            Expr::Regex(regex) => self.regexes[regex.as_str()].clone(),
+            Expr::Rule(name) => {
+                self.rule(self.indices[name.as_str()], depth)?
+            }
+            Expr::Seq(exprs) => {
+                let mut accum: Option<SBoxedStrategy<String>> = None;
+                for expr in exprs {
+                    let rhs = self.expr(expr, depth)?;
+                    accum = Some(match accum {
+                        None => rhs,
+                        Some(lhs) => (lhs, rhs)
+                            .prop_map(|(mut lhs, rhs)| {
+                                lhs.push_str(&rhs);
+                                lhs
+                            })
+                            .sboxed(),
+                    });
+                }
+                accum.unwrap_or_else(|| Just(String::new()).sboxed())
+            }
+            Expr::Choice(exprs) => {
+                // Only use the alternatives which can finish within the
+                // remaining depth, or else those which finish soonest. They
+                // are ordered by height so that shrinking moves towards the
+                // simplest productions, and the simplest ones become more
+                // likely as the depth increases so that the generated
+                // strings stay reasonably small.
+                let mut alts = exprs
+                    .iter()
+                    .map(|e| (self.height(e), e))
+                    .collect::<Vec<_>>();
+                alts.sort_by_key(|&(height, _)| height);
+                let lowest = match alts.first() {
+                    Some(&(lowest, _)) => lowest,
+                    None => return Ok(Just(String::new()).sboxed()),
+                };
+                let limit = lowest.max(depth);
+                let bias = 1 + self.config.max_depth.saturating_sub(depth);
+                let alts = alts
+                    .into_iter()
+                    .filter(|&(height, _)| height <= limit)
+                    .map(|(height, e)| {
+                        let weight = if height == lowest { bias } else { 1 };
+                        Ok((weight, self.expr(e, depth)?))
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                Union::new_weighted(alts).sboxed()
+            }
+            Expr::Repeat { expr, min, max } => {
+                let max = max.unwrap_or(self.config.max_repeats.max(*min));
+                if *min == 0 && self.height(expr) > depth {
+                    Just(String::new()).sboxed()
+                } else {
+                    vec(self.expr(expr, depth)?, *min..=max.max(*min))
+                        .prop_map(|parts| parts.concat())
+                        .sboxed()
+                }
+            }
+        })
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            if trimmed.starts_with('#') {
+                self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.rest().is_empty()
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn name(&mut self) -> Result<Option<String>, Error> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let bracketed = self.rest().starts_with('<');
+        let rest = if bracketed {
+            &self.rest()[1..]
+        } else {
+            self.rest()
+        };
+        let len = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+            .unwrap_or(rest.len());
+        if len == 0 || rest.starts_with(|c: char| c.is_ascii_digit()) {
+            return if bracketed {
+                Err(Error::Syntax(start, "expected a rule name"))
+            } else {
+                Ok(None)
+            };
+        }
+        let name = rest[..len].to_owned();
+        self.pos += len + bracketed as usize;
+        if bracketed && !self.eat(">") {
+            return Err(Error::Syntax(self.pos, "expected `>`"));
+        }
+        Ok(Some(name))
+    }
+
+    fn choice(&mut self) -> Result<Expr, Error> {
+        let mut alts = vec![self.seq()?];
+        while self.eat("|") {
+            alts.push(self.seq()?);
+        }
+        Ok(if alts.len() == 1 {
+            alts.pop().unwrap()
+        } else {
+            Expr::Choice(alts)
+        })
+    }
+
+    fn seq(&mut self) -> Result<Expr, Error> {
+        let mut items = vec![];
+        while let Some(item) = self.postfix()? {
+            items.push(item);
+        }
+        Ok(if items.len() == 1 {
+            items.pop().unwrap()
+        } else {
+            Expr::Seq(items)
+        })
+    }
+
+    fn postfix(&mut self) -> Result<Option<Expr>, Error> {
+        let mut expr = match self.primary()? {
+            Some(expr) => expr,
+            None => return Ok(None),
+        };
+        loop {
+            expr = if self.eat("?") {
+                expr.optional()
+            } else if self.eat("*") {
+                expr.many()
+            } else if self.eat("+") {
+                expr.many1()
+            } else {
+                return Ok(Some(expr));
+            };
+        }
+    }
+
+    fn primary(&mut self) -> Result<Option<Expr>, Error> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let expr = match self.rest().chars().next() {
+            Some(quote @ '"') | Some(quote @ '\'') => {
+                self.pos += 1;
+                Expr::Literal(self.quoted(quote)?)
+            }
+            Some('/') => {
+                self.pos += 1;
+                Expr::Regex(self.quoted('/')?)
+            }
+            Some('(') => {
+                self.pos += 1;
+                self.closed_by(")", "expected `)`")?
+            }
+            Some('[') => {
+                self.pos += 1;
+                self.closed_by("]", "expected `]`")?.optional()
+            }
+            Some('{') => {
+                self.pos += 1;
+                self.closed_by("}", "expected `}`")?.many()
+            }
+            _ => match self.name()? {
+                // A name followed by `=` starts the next rule, so the
+                // current one is missing its `;`.
+                Some(_) if self.eat("=") || self.eat("::=") => {
+                    self.pos = start;
+                    return Ok(None);
+                }
+                Some(name) => Expr::Rule(name),
+                None => return Ok(None),
+            },
+        };
+        Ok(Some(expr))
+    }
+
+    fn closed_by(
+        &mut self,
+        close: &str,
+        message: &'static str,
+    ) -> Result<Expr, Error> {
+        let expr = self.choice()?;
+        if self.eat(close) {
+            Ok(expr)
+        } else {
+            Err(Error::Syntax(self.pos, message))
+        }
+    }
+
+    /// Reads the contents of a literal or regex up to the closing `quote`,
+    /// which has already been opened.
+    fn quoted(&mut self, quote: char) -> Result<String, Error> {
+        let start = self.pos - 1;
+        let mut out = String::new();
+        let mut chars = self.rest().char_indices();
+        while let Some((ix, c)) = chars.next() {
+            if c == quote {
+                self.pos += ix + 1;
+                return Ok(out);
+            }
+            if c != '\\' {
+                out.push(c);
+                continue;
+            }
+            let escaped = match chars.next() {
+                Some((_, c)) => c,
+                None => break,
+            };
+            if quote == '/' {
+                // Leave escapes to the regex parser, except for the
+                // delimiter itself.
+                if escaped != '/' {
+                    out.push('\\');
+                }
+                out.push(escaped);
+            } else {
+                out.push(match escaped {
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    '\\' | '"' | '\'' => escaped,
+                    _ => {
+                        return Err(Error::Syntax(
+                            self.pos + ix,
+                            "unknown escape sequence",
+                        ))
+                    }
+                });
+            }
+        }
+        Err(Error::Syntax(start, "unterminated literal"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ARITHMETIC: &str = r#"
+        # Operator precedence is encoded in the rules.
+        expr   = term (("+" | "-") term)* ;
+        term   = factor (("*" | "/") factor)* ;
+        factor = /[0-9]/ | "(" expr ")" | "-" factor ;
+    "#;
+
+    /// Recognises `ARITHMETIC`, returning the rest of the input.
+    fn parse_expr(s: &str) -> Option<&str> {
+        fn factor(s: &str) -> Option<&str> {
+            if let Some(rest) = s.strip_prefix('(') {
+                parse_expr(rest)?.strip_prefix(')')
+            } else if let Some(rest) = s.strip_prefix('-') {
+                factor(rest)
+            } else if s.starts_with(|c: char| c.is_ascii_digit()) {
+                Some(&s[1..])
+            } else {
+                None
+            }
+        }
+        fn chain<'a>(
+            mut s: &'a str,
+            ops: &[char],
+            item: fn(&str) -> Option<&str>,
+        ) -> Option<&'a str> {
+            s = item(s)?;
+            while let Some(rest) = s.strip_prefix(ops) {
+                s = item(rest)?;
+            }
+            Some(s)
+        }
+        chain(s, &['+', '-'], |s| chain(s, &['*', '/'], factor))
+    }
+
+    fn nesting(s: &str) -> usize {
+        s.chars()
+            .scan(0usize, |depth, c| {
+                match c {
+                    '(' => *depth += 1,
+                    ')' => *depth -= 1,
+                    _ => (),
+                }
+                Some(*depth)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn generates_sentences_of_the_grammar() {
+        let grammar = Grammar::parse(ARITHMETIC).unwrap();
+        let config = GrammarConfig {
+            max_depth: 10,
+            ..GrammarConfig::default()
+        };
+        let strategy = grammar.strategy_with_config("expr", &config).unwrap();
+
+        let mut runner = TestRunner::deterministic();
+        let mut max_nesting = 0;
+        for _ in 0..256 {
+            let mut tree = strategy.new_tree(&mut runner).unwrap();
+            for _ in 0..64 {
+                let s = tree.current();
+                assert_eq!(Some(""), parse_expr(&s), "{:?}", s);
+                max_nesting = max_nesting.max(nesting(&s));
+                if !tree.simplify() {
+                    break;
+                }
+            }
+        }
+        // Each level of parentheses takes three rule expansions.
+        assert!(max_nesting >= 2);
+        assert!(max_nesting <= 4);
+    }
+
+    #[test]
+    fn shrinks_by_removing_productions() {
+        let grammar = Grammar::parse(ARITHMETIC).unwrap();
+        let strategy = grammar.strategy("expr").unwrap();
+
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..64 {
+            let mut tree = strategy.new_tree(&mut runner).unwrap();
+            while tree.simplify() {}
+            assert_eq!("0", tree.current());
+        }
+    }
+
+    #[test]
+    fn builder_and_parser_agree() {
+        let built = Grammar::new()
+            .rule(
+                "list",
+                Expr::seq(vec![
+                    Expr::literal("["),
+                    Expr::seq(vec![
+                        Expr::rule("item"),
+                        Expr::seq(vec![
+                            Expr::literal(", "),
+                            Expr::rule("item"),
+                        ])
+                        .many(),
+                    ])
+                    .optional(),
+                    Expr::literal("]"),
+                ]),
+            )
+            .rule("item", Expr::regex("[a-z]+"))
+            .rule("item", Expr::rule("list"));
+        let parsed = Grammar::parse(
+            r#"
+            <list> ::= "[" [ <item> { ", " <item> } ] "]" ;
+            item = /[a-z]+/ ;
+            item = list ;
+        "#,
+        )
+        .unwrap();
+        assert_eq!(built, parsed);
+    }
+
+    #[test]
+    fn parses_escapes() {
+        let grammar = Grammar::parse(r#"a = "\"\n" '\'' /\/[\d]/ ;"#).unwrap();
+        assert_eq!(
+            Grammar::new().rule(
+                "a",
+                Expr::seq(vec![
+                    Expr::literal("\"\n"),
+                    Expr::literal("'"),
+                    Expr::regex(r"/[\d]"),
+                ])
+            ),
+            grammar
+        );
+    }
+
+    #[test]
+    fn reports_errors() {
+        let syntax = |text| match Grammar::parse(text) {
+            Err(Error::Syntax(offset, _)) => offset,
+            other => panic!("unexpected result {:?}", other),
+        };
+        assert_eq!(8, syntax("a = \"x\" b = \"y\";"));
+        assert_eq!(4, syntax("a = \"x;"));
+        assert_eq!(6, syntax("a = (b;"));
+
+        let grammar = Grammar::parse("a = b ;").unwrap();
+        assert!(matches!(
+            grammar.strategy("a"),
+            Err(Error::UndefinedRule(ref name)) if name == "b"
+        ));
+        assert!(matches!(
+            grammar.strategy("c"),
+            Err(Error::UndefinedRule(ref name)) if name == "c"
+        ));
+
+        let grammar =
+            Grammar::parse("a = \"x\" b ; b = a | \"(\" b \")\" ;").unwrap();
+        assert!(matches!(
+            grammar.strategy("a"),
+            Err(Error::NonTerminating(_))
+        ));
+
+        let grammar = Grammar::parse("a = /^x/ ;").unwrap();
+        assert!(matches!(grammar.strategy("a"), Err(Error::Regex(_))));
+    }
+}
@@ -0,0 +1,322 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating JSON documents as `serde_json::Value`s.
+//!
+//! ```
+//! use proptest::json;
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(doc in json::value())| {
+//!     let text = serde_json::to_string(&doc).unwrap();
+//!     prop_assert!(serde_json::from_str::<serde_json::Value>(&text).is_ok());
+//! });
+//! ```
+//!
+//! Documents shrink by removing array elements and object entries, by
+//! replacing nested values with simpler ones and ultimately by `null`.
+
+use serde_json::{Map, Number, Value};
+
+use crate::bool;
+use crate::collection::vec;
+use crate::num;
+use crate::std_facade::{Box, String};
+use crate::strategy::*;
+use crate::string::string_regex;
+use crate::test_runner::*;
+
+/// Which kinds of numbers to generate.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Numbers {
+    /// Only integers, in the range of `i64`.
+    Integers,
+    /// Only finite floating-point numbers.
+    Floats,
+    /// Both integers and floating-point numbers.
+    #[default]
+    Any,
+}
+
+/// Configuration for the strategies in this module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct JsonConfig {
+    /// The maximum number of levels of nested arrays and objects. The
+    /// default is 4.
+    pub max_depth: u32,
+    /// The desired total number of values in a document, as for
+    /// `Strategy::prop_recursive()`. The default is 64.
+    pub desired_size: u32,
+    /// The maximum number of elements in an array or entries in an object.
+    /// The default is 8.
+    pub max_collection_size: usize,
+    /// The regular expression for string values. The default is
+    /// `\PC{0,16}`, non-control characters.
+    pub string_regex: &'static str,
+    /// The regular expression for object keys. The default is
+    /// `[a-z_][a-z0-9_]{0,8}`.
+    pub key_regex: &'static str,
+    /// Which kinds of numbers to generate.
+    pub numbers: Numbers,
+}
+
+impl Default for JsonConfig {
+    fn default() -> Self {
+        JsonConfig {
+            max_depth: 4,
+            desired_size: 64,
+            max_collection_size: 8,
+            string_regex: "\\PC{0,16}",
+            key_regex: "[a-z_][a-z0-9_]{0,8}",
+            numbers: Numbers::Any,
+        }
+    }
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `serde_json::Value`s.
+    ///
+    /// Created by the functions in this module.
+    #[derive(Debug)]
+    pub struct JsonStrategy[][](BoxedStrategy<Value>) -> JsonValueTree;
+    /// `ValueTree` corresponding to `JsonStrategy`.
+    pub struct JsonValueTree[][](Box<dyn ValueTree<Value = Value>>) -> Value;
+}
+
+/// Creates a strategy which generates arbitrary JSON values, using the
+/// default `JsonConfig`.
+pub fn value() -> JsonStrategy {
+    value_with_config(&JsonConfig::default())
+}
+
+/// Creates a strategy which generates arbitrary JSON values as described by
+/// `config`.
+///
+/// ## Panics
+///
+/// Panics if `config.string_regex` or `config.key_regex` is not a valid
+/// regular expression for generation.
+pub fn value_with_config(config: &JsonConfig) -> JsonStrategy {
+    let config = *config;
+    JsonStrategy(
+        scalar(&config)
+            .prop_recursive(
+                config.max_depth,
+                config.desired_size,
+                config.max_collection_size as u32,
+                move |inner| {
+                    Union::new(vec![
+                        array_of(inner.clone(), &config),
+                        object_of(inner, &config),
+                    ])
+                },
+            )
+            .boxed(),
+    )
+}
+
+/// Creates a strategy which generates JSON arrays whose elements are
+/// arbitrary values as described by `config`.
+///
+/// The array itself counts towards `config.max_depth`.
+///
+/// ## Panics
+///
+/// Panics under the same conditions as `value_with_config()`.
+pub fn array(config: &JsonConfig) -> JsonStrategy {
+    JsonStrategy(array_of(nested(config), config).boxed())
+}
+
+/// Creates a strategy which generates JSON objects whose values are
+/// arbitrary values as described by `config`.
+///
+/// The object itself counts towards `config.max_depth`.
+///
+/// ## Panics
+///
+/// Panics under the same conditions as `value_with_config()`.
+pub fn object(config: &JsonConfig) -> JsonStrategy {
+    JsonStrategy(object_of(nested(config), config).boxed())
+}
+
+/// The values which may be nested within a top-level array or object.
+fn nested(config: &JsonConfig) -> BoxedStrategy<Value> {
+    value_with_config(&JsonConfig {
+        max_depth: config.max_depth.saturating_sub(1),
+        ..*config
+    })
+    .0
+}
+
+fn scalar(config: &JsonConfig) -> BoxedStrategy<Value> {
+    let integer = num::i64::ANY.prop_map(Value::from).boxed();
+    let float = num::f64::FINITE
+        .prop_map(|f| {
+            Value::Number(Number::from_f64(f).expect("non-finite float"))
+        })
+        .boxed();
+    let number = match config.numbers {
+        Numbers::Integers => integer,
+        Numbers::Floats => float,
+        Numbers::Any => Union::new(vec![integer, float]).boxed(),
+    };
+
+    prop_oneof![
+        Just(Value::Null),
+        bool::ANY.prop_map(Value::Bool),
+        number,
+        string_regex(config.string_regex)
+            .unwrap()
+            .prop_map(Value::String),
+    ]
+    .boxed()
+}
+
+fn array_of(
+    element: BoxedStrategy<Value>,
+    config: &JsonConfig,
+) -> BoxedStrategy<Value> {
+    vec(element, 0..=config.max_collection_size)
+        .prop_map(Value::Array)
+        .boxed()
+}
+
+fn object_of(
+    element: BoxedStrategy<Value>,
+    config: &JsonConfig,
+) -> BoxedStrategy<Value> {
+    let key = string_regex(config.key_regex).unwrap();
+    vec((key, element), 0..=config.max_collection_size)
+        .prop_map(|entries| {
+            Value::Object(entries.into_iter().collect::<Map<String, Value>>())
+        })
+        .boxed()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn depth(value: &Value) -> u32 {
+        match value {
+            Value::Array(elements) => {
+                1 + elements.iter().map(depth).max().unwrap_or(0)
+            }
+            Value::Object(entries) => {
+                1 + entries.values().map(depth).max().unwrap_or(0)
+            }
+            _ => 0,
+        }
+    }
+
+    fn for_each_value(value: &Value, f: &mut impl FnMut(&Value)) {
+        f(value);
+        match value {
+            Value::Array(elements) => {
+                elements.iter().for_each(|v| for_each_value(v, f))
+            }
+            Value::Object(entries) => {
+                entries.values().for_each(|v| for_each_value(v, f))
+            }
+            _ => (),
+        }
+    }
+
+    #[test]
+    fn respects_config() {
+        let config = JsonConfig {
+            max_depth: 3,
+            max_collection_size: 5,
+            numbers: Numbers::Integers,
+            string_regex: "[xyz]{1,3}",
+            key_regex: "k[0-9]",
+            ..JsonConfig::default()
+        };
+        let mut runner = TestRunner::deterministic();
+        let mut max_depth = 0;
+        for _ in 0..256 {
+            let doc = value_with_config(&config)
+                .new_tree(&mut runner)
+                .unwrap()
+                .current();
+            max_depth = max_depth.max(depth(&doc));
+            for_each_value(&doc, &mut |v| match v {
+                Value::Number(n) => assert!(n.is_i64(), "{}", n),
+                Value::String(s) => {
+                    assert!((1..=3).contains(&s.len()), "{:?}", s);
+                    assert!(s.chars().all(|c| "xyz".contains(c)), "{:?}", s);
+                }
+                Value::Array(elements) => assert!(elements.len() <= 5),
+                Value::Object(entries) => {
+                    assert!(entries.len() <= 5);
+                    for key in entries.keys() {
+                        assert!(key.len() == 2 && key.starts_with('k'));
+                    }
+                }
+                _ => (),
+            });
+
+            let text = serde_json::to_string(&doc).unwrap();
+            assert_eq!(doc, serde_json::from_str::<Value>(&text).unwrap());
+        }
+        assert_eq!(3, max_depth);
+    }
+
+    #[test]
+    fn array_and_object_generate_collections() {
+        let mut runner = TestRunner::deterministic();
+        let config = JsonConfig::default();
+        for _ in 0..64 {
+            let doc = array(&config).new_tree(&mut runner).unwrap().current();
+            assert!(doc.is_array());
+            assert!(depth(&doc) <= config.max_depth);
+            let doc = object(&config).new_tree(&mut runner).unwrap().current();
+            assert!(doc.is_object());
+            assert!(depth(&doc) <= config.max_depth);
+        }
+    }
+
+    #[test]
+    fn shrinks_by_removing_entries() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..64 {
+            let mut tree = value().new_tree(&mut runner).unwrap();
+            while tree.simplify() {}
+            assert_eq!(Value::Null, tree.current());
+
+            // An array which must hold at least one `true` shrinks to
+            // exactly that.
+            let mut tree =
+                array(&JsonConfig::default()).new_tree(&mut runner).unwrap();
+            if !tree
+                .current()
+                .as_array()
+                .unwrap()
+                .contains(&Value::Bool(true))
+            {
+                continue;
+            }
+            loop {
+                let accepted = tree
+                    .current()
+                    .as_array()
+                    .unwrap()
+                    .contains(&Value::Bool(true));
+                if !(if accepted {
+                    tree.simplify()
+                } else {
+                    tree.complicate()
+                }) {
+                    break;
+                }
+            }
+            assert_eq!(serde_json::json!([true]), tree.current());
+        }
+    }
+}
@@ -0,0 +1,488 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating rectangular matrices.
+//!
+//! Matrices are represented as a `Vec` of rows, each a `Vec` of the same
+//! length. Unlike nesting `collection::vec()` strategies, or choosing the
+//! dimensions first with `prop_flat_map()`, the strategies here keep the
+//! matrices rectangular while shrinking, by removing whole rows and columns.
+//! Where two matrices share a dimension, as in `matrix_pair()`, removing a
+//! row or column of that dimension removes it from both, so the matrices
+//! stay compatible.
+
+use crate::bits::{BitSetLike, VarBitSet};
+use crate::collection::SizeRange;
+use crate::num::sample_uniform_incl;
+use crate::std_facade::Vec;
+use crate::strategy::*;
+use crate::test_runner::*;
+
+/// One dimension shared by any number of matrices.
+#[derive(Clone, Debug)]
+struct Dim {
+    included: VarBitSet,
+    min_size: usize,
+}
+
+impl Dim {
+    fn new(runner: &mut TestRunner, size: &SizeRange) -> Self {
+        let (start, end) = size.start_end_incl();
+        Dim {
+            included: VarBitSet::saturated(sample_uniform_incl(
+                runner, start, end,
+            )),
+            min_size: start,
+        }
+    }
+}
+
+/// The elements of one matrix, indexed by the dimensions of its rows and its
+/// columns.
+#[derive(Clone, Debug)]
+struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    elements: Vec<Vec<T>>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Shrink {
+    DeleteIndex(usize, usize),
+    ShrinkElement(usize, usize, usize),
+}
+
+/// Shrinks a set of matrices with shared dimensions, first by deleting rows
+/// and columns and then by shrinking the remaining elements.
+#[derive(Clone, Debug)]
+struct LinkedGrids<T> {
+    dims: Vec<Dim>,
+    grids: Vec<Grid<T>>,
+    shrink: Shrink,
+    prev_shrink: Option<Shrink>,
+}
+
+impl<T: ValueTree> LinkedGrids<T> {
+    fn new<S: Strategy<Tree = T>>(
+        runner: &mut TestRunner,
+        element: &S,
+        sizes: &[&SizeRange],
+        grids: &[(usize, usize)],
+    ) -> Result<Self, Reason> {
+        let dims = sizes
+            .iter()
+            .map(|size| Dim::new(runner, size))
+            .collect::<Vec<_>>();
+
+        let mut new_grids = Vec::with_capacity(grids.len());
+        for &(rows, cols) in grids {
+            let mut elements = Vec::with_capacity(dims[rows].included.len());
+            for _ in 0..dims[rows].included.len() {
+                let mut row = Vec::with_capacity(dims[cols].included.len());
+                for _ in 0..dims[cols].included.len() {
+                    row.push(element.new_tree(runner)?);
+                }
+                elements.push(row);
+            }
+            new_grids.push(Grid {
+                rows,
+                cols,
+                elements,
+            });
+        }
+
+        Ok(LinkedGrids {
+            dims,
+            grids: new_grids,
+            shrink: Shrink::DeleteIndex(0, 0),
+            prev_shrink: None,
+        })
+    }
+
+    #[cfg(feature = "ndarray")]
+    fn size(&self, dim: usize) -> usize {
+        self.dims[dim].included.count()
+    }
+
+    fn current(&self, grid: usize) -> Vec<Vec<T::Value>> {
+        let grid = &self.grids[grid];
+        let (rows, cols) = (&self.dims[grid.rows], &self.dims[grid.cols]);
+        grid.elements
+            .iter()
+            .enumerate()
+            .filter(|&(ix, _)| rows.included.test(ix))
+            .map(|(_, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|&(ix, _)| cols.included.test(ix))
+                    .map(|(_, element)| element.current())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn simplify(&mut self) -> bool {
+        // As with `VecValueTree`, delete rows and columns until we can do so
+        // no further, then shrink each remaining element in sequence.
+        while let Shrink::DeleteIndex(dim, ix) = self.shrink {
+            if dim >= self.dims.len() {
+                self.shrink = Shrink::ShrinkElement(0, 0, 0);
+                break;
+            }
+
+            let d = &mut self.dims[dim];
+            if ix >= d.included.len() || d.included.count() == d.min_size {
+                self.shrink = Shrink::DeleteIndex(dim + 1, 0);
+                continue;
+            }
+
+            d.included.clear(ix);
+            self.prev_shrink = Some(self.shrink);
+            self.shrink = Shrink::DeleteIndex(dim, ix + 1);
+            return true;
+        }
+
+        while let Shrink::ShrinkElement(grid, row, col) = self.shrink {
+            if grid >= self.grids.len() {
+                // Nothing more we can do
+                return false;
+            }
+
+            let g = &mut self.grids[grid];
+            if row >= g.elements.len() {
+                self.shrink = Shrink::ShrinkElement(grid + 1, 0, 0);
+            } else if col >= g.elements[row].len() {
+                self.shrink = Shrink::ShrinkElement(grid, row + 1, 0);
+            } else if !self.dims[g.rows].included.test(row)
+                || !self.dims[g.cols].included.test(col)
+                || !g.elements[row][col].simplify()
+            {
+                self.shrink = Shrink::ShrinkElement(grid, row, col + 1);
+            } else {
+                self.prev_shrink = Some(self.shrink);
+                return true;
+            }
+        }
+
+        panic!("Unexpected shrink state");
+    }
+
+    fn complicate(&mut self) -> bool {
+        match self.prev_shrink {
+            None => false,
+            Some(Shrink::DeleteIndex(dim, ix)) => {
+                self.dims[dim].included.set(ix);
+                self.prev_shrink = None;
+                true
+            }
+            Some(Shrink::ShrinkElement(grid, row, col)) => {
+                if self.grids[grid].elements[row][col].complicate() {
+                    true
+                } else {
+                    self.prev_shrink = None;
+                    false
+                }
+            }
+        }
+    }
+}
+
+/// Strategy to create rectangular matrices with elements from a delegate
+/// strategy.
+///
+/// Created by the `matrix()` function in the same module.
+#[must_use = "strategies do nothing unless used"]
+#[derive(Clone, Debug)]
+pub struct MatrixStrategy<T: Strategy> {
+    element: T,
+    rows: SizeRange,
+    cols: SizeRange,
+}
+
+/// `ValueTree` corresponding to `MatrixStrategy`.
+#[derive(Clone, Debug)]
+pub struct MatrixValueTree<T: ValueTree>(LinkedGrids<T>);
+
+/// Create a strategy to generate rectangular matrices, as `Vec`s of rows,
+/// with a number of rows within `rows` and a number of columns within
+/// `cols`, and with elements from `element`.
+///
+/// Matrices shrink by removing rows, then columns, and then by shrinking
+/// their elements, and stay rectangular throughout.
+pub fn matrix<T: Strategy>(
+    element: T,
+    rows: impl Into<SizeRange>,
+    cols: impl Into<SizeRange>,
+) -> MatrixStrategy<T> {
+    MatrixStrategy {
+        element,
+        rows: rows.into(),
+        cols: cols.into(),
+    }
+}
+
+impl<T: Strategy> Strategy for MatrixStrategy<T> {
+    type Tree = MatrixValueTree<T::Tree>;
+    type Value = Vec<Vec<T::Value>>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        LinkedGrids::new(
+            runner,
+            &self.element,
+            &[&self.rows, &self.cols],
+            &[(0, 1)],
+        )
+        .map(MatrixValueTree)
+    }
+}
+
+impl<T: ValueTree> ValueTree for MatrixValueTree<T> {
+    type Value = Vec<Vec<T::Value>>;
+
+    fn current(&self) -> Self::Value {
+        self.0.current(0)
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.0.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.0.complicate()
+    }
+}
+
+/// Strategy to create pairs of matrices which can be multiplied together.
+///
+/// Created by the `matrix_pair()` function in the same module.
+#[must_use = "strategies do nothing unless used"]
+#[derive(Clone, Debug)]
+pub struct MatrixPairStrategy<T: Strategy> {
+    element: T,
+    m: SizeRange,
+    k: SizeRange,
+    n: SizeRange,
+}
+
+/// `ValueTree` corresponding to `MatrixPairStrategy`.
+#[derive(Clone, Debug)]
+pub struct MatrixPairValueTree<T: ValueTree>(LinkedGrids<T>);
+
+/// Create a strategy to generate pairs of matrices `(a, b)`, where `a` has
+/// `m` rows and `k` columns and `b` has `k` rows and `n` columns, so that the
+/// product `a × b` is defined. Each dimension is chosen independently from
+/// the given range, and elements are taken from `element`.
+///
+/// The pair shrinks by removing rows and columns, removing a row of `b`
+/// whenever the corresponding column of `a` is removed, and then by
+/// shrinking the elements of `a` and then of `b`.
+///
+/// ## Example
+///
+/// ```
+/// use proptest::matrix::matrix_pair;
+/// use proptest::prelude::*;
+///
+/// fn multiply(a: &[Vec<i64>], b: &[Vec<i64>]) -> Vec<Vec<i64>> {
+///     a.iter()
+///         .map(|row| {
+///             (0..b.first().map_or(0, Vec::len))
+///                 .map(|j| row.iter().zip(b).map(|(x, b)| x * b[j]).sum())
+///                 .collect()
+///         })
+///         .collect()
+/// }
+///
+/// proptest! {
+///     # /*
+///     #[test]
+///     # */
+///     fn product_has_outer_dimensions(
+///         (a, b) in matrix_pair(-10i64..10, 1..5, 1..5, 1..5)
+///     ) {
+///         let product = multiply(&a, &b);
+///         prop_assert_eq!(a.len(), product.len());
+///         prop_assert_eq!(b[0].len(), product[0].len());
+///     }
+/// }
+/// #
+/// # fn main() { product_has_outer_dimensions(); }
+/// ```
+pub fn matrix_pair<T: Strategy>(
+    element: T,
+    m: impl Into<SizeRange>,
+    k: impl Into<SizeRange>,
+    n: impl Into<SizeRange>,
+) -> MatrixPairStrategy<T> {
+    MatrixPairStrategy {
+        element,
+        m: m.into(),
+        k: k.into(),
+        n: n.into(),
+    }
+}
+
+impl<T: Strategy> Strategy for MatrixPairStrategy<T> {
+    type Tree = MatrixPairValueTree<T::Tree>;
+    type Value = (Vec<Vec<T::Value>>, Vec<Vec<T::Value>>);
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        LinkedGrids::new(
+            runner,
+            &self.element,
+            &[&self.m, &self.k, &self.n],
+            &[(0, 1), (1, 2)],
+        )
+        .map(MatrixPairValueTree)
+    }
+}
+
+impl<T: ValueTree> ValueTree for MatrixPairValueTree<T> {
+    type Value = (Vec<Vec<T::Value>>, Vec<Vec<T::Value>>);
+
+    fn current(&self) -> Self::Value {
+        (self.0.current(0), self.0.current(1))
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.0.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.0.complicate()
+    }
+}
+
+/// Strategy to create `ndarray::Array2`s.
+///
+/// Created by the `array2()` function in the same module.
+#[cfg(feature = "ndarray")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+#[must_use = "strategies do nothing unless used"]
+#[derive(Clone, Debug)]
+pub struct Array2Strategy<T: Strategy>(MatrixStrategy<T>);
+
+/// `ValueTree` corresponding to `Array2Strategy`.
+#[cfg(feature = "ndarray")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+#[derive(Clone, Debug)]
+pub struct Array2ValueTree<T: ValueTree>(MatrixValueTree<T>);
+
+/// Create a strategy to generate `ndarray::Array2`s with a number of rows
+/// within `rows` and a number of columns within `cols`, and with elements
+/// from `element`.
+///
+/// Arrays shrink in the same way as those generated by `matrix()`.
+#[cfg(feature = "ndarray")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+pub fn array2<T: Strategy>(
+    element: T,
+    rows: impl Into<SizeRange>,
+    cols: impl Into<SizeRange>,
+) -> Array2Strategy<T> {
+    Array2Strategy(matrix(element, rows, cols))
+}
+
+#[cfg(feature = "ndarray")]
+impl<T: Strategy> Strategy for Array2Strategy<T> {
+    type Tree = Array2ValueTree<T::Tree>;
+    type Value = ndarray::Array2<T::Value>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        self.0.new_tree(runner).map(Array2ValueTree)
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T: ValueTree> ValueTree for Array2ValueTree<T> {
+    type Value = ndarray::Array2<T::Value>;
+
+    fn current(&self) -> Self::Value {
+        let grids = &(self.0).0;
+        let shape = (grids.size(0), grids.size(1));
+        let elements = grids.current(0).into_iter().flatten().collect();
+        ndarray::Array2::from_shape_vec(shape, elements)
+            .expect("matrix is not rectangular")
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.0.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.0.complicate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_rectangular<T>(matrix: &[Vec<T>], cols: usize) {
+        assert!(matrix.iter().all(|row| row.len() == cols));
+    }
+
+    #[test]
+    fn matrices_are_rectangular() {
+        let mut runner = TestRunner::deterministic();
+        let input = matrix(0..100i32, 1..5, 2..6);
+        for _ in 0..256 {
+            let mut tree = input.new_tree(&mut runner).unwrap();
+            let cols = tree.current()[0].len();
+            assert!((2..6).contains(&cols));
+            while tree.simplify() {
+                let matrix = tree.current();
+                assert!((1..5).contains(&matrix.len()));
+                assert!(matrix[0].len() >= 2);
+                assert_rectangular(&matrix, matrix[0].len());
+            }
+            assert_eq!(vec![vec![0, 0]], tree.current());
+        }
+    }
+
+    #[test]
+    fn matrix_pairs_stay_compatible() {
+        let mut runner = TestRunner::deterministic();
+        // Fail whenever the shared dimension is at least 3.
+        let result =
+            runner.run(&matrix_pair(0..10u8, 1..5, 0..8, 1..5), |(a, b)| {
+                prop_assert_eq!(a[0].len(), b.len());
+                assert_rectangular(&a, a[0].len());
+                assert_rectangular(&b, b.first().map_or(0, Vec::len));
+                prop_assert!(b.len() < 3);
+                Ok(())
+            });
+
+        match result {
+            Err(TestError::Fail(_, (a, b))) => {
+                assert_eq!(vec![vec![0; 3]], a);
+                assert_eq!(vec![vec![0]; 3], b);
+            }
+            e => panic!("Unexpected result: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_matrix_sanity() {
+        check_strategy_sanity(matrix(0..10i32, 0..3, 0..3), None);
+        check_strategy_sanity(matrix_pair(0..10i32, 1..3, 0..3, 1..3), None);
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn arrays_keep_their_shape() {
+        let mut runner = TestRunner::deterministic();
+        let input = array2(0..10i32, 0..3, 2..4);
+        for _ in 0..64 {
+            let array = input.new_tree(&mut runner).unwrap().current();
+            assert!(array.nrows() < 3);
+            assert!(array.ncols() >= 2 && array.ncols() < 4);
+        }
+    }
+}
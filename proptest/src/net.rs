@@ -0,0 +1,138 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parameters for generating [`std::net`] addresses.
+//!
+//! [`AddrParams`] in this module is used as the argument to the
+//! [`Arbitrary`](crate::arbitrary::Arbitrary) implementations for
+//! [`Ipv4Addr`](std::net::Ipv4Addr), [`Ipv6Addr`](std::net::Ipv6Addr),
+//! [`IpAddr`](std::net::IpAddr) and the corresponding socket address types.
+
+/// Parameters for the [`Arbitrary`](crate::arbitrary::Arbitrary)
+/// implementations of the `std::net` address types.
+///
+/// Each special class of address has a relative weight; a weight of 0
+/// excludes the class entirely. The classes are, in the order that
+/// shrinking moves *away* from:
+///
+/// - unspecified: `0.0.0.0` and `::`;
+/// - loopback: `127.0.0.0/8` and `::1`;
+/// - link-local: `169.254.0.0/16` and `fe80::/10`;
+/// - multicast: `224.0.0.0/4` and `ff00::/8`;
+/// - IPv4-mapped: `::ffff:0:0/96`, for IPv6 addresses only;
+/// - other: any address at all.
+///
+/// By default, roughly half of all addresses belong to a special class.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AddrParams {
+    unspecified: u32,
+    loopback: u32,
+    link_local: u32,
+    multicast: u32,
+    ipv4_mapped: u32,
+    other: u32,
+}
+
+impl AddrParams {
+    /// Gets the weight of unspecified addresses.
+    pub fn unspecified(&self) -> u32 {
+        self.unspecified
+    }
+
+    /// Sets the weight of unspecified addresses.
+    pub fn with_unspecified(mut self, weight: u32) -> Self {
+        self.unspecified = weight;
+        self
+    }
+
+    /// Gets the weight of loopback addresses.
+    pub fn loopback(&self) -> u32 {
+        self.loopback
+    }
+
+    /// Sets the weight of loopback addresses.
+    pub fn with_loopback(mut self, weight: u32) -> Self {
+        self.loopback = weight;
+        self
+    }
+
+    /// Gets the weight of link-local addresses.
+    pub fn link_local(&self) -> u32 {
+        self.link_local
+    }
+
+    /// Sets the weight of link-local addresses.
+    pub fn with_link_local(mut self, weight: u32) -> Self {
+        self.link_local = weight;
+        self
+    }
+
+    /// Gets the weight of multicast addresses.
+    pub fn multicast(&self) -> u32 {
+        self.multicast
+    }
+
+    /// Sets the weight of multicast addresses.
+    pub fn with_multicast(mut self, weight: u32) -> Self {
+        self.multicast = weight;
+        self
+    }
+
+    /// Gets the weight of IPv4-mapped IPv6 addresses.
+    pub fn ipv4_mapped(&self) -> u32 {
+        self.ipv4_mapped
+    }
+
+    /// Sets the weight of IPv4-mapped IPv6 addresses. This has no effect on
+    /// IPv4 addresses.
+    pub fn with_ipv4_mapped(mut self, weight: u32) -> Self {
+        self.ipv4_mapped = weight;
+        self
+    }
+
+    /// Gets the weight of addresses drawn from the whole address space.
+    pub fn other(&self) -> u32 {
+        self.other
+    }
+
+    /// Sets the weight of addresses drawn from the whole address space.
+    pub fn with_other(mut self, weight: u32) -> Self {
+        self.other = weight;
+        self
+    }
+
+    /// Returns parameters with every weight set to 0, for enabling only
+    /// particular classes with the `with_*` methods.
+    ///
+    /// At least one applicable weight must be made non-zero before the
+    /// parameters are used.
+    pub fn none() -> Self {
+        Self {
+            unspecified: 0,
+            loopback: 0,
+            link_local: 0,
+            multicast: 0,
+            ipv4_mapped: 0,
+            other: 0,
+        }
+    }
+}
+
+impl Default for AddrParams {
+    fn default() -> Self {
+        Self {
+            unspecified: 1,
+            loopback: 4,
+            link_local: 2,
+            multicast: 2,
+            ipv4_mapped: 4,
+            other: 10,
+        }
+    }
+}
@@ -10,7 +10,10 @@
 //! Strategies to generate numeric values (as opposed to integers used as bit
 //! fields).
 //!
-//! All strategies in this module shrink by binary searching towards 0.
+//! All strategies in this module shrink by binary searching towards 0, except
+//! for those created by the integer modules' `shrink_towards()` (or
+//! `RangeShrinkTowards::shrink_towards()` on integer ranges), which search
+//! towards a given target instead.
 
 mod float_samplers;
 
@@ -38,6 +41,56 @@ pub fn sample_uniform_incl<X: SampleUniform>(
     Uniform::new_inclusive(start, end).sample(run.rng())
 }
 
+/// Adds `shrink_towards()` to ranges of integers, so that, for example,
+/// `(-100..100).shrink_towards(1)` is the same as
+/// `num::i32::shrink_towards(-100..100, 1)`.
+///
+/// This is implemented for `Range`, `RangeInclusive`, `RangeFrom`, `RangeTo`
+/// and `RangeToInclusive` over every primitive integer type, and is exported
+/// by the prelude.
+///
+/// ```
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     # /*
+///     #[test]
+///     # */
+///     fn year_is_in_range(year in (1900..=2100).shrink_towards(1970)) {
+///         prop_assert!((1900..=2100).contains(&year));
+///     }
+/// }
+/// # fn main() { year_is_in_range(); }
+/// ```
+pub trait RangeShrinkTowards {
+    /// The type of integer in the range.
+    type Value;
+    /// The strategy returned by `shrink_towards()`.
+    type Strategy: crate::strategy::Strategy<Value = Self::Value>;
+
+    /// Generates integers uniformly distributed over this range which shrink
+    /// towards `target`, rather than towards 0 or the end of the range
+    /// nearest to 0 as plain ranges do.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the range is empty or does not contain `target`.
+    fn shrink_towards(self, target: Self::Value) -> Self::Strategy;
+}
+
+macro_rules! range_shrink_towards {
+    ($typ:ident, $($range:ident),*) => {$(
+        impl $crate::num::RangeShrinkTowards for ::core::ops::$range<$typ> {
+            type Value = $typ;
+            type Strategy = ShrinkTowards;
+
+            fn shrink_towards(self, target: $typ) -> ShrinkTowards {
+                shrink_towards(self, target)
+            }
+        }
+    )*};
+}
+
 macro_rules! int_any {
     ($typ: ident) => {
         /// Type of the `ANY` constant.
@@ -56,6 +109,189 @@ macro_rules! int_any {
                 Ok(BinarySearch::new(runner.rng().gen()))
             }
         }
+
+        /// Type of the `EDGE` constant.
+        #[derive(Clone, Copy, Debug)]
+        #[must_use = "strategies do nothing unless used"]
+        pub struct Edge(());
+        /// Generates integers over the whole range like `ANY`, but half of
+        /// the time picks a boundary value instead of a uniform one.
+        ///
+        /// Boundary values are the powers of two, the values either side of
+        /// them, and the (wrapping) negations of all of these. This includes
+        /// 0, 1, `MIN` and `MAX`, which uniform sampling is very unlikely to
+        /// produce.
+        pub const EDGE: Edge = Edge(());
+
+        impl Strategy for Edge {
+            type Tree = BinarySearch;
+            type Value = $typ;
+
+            fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+                let rng = runner.rng();
+                if !rng.gen::<bool>() {
+                    return Ok(BinarySearch::new(rng.gen()));
+                }
+
+                let bits = (0 as $typ).count_zeros();
+                let power = (1 as $typ).wrapping_shl(rng.gen_range(0..bits));
+                let value = match rng.gen_range(0..3) {
+                    0 => power.wrapping_sub(1),
+                    1 => power,
+                    _ => power.wrapping_add(1),
+                };
+                Ok(BinarySearch::new(if rng.gen::<bool>() {
+                    value.wrapping_neg()
+                } else {
+                    value
+                }))
+            }
+        }
+    };
+}
+
+macro_rules! int_shrink_towards {
+    ($typ:ident, $utyp:ident) => {
+        /// Generates integers uniformly distributed over `range` which shrink
+        /// towards `target`, rather than towards 0 or the end of the range
+        /// nearest to 0 as plain ranges do.
+        ///
+        /// ## Panics
+        ///
+        /// Panics if `range` is empty or does not contain `target`.
+        pub fn shrink_towards(
+            range: impl ::core::ops::RangeBounds<$typ>,
+            target: $typ,
+        ) -> ShrinkTowards {
+            use ::core::ops::Bound;
+
+            let start = match range.start_bound() {
+                Bound::Included(&start) => Some(start),
+                Bound::Excluded(&start) => start.checked_add(1),
+                Bound::Unbounded => Some($typ::MIN),
+            };
+            let end = match range.end_bound() {
+                Bound::Included(&end) => Some(end),
+                Bound::Excluded(&end) => end.checked_sub(1),
+                Bound::Unbounded => Some($typ::MAX),
+            };
+            match (start, end) {
+                (Some(start), Some(end)) if start <= end => {
+                    assert!(
+                        start <= target && target <= end,
+                        "Target {} is outside of range {}..={}",
+                        target,
+                        start,
+                        end
+                    );
+                    ShrinkTowards { start, end, target }
+                }
+                _ => panic!("Invalid use of empty range."),
+            }
+        }
+
+        range_shrink_towards!(
+            $typ,
+            Range,
+            RangeInclusive,
+            RangeFrom,
+            RangeTo,
+            RangeToInclusive
+        );
+
+        /// Strategy returned by `shrink_towards()`.
+        #[derive(Clone, Copy, Debug)]
+        #[must_use = "strategies do nothing unless used"]
+        pub struct ShrinkTowards {
+            start: $typ,
+            end: $typ,
+            target: $typ,
+        }
+
+        impl Strategy for ShrinkTowards {
+            type Tree = BinarySearchTowards;
+            type Value = $typ;
+
+            fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+                Ok(BinarySearchTowards::new(
+                    self.target,
+                    $crate::num::sample_uniform_incl(
+                        runner, self.start, self.end,
+                    ),
+                ))
+            }
+        }
+
+        /// Shrinks an integer towards a target value, using binary search to
+        /// find boundary points.
+        #[derive(Clone, Copy, Debug)]
+        pub struct BinarySearchTowards {
+            target: $typ,
+            above: bool,
+            // Distances from `target`, which cannot overflow.
+            lo: $utyp,
+            curr: $utyp,
+            hi: $utyp,
+        }
+        impl BinarySearchTowards {
+            /// Creates a new binary searcher starting at `start` and
+            /// shrinking towards `target`.
+            pub fn new(target: $typ, start: $typ) -> Self {
+                let above = start >= target;
+                let distance = if above {
+                    (start as $utyp).wrapping_sub(target as $utyp)
+                } else {
+                    (target as $utyp).wrapping_sub(start as $utyp)
+                };
+                BinarySearchTowards {
+                    target,
+                    above,
+                    lo: 0,
+                    curr: distance,
+                    hi: distance,
+                }
+            }
+
+            fn reposition(&mut self) -> bool {
+                let new_mid = self.lo + (self.hi - self.lo) / 2;
+
+                if new_mid == self.curr {
+                    false
+                } else {
+                    self.curr = new_mid;
+                    true
+                }
+            }
+        }
+        impl ValueTree for BinarySearchTowards {
+            type Value = $typ;
+
+            fn current(&self) -> $typ {
+                if self.above {
+                    self.target.wrapping_add(self.curr as $typ)
+                } else {
+                    self.target.wrapping_sub(self.curr as $typ)
+                }
+            }
+
+            fn simplify(&mut self) -> bool {
+                if self.hi <= self.lo {
+                    return false;
+                }
+
+                self.hi = self.curr;
+                self.reposition()
+            }
+
+            fn complicate(&mut self) -> bool {
+                if self.hi <= self.lo {
+                    return false;
+                }
+
+                self.lo = self.curr + 1;
+                self.reposition()
+            }
+        }
     };
 }
 
@@ -172,7 +408,7 @@ macro_rules! numeric_api {
 }
 
 macro_rules! signed_integer_bin_search {
-    ($typ:ident) => {
+    ($typ:ident, $utyp:ident) => {
         #[allow(missing_docs)]
         pub mod $typ {
             use rand::Rng;
@@ -269,12 +505,13 @@ macro_rules! signed_integer_bin_search {
             }
 
             numeric_api!($typ, 1);
+            int_shrink_towards!($typ, $utyp);
         }
     };
 }
 
 macro_rules! unsigned_integer_bin_search {
-    ($typ:ident) => {
+    ($typ:ident, $utyp:ident) => {
         #[allow(missing_docs)]
         pub mod $typ {
             use rand::Rng;
@@ -357,22 +594,23 @@ macro_rules! unsigned_integer_bin_search {
             }
 
             numeric_api!($typ, 1);
+            int_shrink_towards!($typ, $utyp);
         }
     };
 }
 
-signed_integer_bin_search!(i8);
-signed_integer_bin_search!(i16);
-signed_integer_bin_search!(i32);
-signed_integer_bin_search!(i64);
-signed_integer_bin_search!(i128);
-signed_integer_bin_search!(isize);
-unsigned_integer_bin_search!(u8);
-unsigned_integer_bin_search!(u16);
-unsigned_integer_bin_search!(u32);
-unsigned_integer_bin_search!(u64);
-unsigned_integer_bin_search!(u128);
-unsigned_integer_bin_search!(usize);
+signed_integer_bin_search!(i8, u8);
+signed_integer_bin_search!(i16, u16);
+signed_integer_bin_search!(i32, u32);
+signed_integer_bin_search!(i64, u64);
+signed_integer_bin_search!(i128, u128);
+signed_integer_bin_search!(isize, usize);
+unsigned_integer_bin_search!(u8, u8);
+unsigned_integer_bin_search!(u16, u16);
+unsigned_integer_bin_search!(u32, u32);
+unsigned_integer_bin_search!(u64, u64);
+unsigned_integer_bin_search!(u128, u128);
+unsigned_integer_bin_search!(usize, usize);
 
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -393,6 +631,12 @@ bitflags! {
             Self::ZERO.bits() |
             Self::INFINITE.bits() |
             Self::QUIET_NAN.bits();
+        const FINITE =
+            Self::POSITIVE.bits() |
+            Self::NEGATIVE.bits() |
+            Self::NORMAL.bits() |
+            Self::SUBNORMAL.bits() |
+            Self::ZERO.bits();
     }
 }
 
@@ -426,6 +670,8 @@ where
     const EXP_MASK: Self::Bits;
     const EXP_ZERO: Self::Bits;
     const MANTISSA_MASK: Self::Bits;
+    /// The largest `n` for which `10^n` is exactly representable.
+    const MAX_EXACT_POW10: i32;
 }
 
 impl FloatLayout for f32 {
@@ -435,6 +681,7 @@ impl FloatLayout for f32 {
     const EXP_MASK: u32 = 0x7F80_0000;
     const EXP_ZERO: u32 = 0x3F80_0000;
     const MANTISSA_MASK: u32 = 0x007F_FFFF;
+    const MAX_EXACT_POW10: i32 = 10;
 }
 
 impl FloatLayout for f64 {
@@ -444,6 +691,7 @@ impl FloatLayout for f64 {
     const EXP_MASK: u64 = 0x7FF0_0000_0000_0000;
     const EXP_ZERO: u64 = 0x3FF0_0000_0000_0000;
     const MANTISSA_MASK: u64 = 0x000F_FFFF_FFFF_FFFF;
+    const MAX_EXACT_POW10: i32 = 22;
 }
 
 macro_rules! float_any {
@@ -618,6 +866,13 @@ macro_rules! float_any {
         /// Before proptest 0.4.1, this erroneously generated values in the
         /// range 0.0..1.0.
         pub const ANY: Any = Any(FloatTypes::ANY);
+        /// Generates arbitrary finite floating-point values, i.e., neither
+        /// infinities nor NaNs.
+        ///
+        /// Equivalent to `POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO`.
+        /// Since this already includes both signs, use `POSITIVE | NORMAL |
+        /// SUBNORMAL | ZERO` instead to generate only non-negative values.
+        pub const FINITE: Any = Any(FloatTypes::FINITE);
 
         impl Strategy for Any {
             type Tree = BinarySearch;
@@ -717,6 +972,11 @@ macro_rules! float_bin_search {
             /// Shrinks a float towards 0, using binary search to find boundary
             /// points.
             ///
+            /// The lowest possible value is tried first. If that passes, values
+            /// with short decimal representations, such as integers and halves,
+            /// are tried in preference to exact midpoints, so shrunk values
+            /// read like `2.0` rather than `1.9999999999999998`.
+            ///
             /// Non-finite values immediately shrink to 0.
             #[derive(Clone, Copy, Debug)]
             pub struct BinarySearch {
@@ -724,6 +984,9 @@ macro_rules! float_bin_search {
                 curr: $typ,
                 hi: $typ,
                 allowed: FloatTypes,
+                // Whether `lo` is known to pass, rather than being the lowest
+                // value still to be tried.
+                lo_passed: bool,
             }
 
             impl BinarySearch {
@@ -734,15 +997,29 @@ macro_rules! float_bin_search {
                         curr: start,
                         hi: start,
                         allowed: FloatTypes::all(),
+                        lo_passed: false,
                     }
                 }
 
                 fn new_with_types(start: $typ, allowed: FloatTypes) -> Self {
+                    // Start from the smallest magnitude which is allowed, so
+                    // that disallowed classes near 0 aren't searched.
+                    let lo = if allowed.contains(FloatTypes::ZERO) {
+                        0.0
+                    } else if allowed.contains(FloatTypes::SUBNORMAL) {
+                        $typ::from_bits(1)
+                    } else if allowed.contains(FloatTypes::NORMAL) {
+                        $typ::MIN_POSITIVE
+                    } else {
+                        0.0
+                    };
+
                     BinarySearch {
-                        lo: 0.0,
+                        lo: if start.is_sign_negative() { -lo } else { lo },
                         curr: start,
                         hi: start,
                         allowed,
+                        lo_passed: false,
                     }
                 }
 
@@ -759,6 +1036,7 @@ macro_rules! float_bin_search {
                         hi: start,
                         curr: start,
                         allowed: FloatTypes::all(),
+                        lo_passed: false,
                     }
                 }
 
@@ -808,19 +1086,112 @@ macro_rules! float_bin_search {
                     }
                 }
 
-                fn reposition(&mut self) -> bool {
-                    let interval = self.hi - self.lo;
-                    let interval =
-                        if interval.is_finite() { interval } else { 0.0 };
-                    let new_mid = self.lo + interval / 2.0;
+                /// Returns the smallest multiple of `10^exp`, or of half of
+                /// it if `halves` is set, which is at least `a` and at most
+                /// `b`, if there is one.
+                fn multiple_between(
+                    a: $typ,
+                    b: $typ,
+                    exp: i32,
+                    halves: bool,
+                ) -> Option<$typ> {
+                    let ten: $typ = 10.0;
+                    let divisor = if halves { 2.0 } else { 1.0 };
+                    // Negative powers of ten are not exact, so divide by the
+                    // (exact) positive power instead of multiplying.
+                    let multiple = if exp >= 0 {
+                        let step = ten.powi(exp) / divisor;
+                        (a / step).ceil() * step
+                    } else {
+                        let inverse = ten.powi(-exp) * divisor;
+                        (a * inverse).ceil() / inverse
+                    };
 
-                    let new_mid = if new_mid == self.curr || 0.0 == interval {
-                        new_mid
+                    if a <= multiple && multiple <= b {
+                        Some(multiple)
                     } else {
+                        None
+                    }
+                }
+
+                /// Picks the value with the shortest decimal representation
+                /// between the non-negative `a` and `b`, trying integers and
+                /// halves of each decimal place before the next one.
+                fn simplest_between(a: $typ, b: $typ) -> Option<$typ> {
+                    let ten: $typ = 10.0;
+                    // Estimate the largest power of ten not above `b` from
+                    // its binary exponent (log10(2) ~= 0.30103), then fix it
+                    // up since the estimate may be off by one.
+                    let shift = $typ::MANTISSA_MASK.count_ones();
+                    let exp2 = ((b.to_bits() & $typ::EXP_MASK) >> shift) as i32
+                        - ($typ::EXP_ZERO >> shift) as i32;
+                    let mut exp = (exp2 * 30103).div_euclid(100_000);
+                    while ten.powi(exp + 1) <= b {
+                        exp += 1;
+                    }
+                    while ten.powi(exp) > b && ten.powi(exp) > 0.0 {
+                        exp -= 1;
+                    }
+
+                    // More places than this only matter for values with no
+                    // short representation, which the midpoint handles.
+                    // Outside the exactly representable powers of ten, the
+                    // multiples would not be rounded to the nice values.
+                    let places = $typ::DIGITS as i32;
+                    let exact = $typ::MAX_EXACT_POW10;
+                    let exps = (exp - places).max(-exact)..=exp.min(exact);
+                    for exp in exps.rev() {
+                        for &halves in &[false, true] {
+                            if let Some(simplest) =
+                                BinarySearch::multiple_between(a, b, exp, halves)
+                            {
+                                return Some(simplest);
+                            }
+                        }
+                    }
+                    None
+                }
+
+                fn reposition(&mut self) -> bool {
+                    let new_mid = if !self.lo_passed {
                         self.lo
+                    } else if !self.hi.is_finite() {
+                        // There is nothing to search between `lo` and a
+                        // non-finite value.
+                        self.hi
+                    } else {
+                        // Search over the bit patterns of the magnitudes,
+                        // which are ordered the same way as the values, so
+                        // that the search takes as many steps as there are
+                        // bits rather than one per power of two.
+                        let lo = self.lo.abs().to_bits();
+                        let bits = self.hi.abs().to_bits() - lo;
+                        let at = |offset| $typ::from_bits(lo + offset);
+
+                        if bits <= 1 {
+                            self.hi
+                        } else {
+                            // Prefer "nice" values so that shrunk values are
+                            // easy to read, but only from the middle half of
+                            // the interval so the search still converges
+                            // quickly.
+                            let quarter = (bits / 4).max(1);
+                            let nice = BinarySearch::simplest_between(
+                                at(quarter),
+                                at(bits - quarter),
+                            )
+                            .unwrap_or_else(|| at(bits / 2));
+
+                            if self.hi.is_sign_negative() {
+                                -nice
+                            } else {
+                                nice
+                            }
+                        }
                     };
 
-                    if new_mid == self.curr {
+                    // Compare bits so that NaNs are considered equal.
+                    if new_mid.to_bits() == self.curr.to_bits() {
                         false
                     } else {
                         self.curr = new_mid;
@@ -828,21 +1199,13 @@ macro_rules! float_bin_search {
                     }
                 }
 
-                fn done(lo: $typ, hi: $typ) -> bool {
-                    (lo.abs() > hi.abs() && !hi.is_nan()) || lo.is_nan()
-                }
-
                 fn complicate_once(&mut self) -> bool {
-                    if BinarySearch::done(self.lo, self.hi) {
+                    if self.curr.to_bits() == self.hi.to_bits() {
                         return false;
                     }
 
-                    self.lo = if self.curr == self.lo {
-                        self.hi
-                    } else {
-                        self.curr
-                    };
-
+                    self.lo = self.curr;
+                    self.lo_passed = true;
                     self.reposition()
                 }
             }
@@ -854,10 +1217,6 @@ macro_rules! float_bin_search {
                 }
 
                 fn simplify(&mut self) -> bool {
-                    if BinarySearch::done(self.lo, self.hi) {
-                        return false;
-                    }
-
                     self.hi = self.curr;
                     if self.reposition() {
                         self.ensure_acceptable();
@@ -887,6 +1246,8 @@ float_bin_search!(f64, F64U);
 
 #[cfg(test)]
 mod test {
+    use std::collections::HashSet;
+
     use crate::strategy::*;
     use crate::test_runner::*;
 
@@ -1116,6 +1477,94 @@ mod test {
         contract_sanity!(f64);
     }
 
+    #[test]
+    fn edge_generates_boundary_values() {
+        let mut runner = TestRunner::deterministic();
+        let mut seen = HashSet::new();
+        for _ in 0..4096 {
+            seen.insert(i64::EDGE.sample(&mut runner));
+        }
+        for value in [0, 1, -1, i64::MIN, i64::MAX, 1 << 32, (1 << 31) - 1] {
+            assert!(seen.contains(&value), "{} never generated", value);
+        }
+
+        let mut seen = HashSet::new();
+        for _ in 0..4096 {
+            seen.insert(u8::EDGE.sample(&mut runner));
+        }
+        for value in [0, 1, 2, 127, 128, 129, 255] {
+            assert!(seen.contains(&value), "{} never generated", value);
+        }
+    }
+
+    #[test]
+    fn edge_simplify_complicate_contract_upheld() {
+        check_strategy_sanity(i32::EDGE, None);
+        check_strategy_sanity(u64::EDGE, None);
+    }
+
+    #[test]
+    fn shrink_towards_converges_on_target() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..256 {
+            assert_eq!(
+                1,
+                i32::shrink_towards(-100..100, 1).minimal(&mut runner)
+            );
+            assert_eq!(
+                1970,
+                u16::shrink_towards(1900..=2100, 1970).minimal(&mut runner)
+            );
+            assert_eq!(-100, i8::shrink_towards(.., -100).minimal(&mut runner));
+        }
+    }
+
+    #[test]
+    fn shrink_towards_stays_between_target_and_start() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..256 {
+            let mut tree =
+                i8::shrink_towards(.., -100).new_tree(&mut runner).unwrap();
+            let start = tree.current();
+            let (lo, hi) = if start < -100 {
+                (start, -100)
+            } else {
+                (-100, start)
+            };
+            while tree.simplify() {
+                assert!(lo <= tree.current() && tree.current() <= hi);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of range")]
+    fn shrink_towards_rejects_target_outside_range() {
+        let _ = u8::shrink_towards(10..20, 20);
+    }
+
+    #[test]
+    fn shrink_towards_is_available_on_ranges() {
+        use super::RangeShrinkTowards;
+
+        let mut runner = TestRunner::deterministic();
+        assert_eq!(1, (-100i32..100).shrink_towards(1).minimal(&mut runner));
+        assert_eq!(
+            1970,
+            (1900u16..=2100).shrink_towards(1970).minimal(&mut runner)
+        );
+        assert_eq!(7, (5u8..).shrink_towards(7).minimal(&mut runner));
+        assert_eq!(-3, (..0i64).shrink_towards(-3).minimal(&mut runner));
+        assert_eq!(0, (..=9usize).shrink_towards(0).minimal(&mut runner));
+    }
+
+    #[test]
+    fn shrink_towards_simplify_complicate_contract_upheld() {
+        check_strategy_sanity(i32::shrink_towards(-1000..1000, 7), None);
+        check_strategy_sanity(u64::shrink_towards(0..1000, 500), None);
+        check_strategy_sanity(i8::shrink_towards(.., 0), None);
+    }
+
     #[test]
     fn unsigned_integer_binsearch_simplify_complicate_contract_upheld() {
         check_strategy_sanity(0u32..1000u32, None);
@@ -1218,6 +1667,54 @@ mod test {
         assert_eq!(::std::f64::MIN_POSITIVE, value.current());
     }
 
+    #[test]
+    fn float_shrinks_through_nice_values() {
+        let mut value = f64::BinarySearch::new(73.123);
+        let mut tried = vec![];
+        loop {
+            let passed = value.current() < 20.0;
+            tried.push(value.current());
+            if !(if passed {
+                value.complicate()
+            } else {
+                value.simplify()
+            }) {
+                break;
+            }
+        }
+
+        assert_eq!(20.0, value.current());
+        assert_eq!(&[73.123, 0.0], &tried[..2]);
+        assert_eq!(
+            &[1.0, 10.0, 20.0, 15.0, 16.0, 17.0, 18.0, 19.0, 19.5, 19.7],
+            &tried[6..16]
+        );
+    }
+
+    #[test]
+    fn float_simplifies_to_smallest_allowed_value() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..64 {
+            let mut value = f64::NORMAL.new_tree(&mut runner).unwrap();
+            while value.simplify() {}
+            assert_eq!(::std::f64::MIN_POSITIVE, value.current());
+        }
+    }
+
+    #[test]
+    fn finite_generates_only_finite_values_of_both_signs() {
+        let mut runner = TestRunner::deterministic();
+        let mut seen_negative = false;
+        let mut seen_positive = false;
+        for _ in 0..1024 {
+            let value = f64::FINITE.sample(&mut runner);
+            assert!(value.is_finite(), "{} is not finite", value);
+            seen_negative |= value < 0.0;
+            seen_positive |= value > 0.0;
+        }
+        assert!(seen_negative && seen_positive);
+    }
+
     macro_rules! float_generation_test_body {
         ($strategy:ident, $typ:ident) => {
             use std::num::FpCategory;
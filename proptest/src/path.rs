@@ -7,14 +7,20 @@ use crate::{collection::SizeRange, string::StringParam};
 
 /// Parameters for the [`Arbitrary`] implementation for [`PathBuf`].
 ///
-/// By default, this generates paths with 0 to 8 components uniformly at random, each of which is a
-/// default [`StringParam`].
+/// By default, this generates relative and absolute paths with 0 to 8 components uniformly at
+/// random, each of which is a default [`StringParam`].
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct PathParams {
     /// The number of components in the path.
     components: SizeRange,
     /// The regular expression to generate individual components.
     component_regex: StringParam,
+    /// Whether paths are absolute, or `None` for either.
+    absolute: Option<bool>,
+    /// Whether to mix in special components such as `..` and `NUL`.
+    special_components: bool,
+    /// Whether to mix both separators on platforms which have two.
+    mixed_separators: bool,
 }
 
 impl PathParams {
@@ -42,6 +48,49 @@ impl PathParams {
         self.component_regex = component_regex.into();
         self
     }
+
+    /// Gets whether generated paths are absolute, or `None` if both absolute and relative paths
+    /// are generated.
+    pub fn absolute(&self) -> Option<bool> {
+        self.absolute
+    }
+
+    /// Sets whether generated paths are absolute (`Some(true)`), relative (`Some(false)`) or
+    /// either with equal probability (`None`, the default).
+    pub fn with_absolute(mut self, absolute: Option<bool>) -> Self {
+        self.absolute = absolute;
+        self
+    }
+
+    /// Gets whether special components are mixed into generated paths.
+    pub fn special_components(&self) -> bool {
+        self.special_components
+    }
+
+    /// Sets whether to replace about a quarter of the components with ones which commonly break
+    /// path handling: `.` and `..`, names reserved on Windows such as `NUL` and `com1.txt`,
+    /// names with leading hyphens or trailing dots and spaces, non-ASCII and non-normalized
+    /// names, and components of 255 and 256 bytes.
+    ///
+    /// This is off by default, since paths containing `..` may escape the directory they are
+    /// joined to.
+    pub fn with_special_components(mut self, special_components: bool) -> Self {
+        self.special_components = special_components;
+        self
+    }
+
+    /// Gets whether generated paths mix both path separators.
+    pub fn mixed_separators(&self) -> bool {
+        self.mixed_separators
+    }
+
+    /// Sets whether to separate components by randomly choosing between `/` and the platform's
+    /// main separator, rather than always using the main separator. This only changes the
+    /// generated paths on platforms, such as Windows, which accept two separators.
+    pub fn with_mixed_separators(mut self, mixed_separators: bool) -> Self {
+        self.mixed_separators = mixed_separators;
+        self
+    }
 }
 
 impl Default for PathParams {
@@ -50,6 +99,9 @@ impl Default for PathParams {
             components: (0..8).into(),
             // This is the default regex for `any::<String>()`.
             component_regex: StringParam::default(),
+            absolute: None,
+            special_components: false,
+            mixed_separators: false,
         }
     }
 }
@@ -21,6 +21,7 @@
 //! revision to the `rand` crate.
 
 pub use crate::arbitrary::{any, any_with, Arbitrary};
+pub use crate::num::RangeShrinkTowards;
 pub use crate::strategy::{BoxedStrategy, Just, SBoxedStrategy, Strategy};
 pub use crate::test_runner::Config as ProptestConfig;
 pub use crate::test_runner::TestCaseError;
@@ -28,6 +29,8 @@ pub use crate::{
     prop_assert, prop_assert_eq, prop_assert_ne, prop_assume, prop_compose,
     prop_oneof, proptest,
 };
+#[cfg(feature = "std")]
+pub use crate::{prop_classify, prop_collect, prop_target};
 
 pub use rand::{Rng, RngCore};
 
@@ -37,17 +40,53 @@ pub use rand::{Rng, RngCore};
 pub mod prop {
     pub use crate::arbitrary;
     pub use crate::array;
+    #[cfg(feature = "num")]
+    pub use crate::bigint;
+    pub use crate::binary;
     pub use crate::bits;
     pub use crate::bool;
     pub use crate::char;
+    #[cfg(feature = "std")]
+    pub use crate::cli;
     pub use crate::collection;
+    #[cfg(feature = "num")]
+    pub use crate::complex;
+    pub use crate::concurrency;
+    #[cfg(feature = "std")]
+    pub use crate::config;
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    pub use crate::datetime;
+    #[cfg(feature = "rust_decimal")]
+    pub use crate::decimal;
+    #[cfg(feature = "serde")]
+    pub use crate::deserialize;
+    pub use crate::fault;
+    #[cfg(feature = "fs")]
+    pub use crate::fs;
+    #[cfg(feature = "std")]
+    pub use crate::fuzz;
+    #[cfg(feature = "std")]
+    pub use crate::grammar;
+    #[cfg(feature = "serde_json")]
+    pub use crate::json;
+    pub use crate::matrix;
     pub use crate::num;
     pub use crate::option;
+    #[cfg(feature = "quickcheck")]
+    pub use crate::quickcheck;
+    pub use crate::rational;
     pub use crate::result;
     pub use crate::sample;
+    #[cfg(feature = "semver")]
+    pub use crate::semver;
     pub use crate::strategy;
     #[cfg(feature = "std")]
     pub use crate::string;
     pub use crate::test_runner;
+    pub use crate::tree;
     pub use crate::tuple;
+    #[cfg(feature = "uuid")]
+    pub use crate::uuid;
+    #[cfg(feature = "std")]
+    pub use crate::web;
 }
@@ -0,0 +1,256 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for types implementing `quickcheck::Arbitrary`.
+//!
+//! This allows a test suite written with `quickcheck` to be moved to
+//! proptest one test at a time: the `Arbitrary` implementations written for
+//! `quickcheck` keep working, through `any::<T>()` in this module, until
+//! they are replaced with proptest strategies.
+//!
+//! Values are generated by `Arbitrary::arbitrary()` from a `Gen` seeded by
+//! the test runner, so runs are as reproducible as with any other strategy,
+//! and they shrink through the candidates given by `Arbitrary::shrink()`,
+//! in the same way `quickcheck` itself shrinks them.
+//!
+//! ## Example
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use quickcheck::{Arbitrary, Gen};
+//!
+//! // A type from a `quickcheck` suite.
+//! #[derive(Clone, Debug)]
+//! struct Even(u32);
+//!
+//! impl Arbitrary for Even {
+//!     fn arbitrary(g: &mut Gen) -> Self {
+//!         Even(u32::arbitrary(g) & !1)
+//!     }
+//!
+//!     fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+//!         Box::new(self.0.shrink().map(|n| Even(n & !1)))
+//!     }
+//! }
+//!
+//! proptest! {
+//!     # /*
+//!     #[test]
+//!     # */
+//!     fn halves_double_back(even in prop::quickcheck::any::<Even>()) {
+//!         prop_assert_eq!(even.0, even.0 / 2 * 2);
+//!     }
+//! }
+//! #
+//! # fn main() { halves_double_back(); }
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::quickcheck::{Arbitrary, Gen};
+use rand::RngCore;
+
+use crate::std_facade::Box;
+use crate::strategy::*;
+use crate::test_runner::*;
+
+/// The size `quickcheck` uses by default, which bounds the length of
+/// collections among other things.
+const DEFAULT_SIZE: usize = 100;
+
+/// Strategy which generates values of a type implementing
+/// `quickcheck::Arbitrary`.
+///
+/// Created by `any()` and `any_sized()`.
+#[must_use = "strategies do nothing unless used"]
+pub struct QuickCheckStrategy<T> {
+    size: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for QuickCheckStrategy<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for QuickCheckStrategy<T> {}
+
+impl<T> fmt::Debug for QuickCheckStrategy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QuickCheckStrategy")
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+/// Create a strategy which generates values of `T` with its
+/// `quickcheck::Arbitrary` implementation, with `quickcheck`'s default size
+/// of 100.
+pub fn any<T: Arbitrary + fmt::Debug>() -> QuickCheckStrategy<T> {
+    any_sized(DEFAULT_SIZE)
+}
+
+/// Create a strategy which generates values of `T` with its
+/// `quickcheck::Arbitrary` implementation, from a `Gen` of the given size.
+///
+/// The size bounds the length of collections, and is otherwise interpreted
+/// by each implementation, as with `Gen::new()`.
+///
+/// ## Panics
+///
+/// Panics if `size` is zero, which `quickcheck` does not support.
+pub fn any_sized<T: Arbitrary + fmt::Debug>(
+    size: usize,
+) -> QuickCheckStrategy<T> {
+    assert!(size > 0, "QuickCheck size must be positive");
+
+    QuickCheckStrategy {
+        size,
+        _marker: PhantomData,
+    }
+}
+
+impl<T: Arbitrary + fmt::Debug> Strategy for QuickCheckStrategy<T> {
+    type Tree = QuickCheckValueTree<T>;
+    type Value = T;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let seed = runner.rng().next_u64();
+        let mut gen = Gen::from_size_and_seed(self.size, seed);
+        let value = T::arbitrary(&mut gen);
+        Ok(QuickCheckValueTree {
+            accepted: value.clone(),
+            current: value,
+            candidates: None,
+            trying: false,
+        })
+    }
+}
+
+/// `ValueTree` corresponding to `QuickCheckStrategy`.
+///
+/// Each call to `simplify()` moves to the next candidate from
+/// `Arbitrary::shrink()`. A candidate which is not undone by `complicate()`
+/// is accepted, and the candidates after it are replaced by its own.
+pub struct QuickCheckValueTree<T> {
+    /// The simplest value known to fail, whose candidates are being tried.
+    accepted: T,
+    current: T,
+    /// The remaining candidates of `accepted`, created on first use.
+    candidates: Option<Box<dyn Iterator<Item = T>>>,
+    /// Whether `current` is a candidate rather than `accepted`.
+    trying: bool,
+}
+
+impl<T: fmt::Debug> fmt::Debug for QuickCheckValueTree<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QuickCheckValueTree")
+            .field("accepted", &self.accepted)
+            .field("current", &self.current)
+            .field("trying", &self.trying)
+            .finish()
+    }
+}
+
+impl<T: Arbitrary + fmt::Debug> ValueTree for QuickCheckValueTree<T> {
+    type Value = T;
+
+    fn current(&self) -> T {
+        self.current.clone()
+    }
+
+    fn simplify(&mut self) -> bool {
+        if self.trying {
+            // The last candidate was not rejected, so shrink from it.
+            self.accepted = self.current.clone();
+            self.candidates = None;
+            self.trying = false;
+        }
+
+        let accepted = &self.accepted;
+        let candidates =
+            self.candidates.get_or_insert_with(|| accepted.shrink());
+        match candidates.next() {
+            Some(candidate) => {
+                self.current = candidate;
+                self.trying = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn complicate(&mut self) -> bool {
+        if !self.trying {
+            return false;
+        }
+
+        self.current = self.accepted.clone();
+        self.trying = false;
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::std_facade::Vec;
+
+    #[test]
+    fn values_are_reproducible() {
+        let generate = || {
+            let mut runner = TestRunner::deterministic();
+            (0..16)
+                .map(|_| {
+                    any::<Vec<u8>>().new_tree(&mut runner).unwrap().current()
+                })
+                .collect::<Vec<_>>()
+        };
+        let values = generate();
+        assert_eq!(values, generate());
+        assert!(values.iter().any(|v| v.len() > 1));
+        assert!(values.iter().all(|v| v.len() <= DEFAULT_SIZE));
+        assert!(any_sized::<Vec<u8>>(1)
+            .new_tree(&mut TestRunner::deterministic())
+            .unwrap()
+            .current()
+            .is_empty());
+    }
+
+    #[test]
+    fn values_shrink_with_quickcheck_shrinker() {
+        let mut runner = TestRunner::deterministic();
+        let result = runner.run(&any::<Vec<u32>>(), |v| {
+            prop_assert!(v.iter().all(|&n| n < 1000));
+            Ok(())
+        });
+        match result {
+            Err(TestError::Fail(_, value)) => assert_eq!(vec![1000], value),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn complicate_returns_to_accepted_value() {
+        let mut runner = TestRunner::deterministic();
+        let mut tree = any::<u32>().new_tree(&mut runner).unwrap();
+        while 0 == tree.current() {
+            tree = any::<u32>().new_tree(&mut runner).unwrap();
+        }
+        let initial = tree.current();
+
+        assert!(tree.simplify());
+        assert_ne!(initial, tree.current());
+        assert!(tree.complicate());
+        assert_eq!(initial, tree.current());
+        assert!(!tree.complicate());
+    }
+}
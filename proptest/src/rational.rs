@@ -0,0 +1,238 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating rational numbers.
+//!
+//! Rationals are generated as a numerator and a positive denominator, so
+//! the denominator is never zero by construction, rather than by filtering.
+//! They can be generated as plain `(numerator, denominator)` pairs, or, with
+//! the `num` feature, as `num_rational::Ratio`s.
+//!
+//! All strategies shrink the numerator towards zero and the denominator
+//! towards one, that is towards small integers.
+
+use core::ops::RangeInclusive;
+
+use crate::num;
+use crate::strategy::*;
+use crate::test_runner::*;
+use crate::tuple::TupleValueTree;
+
+/// Return the greatest common divisor of `a` and `b`.
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Turns a numerator and denominator into a fraction, optionally reducing it
+/// to lowest terms.
+#[derive(Clone, Copy, Debug)]
+struct ToFraction {
+    reduce: bool,
+}
+
+impl statics::MapFn<(i64, i64)> for ToFraction {
+    type Output = (i64, i64);
+
+    fn apply(&self, (numer, denom): (i64, i64)) -> (i64, i64) {
+        if !self.reduce {
+            return (numer, denom);
+        }
+
+        // The denominator is positive, so the divisor is between 1 and the
+        // denominator, and the divisions can't overflow.
+        let divisor = gcd(numer.unsigned_abs(), denom as u64) as i64;
+        (numer / divisor, denom / divisor)
+    }
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates fractions as `(numerator, denominator)`
+    /// pairs.
+    ///
+    /// Created by `fraction()` and `reduced_fraction()`.
+    #[derive(Clone, Debug)]
+    pub struct FractionStrategy[][](
+        statics::Map<(RangeInclusive<i64>, RangeInclusive<i64>), ToFraction>)
+        -> FractionValueTree;
+    /// `ValueTree` corresponding to `FractionStrategy`.
+    #[derive(Clone, Debug)]
+    pub struct FractionValueTree[][](
+        statics::Map<TupleValueTree<(num::i64::BinarySearch,
+                                     num::i64::BinarySearch)>,
+                     ToFraction>)
+        -> (i64, i64);
+}
+
+fn new_fraction(
+    numer: RangeInclusive<i64>,
+    max_denom: i64,
+    reduce: bool,
+) -> FractionStrategy {
+    assert!(max_denom >= 1, "Denominators must be allowed to be 1");
+
+    FractionStrategy(statics::Map::new(
+        (numer, 1..=max_denom),
+        ToFraction { reduce },
+    ))
+}
+
+/// Create a strategy for `(numerator, denominator)` pairs, with numerators
+/// from `numer` and denominators from 1 to `max_denom`.
+///
+/// The fractions are not reduced, so equal rationals may be generated with
+/// different representations, such as `(1, 2)` and `(2, 4)`.
+///
+/// ## Panics
+///
+/// Panics if `max_denom` is less than 1.
+pub fn fraction(
+    numer: RangeInclusive<i64>,
+    max_denom: i64,
+) -> FractionStrategy {
+    new_fraction(numer, max_denom, false)
+}
+
+/// Like `fraction()`, but reduces each fraction to lowest terms, so that
+/// its numerator and denominator have no common factor.
+///
+/// The numerator is generated before reduction, so the reduced numerator is
+/// within `numer` only if that range includes zero.
+///
+/// ## Panics
+///
+/// Panics if `max_denom` is less than 1.
+pub fn reduced_fraction(
+    numer: RangeInclusive<i64>,
+    max_denom: i64,
+) -> FractionStrategy {
+    new_fraction(numer, max_denom, true)
+}
+
+#[cfg(feature = "num")]
+mapfn! {
+    [] fn ToRatio[](fraction: (i64, i64)) -> ::num_rational::Ratio<i64> {
+        ::num_rational::Ratio::new_raw(fraction.0, fraction.1)
+    }
+}
+
+#[cfg(feature = "num")]
+opaque_strategy_wrapper! {
+    /// Strategy which generates `Ratio<i64>`s.
+    ///
+    /// Created by `ratio()` and `reduced_ratio()`.
+    #[derive(Clone, Debug)]
+    pub struct RatioStrategy[][](
+        statics::Map<FractionStrategy, ToRatio>)
+        -> RatioValueTree;
+    /// `ValueTree` corresponding to `RatioStrategy`.
+    #[derive(Clone, Debug)]
+    pub struct RatioValueTree[][](
+        statics::Map<FractionValueTree, ToRatio>)
+        -> ::num_rational::Ratio<i64>;
+}
+
+/// Create a strategy for `Ratio<i64>`s, with numerators from `numer` and
+/// denominators from 1 to `max_denom`, which are not reduced.
+///
+/// Unreduced ratios are useful for testing code which must not assume
+/// lowest terms, though `Ratio`'s own arithmetic and comparisons handle
+/// them. See `fraction()` for details.
+///
+/// ## Panics
+///
+/// Panics if `max_denom` is less than 1.
+#[cfg(feature = "num")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num")))]
+pub fn ratio(numer: RangeInclusive<i64>, max_denom: i64) -> RatioStrategy {
+    RatioStrategy(statics::Map::new(fraction(numer, max_denom), ToRatio))
+}
+
+/// Like `ratio()`, but reduces each ratio to lowest terms, as `Ratio::new()`
+/// does.
+///
+/// ## Panics
+///
+/// Panics if `max_denom` is less than 1.
+#[cfg(feature = "num")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num")))]
+pub fn reduced_ratio(
+    numer: RangeInclusive<i64>,
+    max_denom: i64,
+) -> RatioStrategy {
+    RatioStrategy(statics::Map::new(
+        reduced_fraction(numer, max_denom),
+        ToRatio,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::strategy::statics::MapFn;
+
+    #[test]
+    fn fractions_have_positive_denominators() {
+        let mut runner = TestRunner::deterministic();
+        let input = fraction(-100..=100, 12);
+        let mut reducible = 0;
+        for _ in 0..256 {
+            let (numer, denom) = input.new_tree(&mut runner).unwrap().current();
+            assert!((-100..=100).contains(&numer));
+            assert!((1..=12).contains(&denom));
+            reducible += (gcd(numer.unsigned_abs(), denom as u64) > 1) as u32;
+        }
+        assert!(reducible > 0);
+        assert_eq!((0, 1), input.minimal(&mut runner));
+    }
+
+    #[test]
+    fn reduced_fractions_are_in_lowest_terms() {
+        let mut runner = TestRunner::deterministic();
+        for numer in [-100..=100, i64::MIN..=i64::MAX] {
+            let input = reduced_fraction(numer, i64::MAX);
+            for _ in 0..256 {
+                let (numer, denom) =
+                    input.new_tree(&mut runner).unwrap().current();
+                assert!(denom >= 1);
+                assert_eq!(1, gcd(numer.unsigned_abs(), denom as u64));
+            }
+        }
+        let reduce = ToFraction { reduce: true };
+        assert_eq!((-3, 2), reduce.apply((-6, 4)));
+        assert_eq!((0, 1), reduce.apply((0, 5)));
+        assert_eq!((i64::MIN / 2, 1), reduce.apply((i64::MIN, 2)));
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn ratios_match_fractions() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..256 {
+            let ratio = reduced_ratio(-50..=50, 50)
+                .new_tree(&mut runner)
+                .unwrap()
+                .current();
+            let reduced =
+                ::num_rational::Ratio::new(*ratio.numer(), *ratio.denom());
+            assert_eq!(reduced.numer(), ratio.numer());
+            assert_eq!(reduced.denom(), ratio.denom());
+        }
+    }
+
+    #[test]
+    fn fraction_sanity() {
+        check_strategy_sanity(fraction(-10..=10, 5), None);
+        check_strategy_sanity(reduced_fraction(-10..=10, 5), None);
+    }
+}
@@ -23,6 +23,7 @@ use rand::Rng;
 
 use crate::bits::{self, BitSetValueTree, SampledBitSetStrategy, VarBitSet};
 use crate::num;
+use crate::option::Probability;
 use crate::strategy::*;
 use crate::test_runner::*;
 
@@ -163,6 +164,242 @@ pub fn select<T: Clone + fmt::Debug + 'static>(
     Select(statics::Map::new(0..cow.len(), SelectMapFn(Arc::new(cow))))
 }
 
+/// Create a strategy which uniformly selects one value from those produced
+/// by `values`.
+///
+/// This is `select()` for sources which are not already a slice or `Vec`,
+/// such as iterators over maps, and collects `values` when called.
+pub fn select_iter<T: Clone + fmt::Debug + 'static>(
+    values: impl IntoIterator<Item = T>,
+) -> Select<T> {
+    select(values.into_iter().collect::<Vec<_>>())
+}
+
+#[derive(Debug)]
+struct WeightedValues<T> {
+    /// The running total of the weights, up to and including each value.
+    cumulative: Vec<u64>,
+    values: Vec<T>,
+}
+
+#[derive(Debug, Clone)]
+struct SelectWeightedMapFn<T: Clone + 'static>(Arc<WeightedValues<T>>);
+
+impl<T: fmt::Debug + Clone + 'static> statics::MapFn<u64>
+    for SelectWeightedMapFn<T>
+{
+    type Output = T;
+
+    fn apply(&self, point: u64) -> T {
+        let ix = self.0.cumulative.partition_point(|&total| total <= point);
+        self.0.values[ix].clone()
+    }
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy to produce one value from a fixed collection of weighted
+    /// options.
+    ///
+    /// Created by the `select_weighted()` in the same module.
+    #[derive(Clone, Debug)]
+    pub struct SelectWeighted[<T>][where T : Clone + fmt::Debug + 'static](
+        statics::Map<Range<u64>, SelectWeightedMapFn<T>>)
+        -> SelectWeightedValueTree<T>;
+    /// `ValueTree` corresponding to `SelectWeighted`.
+    #[derive(Clone, Debug)]
+    pub struct SelectWeightedValueTree[<T>]
+        [where T : Clone + fmt::Debug + 'static](
+        statics::Map<num::u64::BinarySearch, SelectWeightedMapFn<T>>)
+        -> T;
+}
+
+/// Create a strategy which selects one value from `(weight, value)` pairs,
+/// choosing each value with probability proportional to its weight.
+///
+/// Values with a weight of zero are never chosen. Like `select()`, values
+/// shrink by binary search towards those listed earlier, so simpler values
+/// should come first.
+///
+/// ## Panics
+///
+/// Panics if the weights add up to zero.
+///
+/// ## Example
+///
+/// ```
+/// use proptest::prelude::*;
+/// use proptest::sample::select_weighted;
+///
+/// proptest! {
+///     # /*
+///     #[test]
+///     # */
+///     fn executes_opcodes(
+///         opcode in select_weighted(vec![(50, "mov"), (20, "add"), (1, "div")])
+///     ) {
+///         prop_assert!(["mov", "add", "div"].contains(&opcode));
+///     }
+/// }
+/// #
+/// # fn main() { executes_opcodes(); }
+/// ```
+pub fn select_weighted<T: Clone + fmt::Debug + 'static>(
+    values: impl IntoIterator<Item = (u32, T)>,
+) -> SelectWeighted<T> {
+    let mut total = 0u64;
+    let (cumulative, values) = values
+        .into_iter()
+        .map(|(weight, value)| {
+            total += u64::from(weight);
+            (total, value)
+        })
+        .unzip();
+
+    assert!(total > 0, "Cannot select from options with no weight");
+
+    SelectWeighted(statics::Map::new(
+        0..total,
+        SelectWeightedMapFn(Arc::new(WeightedValues { cumulative, values })),
+    ))
+}
+
+mapfn! {
+    [] fn IdentityPermutation[](len: usize) -> Vec<usize> {
+        (0..len).collect()
+    }
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy to produce permutations of `0..n`.
+    ///
+    /// Created by the `permutation()` in the same module.
+    #[derive(Clone, Debug)]
+    pub struct Permutation[][](
+        Shuffle<statics::Map<Just<usize>, IdentityPermutation>>)
+        -> PermutationValueTree;
+    /// `ValueTree` corresponding to `Permutation`.
+    #[derive(Clone, Debug)]
+    pub struct PermutationValueTree[][](
+        ShuffleValueTree<statics::Map<Just<usize>, IdentityPermutation>>)
+        -> Vec<usize>;
+}
+
+/// Create a strategy which generates permutations of `0..n`, as a `Vec`
+/// holding each of `0` to `n - 1` exactly once.
+///
+/// The permutations shrink towards the identity, `[0, 1, ..., n - 1]`, in
+/// the same way as `prop_shuffle()`. Unlike shuffling a collection directly,
+/// the permutation can be applied to values generated separately, for
+/// example with `Index`, or to several collections at once.
+pub fn permutation(n: usize) -> Permutation {
+    Permutation(statics::Map::new(Just(n), IdentityPermutation).prop_shuffle())
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which mixes values from a fixed dictionary of special values
+    /// into those generated by another strategy.
+    ///
+    /// Created by the `with_special_values()` in the same module.
+    #[derive(Clone)]
+    pub struct WithSpecialValues[<S>][where S : Strategy, S::Value : Clone + 'static]
+        (TupleUnion<(WA<S>, WA<Select<S::Value>>)>)
+        -> WithSpecialValuesValueTree<S>;
+    /// `ValueTree` corresponding to `WithSpecialValues`.
+    pub struct WithSpecialValuesValueTree[<S>]
+        [where S : Strategy, S::Value : Clone + 'static]
+        (TupleUnionValueTree<(
+            LazyValueTree<S>,
+            Option<LazyValueTree<Select<S::Value>>>,
+        )>)
+        -> S::Value;
+}
+
+// As with `OptionStrategy`, derives would add unwanted bounds on `S::Value`
+// and `S` here.
+impl<S: Strategy + fmt::Debug> fmt::Debug for WithSpecialValues<S>
+where
+    S::Value: Clone + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WithSpecialValues({:?})", self.0)
+    }
+}
+
+impl<S: Strategy> Clone for WithSpecialValuesValueTree<S>
+where
+    S::Value: Clone + 'static,
+    S::Tree: Clone,
+{
+    fn clone(&self) -> Self {
+        WithSpecialValuesValueTree(self.0.clone())
+    }
+}
+
+impl<S: Strategy> fmt::Debug for WithSpecialValuesValueTree<S>
+where
+    S::Value: Clone + 'static,
+    S::Tree: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WithSpecialValuesValueTree({:?})", self.0)
+    }
+}
+
+/// Create a strategy which usually generates values from `strategy`, but
+/// with probability `probability` instead picks one of `values`.
+///
+/// This is what fuzzers call a dictionary: a list of "interesting" constants,
+/// such as magic numbers, protocol keywords or boundary values, which uniform
+/// generation is very unlikely to hit on its own. Since it works with any
+/// strategy, it serves equally for numbers, strings and other types.
+///
+/// `values` is taken as for `select()`, and special values shrink towards
+/// those listed earlier. A special value may also shrink to a value from
+/// `strategy`, so that failures are reported in terms of ordinary values
+/// where possible.
+///
+/// ## Panics
+///
+/// Panics if `values` is empty, or if `probability` is not between 0.0 and
+/// 1.0, both exclusive.
+///
+/// ## Example
+///
+/// ```
+/// use proptest::prelude::*;
+/// use proptest::sample::with_special_values;
+///
+/// proptest! {
+///     # /*
+///     #[test]
+///     # */
+///     fn parses_headers(
+///         magic in with_special_values(
+///             any::<u32>(), &[0x7f45_4c46, 0xcafe_babe, 0xfeed_face][..], 0.2)
+///     ) {
+///         let _ = magic.to_be_bytes();
+///     }
+/// }
+/// #
+/// # fn main() { parses_headers(); }
+/// ```
+pub fn with_special_values<S: Strategy>(
+    strategy: S,
+    values: impl Into<Cow<'static, [S::Value]>>,
+    probability: impl Into<Probability>,
+) -> WithSpecialValues<S>
+where
+    S::Value: Clone + 'static,
+{
+    let (weight_special, weight_strategy) =
+        float_to_weight(probability.into().into());
+
+    WithSpecialValues(TupleUnion::new((
+        (weight_strategy, Arc::new(strategy)),
+        (weight_special, Arc::new(select(values))),
+    )))
+}
+
 /// A stand-in for an index into a slice or similar collection or conceptually
 /// similar things.
 ///
@@ -507,6 +744,76 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_select_weighted() {
+        let mut counts = [0; 4];
+
+        let mut runner = TestRunner::deterministic();
+        let input = select_weighted(vec![(1, 0usize), (0, 1), (3, 2), (4, 3)]);
+
+        for _ in 0..1024 {
+            counts[input.new_tree(&mut runner).unwrap().current()] += 1;
+        }
+
+        assert!(counts[0] >= 64 && counts[0] < 192, "{:?}", counts);
+        assert_eq!(0, counts[1]);
+        assert!(counts[2] >= 320 && counts[2] < 448, "{:?}", counts);
+        assert!(counts[3] >= 448 && counts[3] < 576, "{:?}", counts);
+        assert_eq!(0, input.minimal(&mut runner));
+    }
+
+    #[test]
+    fn test_select_weighted_sanity() {
+        check_strategy_sanity(
+            select_weighted(vec![(1, 0), (2, 1), (3, 2)]),
+            None,
+        );
+    }
+
+    #[test]
+    fn test_permutation() {
+        let mut runner = TestRunner::deterministic();
+        let mut moved = 0;
+        for _ in 0..256 {
+            let mut permutation = permutation(10).sample(&mut runner);
+            moved += (permutation != (0..10).collect::<Vec<_>>()) as u32;
+            permutation.sort();
+            assert_eq!((0..10).collect::<Vec<_>>(), permutation);
+        }
+        assert!(moved > 200, "{}", moved);
+        assert_eq!(
+            (0..10).collect::<Vec<_>>(),
+            permutation(10).minimal(&mut runner)
+        );
+        assert_eq!(Vec::<usize>::new(), permutation(0).sample(&mut runner));
+    }
+
+    #[test]
+    fn test_with_special_values() {
+        static SPECIAL: &[u32] = &[0xdead_beef, 0xcafe_babe];
+        let mut runner = TestRunner::deterministic();
+        let input = with_special_values(0..100u32, SPECIAL, 0.25);
+        let mut special = 0;
+        for _ in 0..1024 {
+            let value = input.new_tree(&mut runner).unwrap().current();
+            if SPECIAL.contains(&value) {
+                special += 1;
+            } else {
+                assert!(value < 100, "{}", value);
+            }
+        }
+        assert!(special > 192 && special < 320, "{}", special);
+        assert_eq!(0, input.minimal(&mut runner));
+    }
+
+    #[test]
+    fn test_with_special_values_sanity() {
+        check_strategy_sanity(
+            with_special_values(0..100u32, vec![1000, 2000], 0.5),
+            None,
+        );
+    }
+
     #[test]
     fn test_sample_sanity() {
         check_strategy_sanity(subsequence(vec![0, 1, 2, 3, 4], 1..3), None);
@@ -0,0 +1,258 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating `semver::Version` and `semver::VersionReq`
+//! values.
+//!
+//! Versions shrink towards `0.0.0` without pre-release or build metadata,
+//! and requirements towards fewer and simpler comparators.
+
+use ::semver::{
+    BuildMetadata, Comparator, Op, Prerelease, Version, VersionReq,
+};
+
+use crate::collection::vec;
+use crate::option;
+use crate::std_facade::{Box, String, Vec};
+use crate::strategy::*;
+use crate::string::string_regex;
+use crate::test_runner::*;
+
+/// Configuration for the strategies in this module.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SemverConfig {
+    /// The largest major, minor and patch number to generate. The default
+    /// is 20, so that generated versions and requirements often overlap.
+    pub max_number: u64,
+    /// The probability of a version or comparator having a pre-release, for
+    /// example `1.0.0-alpha.1`. The default is 0.25.
+    pub pre_release_probability: f64,
+    /// The probability of a version having build metadata, for example
+    /// `1.0.0+20260101`. The default is 0.1.
+    pub build_probability: f64,
+    /// The maximum number of comparators in a requirement. The default is 3.
+    pub max_comparators: usize,
+}
+
+impl Default for SemverConfig {
+    fn default() -> Self {
+        SemverConfig {
+            max_number: 20,
+            pre_release_probability: 0.25,
+            build_probability: 0.1,
+            max_comparators: 3,
+        }
+    }
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `semver::Version`s.
+    ///
+    /// Created by the `version()` and `version_with_config()` functions in
+    /// the same module.
+    #[derive(Clone, Debug)]
+    pub struct VersionStrategy[][](SBoxedStrategy<Version>)
+        -> VersionValueTree;
+    /// `ValueTree` corresponding to `VersionStrategy`.
+    pub struct VersionValueTree[][](Box<dyn ValueTree<Value = Version>>)
+        -> Version;
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `semver::VersionReq`s.
+    ///
+    /// Created by the `version_req()` and `version_req_with_config()`
+    /// functions in the same module.
+    #[derive(Clone, Debug)]
+    pub struct VersionReqStrategy[][](SBoxedStrategy<VersionReq>)
+        -> VersionReqValueTree;
+    /// `ValueTree` corresponding to `VersionReqStrategy`.
+    pub struct VersionReqValueTree[][](
+        Box<dyn ValueTree<Value = VersionReq>>) -> VersionReq;
+}
+
+/// Creates a strategy which generates versions with the default
+/// `SemverConfig`.
+pub fn version() -> VersionStrategy {
+    version_with_config(&SemverConfig::default())
+}
+
+/// Creates a strategy which generates versions as described by `config`.
+pub fn version_with_config(config: &SemverConfig) -> VersionStrategy {
+    let number = 0..=config.max_number;
+    VersionStrategy(
+        (
+            number.clone(),
+            number.clone(),
+            number,
+            pre_release(config),
+            option::weighted(
+                config.build_probability,
+                identifiers("[0-9A-Za-z-]{1,8}"),
+            ),
+        )
+            .prop_map(|(major, minor, patch, pre, build)| Version {
+                major,
+                minor,
+                patch,
+                pre,
+                build: build.map_or(BuildMetadata::EMPTY, |build| {
+                    BuildMetadata::new(&build).expect("invalid build metadata")
+                }),
+            })
+            .sboxed(),
+    )
+}
+
+/// Creates a strategy which generates version requirements with the default
+/// `SemverConfig`.
+pub fn version_req() -> VersionReqStrategy {
+    version_req_with_config(&SemverConfig::default())
+}
+
+/// Creates a strategy which generates version requirements as described by
+/// `config`.
+///
+/// Each requirement is `*` or a list of comparators using any operator,
+/// with partial versions such as `>=1.2` and wildcards such as `1.*`.
+pub fn version_req_with_config(config: &SemverConfig) -> VersionReqStrategy {
+    let number = 0..=config.max_number;
+    let ordered = (
+        prop_oneof![
+            Just(Op::Caret),
+            Just(Op::Tilde),
+            Just(Op::Exact),
+            Just(Op::Greater),
+            Just(Op::GreaterEq),
+            Just(Op::Less),
+            Just(Op::LessEq),
+        ],
+        number.clone(),
+        option::of((number.clone(), option::of(number.clone()))),
+        pre_release(config),
+    )
+        .prop_map(|(op, major, rest, pre)| {
+            let (minor, patch) = match rest {
+                Some((minor, patch)) => (Some(minor), patch),
+                None => (None, None),
+            };
+            Comparator {
+                op,
+                major,
+                minor,
+                patch,
+                // A pre-release is only allowed on a full version.
+                pre: if patch.is_some() {
+                    pre
+                } else {
+                    Prerelease::EMPTY
+                },
+            }
+        });
+    let wildcard =
+        (number.clone(), option::of(number)).prop_map(|(major, minor)| {
+            Comparator {
+                op: Op::Wildcard,
+                major,
+                minor,
+                patch: None,
+                pre: Prerelease::EMPTY,
+            }
+        });
+    let comparator = prop_oneof![4 => ordered, 1 => wildcard];
+
+    VersionReqStrategy(
+        prop_oneof![
+            1 => Just(VersionReq::STAR),
+            8 => vec(comparator, 1..=config.max_comparators.max(1))
+                .prop_map(|comparators| VersionReq { comparators }),
+        ]
+        .sboxed(),
+    )
+}
+
+fn pre_release(config: &SemverConfig) -> SBoxedStrategy<Prerelease> {
+    // Numeric identifiers may not have leading zeros, so alphanumeric ones
+    // must contain a letter or hyphen.
+    option::weighted(
+        config.pre_release_probability,
+        identifiers(
+            "(alpha|beta|rc|0|[1-9][0-9]{0,3}|[0-9A-Za-z-]{0,3}[A-Za-z-][0-9A-Za-z-]{0,3})",
+        ),
+    )
+    .prop_map(|pre| {
+        pre.map_or(Prerelease::EMPTY, |pre| {
+            Prerelease::new(&pre).expect("invalid pre-release")
+        })
+    })
+    .sboxed()
+}
+
+/// Dot-separated identifiers, each matching `identifier`.
+fn identifiers(identifier: &str) -> SBoxedStrategy<String> {
+    vec(string_regex(identifier).unwrap(), 1..4)
+        .prop_map(|ids: Vec<String>| ids.join("."))
+        .sboxed()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn versions_round_trip() {
+        let mut runner = TestRunner::deterministic();
+        let (mut pre, mut build) = (0, 0);
+        for _ in 0..1024 {
+            let version = version().new_tree(&mut runner).unwrap().current();
+            assert_eq!(
+                version,
+                Version::parse(&format!("{}", version)).unwrap(),
+                "{}",
+                version
+            );
+            assert!(version.major <= 20);
+            pre += !version.pre.is_empty() as usize;
+            build += !version.build.is_empty() as usize;
+        }
+        assert!((150..350).contains(&pre), "{}", pre);
+        assert!((50..150).contains(&build), "{}", build);
+    }
+
+    #[test]
+    fn version_reqs_round_trip() {
+        let mut runner = TestRunner::deterministic();
+        let mut matched = 0;
+        for _ in 0..1024 {
+            let req = version_req().new_tree(&mut runner).unwrap().current();
+            assert_eq!(
+                req,
+                VersionReq::parse(&format!("{}", req)).unwrap(),
+                "{}",
+                req
+            );
+            assert!(req.comparators.len() <= 3);
+
+            let version = version().new_tree(&mut runner).unwrap().current();
+            matched += req.matches(&version) as usize;
+        }
+        // Requirements and versions overlap often enough to be interesting.
+        assert!(matched > 100, "{}", matched);
+    }
+
+    #[test]
+    fn versions_shrink_to_zero() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..64 {
+            let mut tree = version().new_tree(&mut runner).unwrap();
+            while tree.simplify() {}
+            assert_eq!(Version::new(0, 0, 0), tree.current());
+        }
+    }
+}
@@ -0,0 +1,323 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::std_facade::{fmt, Arc};
+use core::marker::PhantomData;
+use core::ops::Add;
+
+use rand::distributions::Distribution;
+use rand::Rng;
+
+use crate::num;
+use crate::strategy::{Just, NewTree, Strategy, ValueTree};
+use crate::test_runner::{Config, RngAlgorithm, TestRng, TestRunner};
+
+/// Returns a `Strategy` which generates values by sampling `dist`, a
+/// `rand::distributions::Distribution`.
+///
+/// This allows distributions which have been tuned for other purposes, such
+/// as for a simulation, to be reused in tests. The values produced do not
+/// shrink; for numbers, `FromDistribution::shrink_towards()` makes them
+/// shrink by binary search.
+///
+/// ```
+/// use proptest::prelude::*;
+/// use proptest::strategy::from_distribution;
+/// use rand::distributions::Uniform;
+///
+/// proptest! {
+///   # /*
+///   #[test]
+///   # */
+///   fn latencies_are_in_range(
+///     ms in from_distribution(Uniform::new_inclusive(5u32, 250))
+///       .shrink_towards(5)
+///   ) {
+///     prop_assert!((5..=250).contains(&ms));
+///   }
+/// }
+/// # fn main() { latencies_are_in_range(); }
+/// ```
+pub fn from_distribution<T, D>(dist: D) -> FromDistribution<D, T>
+where
+    T: Clone + fmt::Debug,
+    D: Distribution<T>,
+{
+    FromDistribution {
+        dist: Arc::new(dist),
+        _marker: PhantomData,
+    }
+}
+
+/// `Strategy` which generates values by sampling a distribution.
+///
+/// See `from_distribution()`.
+#[must_use = "strategies do nothing unless used"]
+pub struct FromDistribution<D, T> {
+    dist: Arc<D>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<D, T> FromDistribution<D, T> {
+    /// Makes generated values shrink towards `target` by binary search,
+    /// rather than not shrinking at all.
+    ///
+    /// Values shrink along the straight line to `target`, whether or not
+    /// the distribution can produce the values on the way, so `target`
+    /// should be a value the code under test accepts, such as the smallest
+    /// value the distribution produces.
+    pub fn shrink_towards(self, target: T) -> ShrinkingDistribution<D, T>
+    where
+        T: BinarySearchable,
+        D: Distribution<T>,
+    {
+        ShrinkingDistribution {
+            dist: self.dist,
+            target,
+        }
+    }
+}
+
+impl<D, T> fmt::Debug for FromDistribution<D, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FromDistribution")
+            .field("dist", &"<distribution>")
+            .finish()
+    }
+}
+
+impl<D, T> Clone for FromDistribution<D, T> {
+    fn clone(&self) -> Self {
+        FromDistribution {
+            dist: Arc::clone(&self.dist),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<D, T> Strategy for FromDistribution<D, T>
+where
+    T: Clone + fmt::Debug,
+    D: Distribution<T>,
+{
+    type Tree = Just<T>;
+    type Value = T;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        Ok(Just(self.dist.sample(runner.rng())))
+    }
+}
+
+/// Numbers which values sampled from a distribution can be shrunk towards
+/// by binary search.
+///
+/// See `FromDistribution::shrink_towards()`.
+pub trait BinarySearchable: Copy + fmt::Debug {
+    /// The `ValueTree` which shrinks from a sampled value.
+    type Tree: ValueTree<Value = Self>;
+
+    /// Returns a `ValueTree` starting at `start` and shrinking towards
+    /// `target`.
+    fn binary_search(target: Self, start: Self) -> Self::Tree;
+}
+
+macro_rules! int_binary_searchable {
+    ($($typ:ident),*) => {$(
+        impl BinarySearchable for $typ {
+            type Tree = num::$typ::BinarySearchTowards;
+
+            fn binary_search(target: $typ, start: $typ) -> Self::Tree {
+                num::$typ::BinarySearchTowards::new(target, start)
+            }
+        }
+    )*};
+}
+
+int_binary_searchable!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+macro_rules! float_binary_searchable {
+    ($($typ:ident),*) => {$(
+        impl BinarySearchable for $typ {
+            type Tree = FloatBinarySearchTowards<num::$typ::BinarySearch>;
+
+            fn binary_search(target: $typ, start: $typ) -> Self::Tree {
+                FloatBinarySearchTowards {
+                    target,
+                    offset: num::$typ::BinarySearch::new(start - target),
+                }
+            }
+        }
+    )*};
+}
+
+float_binary_searchable!(f32, f64);
+
+/// Shrinks a float towards a target value, by shrinking its offset from the
+/// target towards 0.
+///
+/// Values which are not finite, or too far from the target to have a finite
+/// offset, immediately shrink to the target.
+#[derive(Clone, Copy, Debug)]
+pub struct FloatBinarySearchTowards<S: ValueTree> {
+    target: S::Value,
+    offset: S,
+}
+
+impl<S> ValueTree for FloatBinarySearchTowards<S>
+where
+    S: ValueTree,
+    S::Value: Add<Output = S::Value> + Copy,
+{
+    type Value = S::Value;
+
+    fn current(&self) -> S::Value {
+        self.target + self.offset.current()
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.offset.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.offset.complicate()
+    }
+}
+
+/// `Strategy` which generates values by sampling a distribution, and
+/// shrinks them towards a target by binary search.
+///
+/// See `FromDistribution::shrink_towards()`.
+#[must_use = "strategies do nothing unless used"]
+pub struct ShrinkingDistribution<D, T> {
+    dist: Arc<D>,
+    target: T,
+}
+
+impl<D, T: fmt::Debug> fmt::Debug for ShrinkingDistribution<D, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ShrinkingDistribution")
+            .field("dist", &"<distribution>")
+            .field("target", &self.target)
+            .finish()
+    }
+}
+
+impl<D, T: Copy> Clone for ShrinkingDistribution<D, T> {
+    fn clone(&self) -> Self {
+        ShrinkingDistribution {
+            dist: Arc::clone(&self.dist),
+            target: self.target,
+        }
+    }
+}
+
+impl<D, T> Strategy for ShrinkingDistribution<D, T>
+where
+    T: BinarySearchable,
+    D: Distribution<T>,
+{
+    type Tree = T::Tree;
+    type Value = T;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        Ok(T::binary_search(
+            self.target,
+            self.dist.sample(runner.rng()),
+        ))
+    }
+}
+
+/// `rand::distributions::Distribution` which samples values from a
+/// `Strategy`.
+///
+/// See `Strategy::into_distribution()`.
+#[derive(Clone, Debug)]
+pub struct IntoDistribution<S> {
+    strategy: S,
+    config: Config,
+}
+
+impl<S> IntoDistribution<S> {
+    pub(super) fn new(strategy: S) -> Self {
+        IntoDistribution {
+            strategy,
+            config: Config::default(),
+        }
+    }
+}
+
+impl<S: Strategy> Distribution<S::Value> for IntoDistribution<S> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> S::Value {
+        let mut seed = [0u8; 32];
+        rng.fill(&mut seed);
+        let mut runner = TestRunner::new_with_rng(
+            self.config.clone(),
+            TestRng::from_seed(RngAlgorithm::ChaCha, &seed),
+        );
+        self.strategy.sample(&mut runner)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::collection::vec;
+    use crate::std_facade::Vec;
+    use crate::test_runner::TestError;
+    use rand::distributions::{Bernoulli, Uniform};
+    use rand::SeedableRng;
+    use rand_chacha::ChaChaRng;
+
+    #[test]
+    fn samples_do_not_shrink_by_default() {
+        let strategy = from_distribution(Bernoulli::new(0.25).unwrap());
+        let mut runner = TestRunner::deterministic();
+        let mut heads = 0;
+        for _ in 0..1024 {
+            let mut tree = strategy.new_tree(&mut runner).unwrap();
+            heads += tree.current() as u32;
+            assert!(!tree.simplify());
+        }
+        assert!(heads > 192 && heads < 320, "{}", heads);
+    }
+
+    #[test]
+    fn samples_shrink_towards_target() {
+        let mut runner = TestRunner::deterministic();
+        let strategy =
+            from_distribution(Uniform::new(100i32, 1000)).shrink_towards(100);
+        let result = runner.run(&strategy, |n| {
+            prop_assert!(n < 500);
+            Ok(())
+        });
+        match result {
+            Err(TestError::Fail(_, value)) => assert_eq!(500, value),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+
+        let strategy =
+            from_distribution(Uniform::new(-4.0, 4.0)).shrink_towards(1.5);
+        assert_eq!(1.5, strategy.minimal(&mut runner));
+    }
+
+    #[test]
+    fn strategies_sample_as_distributions() {
+        let dist = vec(0..10u8, 1..4).into_distribution();
+        let mut rng = ChaChaRng::seed_from_u64(7);
+        let values: Vec<_> = (&mut rng).sample_iter(&dist).take(64).collect();
+        assert!(values
+            .iter()
+            .all(|v| (1..4).contains(&v.len()) && v.iter().all(|&n| n < 10)));
+
+        let mut rng = ChaChaRng::seed_from_u64(7);
+        assert_eq!(values[0], rng.sample(&dist));
+    }
+}
@@ -0,0 +1,125 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::strategy::*;
+use crate::test_runner::*;
+
+/// `Strategy` and `ValueTree` which delegates to one of two inner values of
+/// possibly different types.
+///
+/// This allows a function to choose between two strategies with the same
+/// `Value` type at runtime, such as when returning `impl Strategy`, without
+/// boxing them. Each `Either` only ever generates values from the strategy it
+/// holds; to pick randomly between two strategies, use `prop_oneof!` or
+/// `Strategy::prop_union()` instead.
+///
+/// The `ValueTree` of an `Either` strategy is an `Either` of the inner
+/// strategies' value trees.
+///
+/// ```
+/// use proptest::prelude::*;
+/// use proptest::strategy::Either;
+///
+/// fn small_or_any(small: bool) -> impl Strategy<Value = u32> {
+///     if small {
+///         Either::Left(0..10u32)
+///     } else {
+///         Either::Right(any::<u32>())
+///     }
+/// }
+///
+/// proptest! {
+///   # /*
+///   #[test]
+///   # */
+///   fn small_values_are_small(x in small_or_any(true)) {
+///     prop_assert!(x < 10);
+///   }
+/// }
+/// # fn main() { small_values_are_small(); }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[must_use = "strategies do nothing unless used"]
+pub enum Either<L, R> {
+    /// Delegate to the left-hand value.
+    Left(L),
+    /// Delegate to the right-hand value.
+    Right(R),
+}
+
+impl<L: Strategy, R: Strategy<Value = L::Value>> Strategy for Either<L, R> {
+    type Tree = Either<L::Tree, R::Tree>;
+    type Value = L::Value;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        match *self {
+            Either::Left(ref left) => left.new_tree(runner).map(Either::Left),
+            Either::Right(ref right) => {
+                right.new_tree(runner).map(Either::Right)
+            }
+        }
+    }
+}
+
+impl<L: ValueTree, R: ValueTree<Value = L::Value>> ValueTree for Either<L, R> {
+    type Value = L::Value;
+
+    fn current(&self) -> L::Value {
+        match *self {
+            Either::Left(ref left) => left.current(),
+            Either::Right(ref right) => right.current(),
+        }
+    }
+
+    fn simplify(&mut self) -> bool {
+        match *self {
+            Either::Left(ref mut left) => left.simplify(),
+            Either::Right(ref mut right) => right.simplify(),
+        }
+    }
+
+    fn complicate(&mut self) -> bool {
+        match *self {
+            Either::Left(ref mut left) => left.complicate(),
+            Either::Right(ref mut right) => right.complicate(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn either(left: bool) -> Either<Just<i32>, core::ops::Range<i32>> {
+        if left {
+            Either::Left(Just(-1))
+        } else {
+            Either::Right(0..100)
+        }
+    }
+
+    #[test]
+    fn generates_from_inner_strategy() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..64 {
+            assert_eq!(
+                -1,
+                either(true).new_tree(&mut runner).unwrap().current()
+            );
+            let value = either(false).new_tree(&mut runner).unwrap().current();
+            assert!((0..100).contains(&value));
+        }
+    }
+
+    #[test]
+    fn test_sanity() {
+        check_strategy_sanity(either(true), None);
+        check_strategy_sanity(either(false), None);
+    }
+}
@@ -14,7 +14,7 @@ use crate::test_runner::*;
 
 /// `Strategy` and `ValueTree` filter_map adaptor.
 ///
-/// See `Strategy::prop_filter_map()`.
+/// See `Strategy::prop_filter_map()` and `Strategy::prop_try_map()`.
 #[must_use = "strategies do nothing unless used"]
 pub struct FilterMap<S, F> {
     pub(super) source: S,
@@ -52,19 +52,55 @@ impl<S: Clone, F> Clone for FilterMap<S, F> {
     }
 }
 
-impl<S: Strategy, F: Fn(S::Value) -> Option<O>, O: fmt::Debug> Strategy
+/// The result of the function passed to `FilterMap`, which either maps the
+/// input to a value or rejects it.
+///
+/// `None` rejects the input for the reason given to
+/// `Strategy::prop_filter_map()`, while `Err` holds its own reason, as for
+/// `Strategy::prop_try_map()`.
+pub trait FilterMapOutput {
+    /// The type of the value the input is mapped to.
+    type Value;
+
+    /// Returns the value the input is mapped to, or, if it is rejected, the
+    /// reason for that if there is one other than the strategy's own.
+    fn into_result(self) -> Result<Self::Value, Option<Reason>>;
+}
+
+impl<O> FilterMapOutput for Option<O> {
+    type Value = O;
+
+    fn into_result(self) -> Result<O, Option<Reason>> {
+        self.ok_or(None)
+    }
+}
+
+impl<O> FilterMapOutput for Result<O, Reason> {
+    type Value = O;
+
+    fn into_result(self) -> Result<O, Option<Reason>> {
+        self.map_err(Some)
+    }
+}
+
+impl<S: Strategy, F: Fn(S::Value) -> R, R: FilterMapOutput> Strategy
     for FilterMap<S, F>
+where
+    R::Value: fmt::Debug,
 {
-    type Tree = FilterMapValueTree<S::Tree, F, O>;
-    type Value = O;
+    type Tree = FilterMapValueTree<S::Tree, F, R::Value>;
+    type Value = R::Value;
 
     fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
         loop {
             let val = self.source.new_tree(runner)?;
-            if let Some(current) = (self.fun)(val.current()) {
-                return Ok(FilterMapValueTree::new(val, &self.fun, current));
-            } else {
-                runner.reject_local(self.whence.clone())?;
+            match (self.fun)(val.current()).into_result() {
+                Ok(current) => {
+                    return Ok(FilterMapValueTree::new(val, &self.fun, current))
+                }
+                Err(whence) => runner.reject_local(
+                    whence.unwrap_or_else(|| self.whence.clone()),
+                )?,
             }
         }
     }
@@ -77,8 +113,11 @@ pub struct FilterMapValueTree<V, F, O> {
     fun: Arc<F>,
 }
 
-impl<V: Clone + ValueTree, F: Fn(V::Value) -> Option<O>, O> Clone
-    for FilterMapValueTree<V, F, O>
+impl<V, F, R, O> Clone for FilterMapValueTree<V, F, O>
+where
+    V: Clone + ValueTree,
+    F: Fn(V::Value) -> R,
+    R: FilterMapOutput<Value = O>,
 {
     fn clone(&self) -> Self {
         Self::new(self.source.clone(), &self.fun, self.fresh_current())
@@ -95,8 +134,11 @@ impl<V: fmt::Debug, F, O> fmt::Debug for FilterMapValueTree<V, F, O> {
     }
 }
 
-impl<V: ValueTree, F: Fn(V::Value) -> Option<O>, O>
-    FilterMapValueTree<V, F, O>
+impl<V, F, R, O> FilterMapValueTree<V, F, O>
+where
+    V: ValueTree,
+    F: Fn(V::Value) -> R,
+    R: FilterMapOutput<Value = O>,
 {
     fn new(source: V, fun: &Arc<F>, current: O) -> Self {
         Self {
@@ -108,12 +150,14 @@ impl<V: ValueTree, F: Fn(V::Value) -> Option<O>, O>
 
     fn fresh_current(&self) -> O {
         (self.fun)(self.source.current())
+            .into_result()
             .expect("internal logic error; this is a bug!")
     }
 
     fn ensure_acceptable(&mut self) {
         loop {
-            if let Some(current) = (self.fun)(self.source.current()) {
+            if let Ok(current) = (self.fun)(self.source.current()).into_result()
+            {
                 // Found an acceptable element!
                 self.current = Cell::new(Some(current));
                 break;
@@ -127,8 +171,12 @@ impl<V: ValueTree, F: Fn(V::Value) -> Option<O>, O>
     }
 }
 
-impl<V: ValueTree, F: Fn(V::Value) -> Option<O>, O: fmt::Debug> ValueTree
-    for FilterMapValueTree<V, F, O>
+impl<V, F, R, O> ValueTree for FilterMapValueTree<V, F, O>
+where
+    V: ValueTree,
+    F: Fn(V::Value) -> R,
+    R: FilterMapOutput<Value = O>,
+    O: fmt::Debug,
 {
     type Value = O;
 
@@ -164,6 +212,7 @@ impl<V: ValueTree, F: Fn(V::Value) -> Option<O>, O: fmt::Debug> ValueTree
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::strategy::Just;
 
     #[test]
     fn test_filter_map() {
@@ -206,4 +255,61 @@ mod test {
             }),
         );
     }
+
+    fn odd_half(v: i32) -> Result<i32, Reason> {
+        if 0 == v % 2 {
+            Err(Reason::from("not odd"))
+        } else {
+            Ok(v / 2)
+        }
+    }
+
+    #[test]
+    fn test_try_map() {
+        let input = (0..256).prop_try_map(odd_half);
+
+        for _ in 0..256 {
+            let mut runner = TestRunner::default();
+            let mut case = input.new_tree(&mut runner).unwrap();
+
+            assert!(case.current() < 128);
+            while case.simplify() {
+                assert!(case.current() < 128);
+            }
+        }
+    }
+
+    #[test]
+    fn errors_are_local_rejections() {
+        let mut runner = TestRunner::new(Config {
+            max_local_rejects: 3,
+            ..Config::default()
+        });
+        let input = Just(2).prop_try_map(odd_half);
+
+        assert_eq!(
+            Some("Too many local rejects".into()),
+            input.new_tree(&mut runner).err()
+        );
+        assert!(format!("{}", runner).contains("3 times at not odd"));
+    }
+
+    #[test]
+    fn test_try_map_sanity() {
+        check_strategy_sanity(
+            (0..256).prop_try_map(|v| {
+                if 0 != v % 5 {
+                    Ok(v * 2)
+                } else {
+                    Err("multiple of 5".into())
+                }
+            }),
+            Some(CheckStrategySanityOptions {
+                // Due to internal rejection sampling, `simplify()` can
+                // converge back to what `complicate()` would do.
+                strict_complicate_after_simplify: false,
+                ..CheckStrategySanityOptions::default()
+            }),
+        );
+    }
 }
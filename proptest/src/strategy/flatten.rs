@@ -8,6 +8,7 @@
 // except according to those terms.
 
 use crate::std_facade::{fmt, Arc};
+use core::any::type_name;
 use core::mem;
 
 use crate::strategy::fuse::Fuse;
@@ -196,6 +197,96 @@ where
     }
 }
 
+/// `Strategy` and `ValueTree` adaptor which maps values to strategies, to be
+/// flattened by `Flatten`.
+///
+/// This is like `Map`, except that the strategies the function returns need
+/// not implement `Debug`.
+///
+/// See `Strategy::prop_flat_map()` and `Strategy::prop_ind_flat_map()`.
+#[must_use = "strategies do nothing unless used"]
+pub struct FlatMap<S, F> {
+    pub(super) source: S,
+    pub(super) fun: Arc<F>,
+}
+
+impl<S: fmt::Debug, F> fmt::Debug for FlatMap<S, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FlatMap")
+            .field("source", &self.source)
+            .field("fun", &"<function>")
+            .finish()
+    }
+}
+
+impl<S: Clone, F> Clone for FlatMap<S, F> {
+    fn clone(&self) -> Self {
+        FlatMap {
+            source: self.source.clone(),
+            fun: Arc::clone(&self.fun),
+        }
+    }
+}
+
+impl<S: Strategy, R: Strategy, F: Fn(S::Value) -> R> Strategy
+    for FlatMap<S, F>
+{
+    type Tree = FlatMap<S::Tree, F>;
+    type Value = Opaque<R>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        self.source.new_tree(runner).map(|v| FlatMap {
+            source: v,
+            fun: Arc::clone(&self.fun),
+        })
+    }
+}
+
+impl<S: ValueTree, R: Strategy, F: Fn(S::Value) -> R> ValueTree
+    for FlatMap<S, F>
+{
+    type Value = Opaque<R>;
+
+    fn current(&self) -> Opaque<R> {
+        Opaque((self.fun)(self.source.current()))
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.source.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.source.complicate()
+    }
+}
+
+/// A strategy produced by the function passed to `Strategy::prop_flat_map()`
+/// or `Strategy::prop_ind_flat_map()`.
+///
+/// This behaves exactly like the strategy it wraps, but is formatted by
+/// `Debug` as the type name of that strategy, so that it need not implement
+/// `Debug` itself.
+#[derive(Clone, Copy)]
+#[must_use = "strategies do nothing unless used"]
+pub struct Opaque<S>(pub S);
+
+impl<S> fmt::Debug for Opaque<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Opaque")
+            .field(&format_args!("{}", type_name::<S>()))
+            .finish()
+    }
+}
+
+impl<S: Strategy> Strategy for Opaque<S> {
+    type Tree = S::Tree;
+    type Value = S::Value;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        self.0.new_tree(runner)
+    }
+}
+
 /// Similar to `Flatten`, but does not shrink the input strategy.
 ///
 /// See `Strategy::prop_ind_flat_map()` fore more details.
@@ -312,6 +403,39 @@ mod test {
         );
     }
 
+    #[test]
+    fn flat_map_accepts_strategies_without_debug() {
+        // Wraps a closure, so cannot derive `Debug`.
+        #[derive(Clone)]
+        struct FromFn<F>(F);
+
+        impl<F: Fn(&mut TestRunner) -> u32> Strategy for FromFn<F> {
+            type Tree = Just<u32>;
+            type Value = u32;
+
+            fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+                Ok(Just((self.0)(runner)))
+            }
+        }
+
+        let input = (0u32..1000)
+            .prop_flat_map(|a| FromFn(move |_: &mut TestRunner| a * 2));
+        assert!(format!("{:?}", input.clone().boxed())
+            .starts_with("BoxedStrategy("));
+
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..100 {
+            let case = input.new_tree(&mut runner).unwrap();
+            assert_eq!(0, case.current() % 2);
+        }
+        check_strategy_sanity(input, None);
+        check_strategy_sanity(
+            (0u32..1000)
+                .prop_ind_flat_map(|a| FromFn(move |_: &mut TestRunner| a)),
+            None,
+        );
+    }
+
     #[test]
     fn flat_map_respects_regen_limit() {
         use std::sync::atomic::{AtomicBool, Ordering};
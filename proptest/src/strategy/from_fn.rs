@@ -0,0 +1,139 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::std_facade::{fmt, Arc};
+
+use crate::strategy::{Just, NewTree, Strategy};
+use crate::test_runner::{Reason, TestRunner};
+
+/// Returns a `Strategy` which generates values by calling `fun`.
+///
+/// `fun` is given the `TestRunner` generating the value, so it can draw
+/// random data from `TestRunner::rng()`. If it returns `Err`, the attempt
+/// counts as a local rejection, as with `Strategy::prop_filter()`, and `fun`
+/// is called again.
+///
+/// The values produced do not shrink. This is convenient for generating
+/// values imperatively, but where possible, building the value from other
+/// strategies, such as with `Strategy::prop_map()`, gives far better
+/// counter-examples when a test fails.
+///
+/// ```
+/// use proptest::prelude::*;
+/// use proptest::strategy::from_fn;
+///
+/// proptest! {
+///   # /*
+///   #[test]
+///   # */
+///   fn ordered_pair((lo, hi) in from_fn(|runner| {
+///     let lo = runner.rng().gen_range(0..100u32);
+///     let hi = runner.rng().gen_range(lo..=100);
+///     Ok((lo, hi))
+///   })) {
+///     prop_assert!(lo <= hi);
+///   }
+/// }
+/// # fn main() { ordered_pair(); }
+/// ```
+pub fn from_fn<V, F>(fun: F) -> FromFn<F>
+where
+    V: Clone + fmt::Debug,
+    F: Fn(&mut TestRunner) -> Result<V, Reason>,
+{
+    FromFn { fun: Arc::new(fun) }
+}
+
+/// `Strategy` which generates values from a function.
+///
+/// See `from_fn()`.
+#[must_use = "strategies do nothing unless used"]
+pub struct FromFn<F> {
+    fun: Arc<F>,
+}
+
+impl<F> fmt::Debug for FromFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FromFn")
+            .field("fun", &"<function>")
+            .finish()
+    }
+}
+
+impl<F> Clone for FromFn<F> {
+    fn clone(&self) -> Self {
+        FromFn {
+            fun: Arc::clone(&self.fun),
+        }
+    }
+}
+
+impl<V, F> Strategy for FromFn<F>
+where
+    V: Clone + fmt::Debug,
+    F: Fn(&mut TestRunner) -> Result<V, Reason>,
+{
+    type Tree = Just<V>;
+    type Value = V;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        loop {
+            match (self.fun)(runner) {
+                Ok(value) => return Ok(Just(value)),
+                Err(whence) => runner.reject_local(whence)?,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::strategy::ValueTree;
+    use crate::test_runner::Config;
+    use rand::Rng;
+
+    #[test]
+    fn generates_from_function() {
+        let strategy = from_fn(|runner| Ok(runner.rng().gen_range(0..10u8)));
+
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..256 {
+            let mut tree = strategy.new_tree(&mut runner).unwrap();
+            assert!(tree.current() < 10);
+            assert!(!tree.simplify());
+        }
+    }
+
+    #[test]
+    fn errors_are_local_rejections() {
+        let strategy = from_fn(|runner| {
+            if runner.rng().gen() {
+                Ok(())
+            } else {
+                Err("tails".into())
+            }
+        });
+
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..64 {
+            assert!(strategy.new_tree(&mut runner).is_ok());
+        }
+
+        let mut runner = TestRunner::new(Config {
+            max_local_rejects: 0,
+            ..Config::default()
+        });
+        let strategy = from_fn(|_| Err::<(), _>("always".into()));
+        assert_eq!(
+            Some("Too many local rejects".into()),
+            strategy.new_tree(&mut runner).err()
+        );
+    }
+}
@@ -7,7 +7,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::std_facade::{fmt, Arc};
+use crate::std_facade::{fmt, Arc, Box};
+use core::any::type_name;
 use core::mem;
 
 use crate::strategy::traits::*;
@@ -26,7 +27,8 @@ enum LazyValueTreeState<S: Strategy> {
     Initialized(S::Tree),
     Uninitialized {
         strategy: Arc<S>,
-        runner: TestRunner,
+        // Boxed so that initialized trees are not as large as a runner.
+        runner: Box<TestRunner>,
     },
     Failed,
 }
@@ -35,7 +37,7 @@ impl<S: Strategy> LazyValueTree<S> {
     /// Create a new value tree where initial generation is deferred until
     /// `maybe_init` is called.
     pub(crate) fn new(strategy: Arc<S>, runner: &mut TestRunner) -> Self {
-        let runner = runner.partial_clone();
+        let runner = Box::new(runner.partial_clone());
         Self {
             state: LazyValueTreeState::Uninitialized { strategy, runner },
         }
@@ -165,9 +167,11 @@ where
             LazyValueTreeState::Initialized(value_tree) => {
                 f.debug_tuple("Initialized").field(value_tree).finish()
             }
-            LazyValueTreeState::Uninitialized { strategy, .. } => f
+            // The strategy need not implement `Debug`, so describe it by its
+            // type.
+            LazyValueTreeState::Uninitialized { .. } => f
                 .debug_struct("Uninitialized")
-                .field("strategy", strategy)
+                .field("strategy", &format_args!("{}", type_name::<S>()))
                 .finish(),
             LazyValueTreeState::Failed => write!(f, "Failed"),
         }
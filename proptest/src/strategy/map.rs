@@ -8,6 +8,7 @@
 // except according to those terms.
 
 use crate::std_facade::Arc;
+use core::any::type_name;
 use core::fmt;
 use core::marker::PhantomData;
 
@@ -106,6 +107,7 @@ impl<S: fmt::Debug, O> fmt::Debug for MapInto<S, O> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("MapInto")
             .field("source", &self.source)
+            .field("output", &format_args!("{}", type_name::<O>()))
             .finish()
     }
 }
@@ -275,6 +277,15 @@ mod test {
             .unwrap();
     }
 
+    #[test]
+    fn map_into_debug_names_output_type() {
+        let strategy = Just(1u8).prop_map_into::<u32>();
+        assert_eq!(
+            "MapInto { source: Just(1), output: u32 }",
+            format!("{:?}", strategy)
+        );
+    }
+
     #[test]
     fn perturb_uses_same_rng_every_time() {
         let mut runner = TestRunner::default();
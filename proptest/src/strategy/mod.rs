@@ -9,9 +9,12 @@
 
 //! Defines the core traits used by Proptest.
 
+mod distribution;
+mod either;
 mod filter;
 mod filter_map;
 mod flatten;
+mod from_fn;
 mod fuse;
 mod just;
 mod lazy;
@@ -21,9 +24,12 @@ mod shuffle;
 mod traits;
 mod unions;
 
+pub use self::distribution::*;
+pub use self::either::*;
 pub use self::filter::*;
 pub use self::filter_map::*;
 pub use self::flatten::*;
+pub use self::from_fn::*;
 pub use self::fuse::*;
 pub use self::just::*;
 pub use self::lazy::*;
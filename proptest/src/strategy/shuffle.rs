@@ -17,10 +17,10 @@ use crate::test_runner::*;
 
 /// `Strategy` shuffle adaptor.
 ///
-/// See `Strategy::prop_shuffle()`.
+/// See `Strategy::prop_shuffle()` and `Strategy::prop_shuffle_n()`.
 #[derive(Clone, Debug)]
 #[must_use = "strategies do nothing unless used"]
-pub struct Shuffle<S>(pub(super) S);
+pub struct Shuffle<S>(pub(super) S, pub(super) Option<usize>);
 
 /// A value which can be used with the `prop_shuffle` combinator.
 ///
@@ -51,41 +51,27 @@ macro_rules! shuffleable {
 shuffleable!([T]);
 shuffleable!(Vec<T>);
 shuffleable!(VecDeque<T>);
-// Zero- and 1-length arrays aren't usefully shuffleable, but are included to
-// simplify external macros that may try to use them anyway.
-shuffleable!([T; 0]);
-shuffleable!([T; 1]);
-shuffleable!([T; 2]);
-shuffleable!([T; 3]);
-shuffleable!([T; 4]);
-shuffleable!([T; 5]);
-shuffleable!([T; 6]);
-shuffleable!([T; 7]);
-shuffleable!([T; 8]);
-shuffleable!([T; 9]);
-shuffleable!([T; 10]);
-shuffleable!([T; 11]);
-shuffleable!([T; 12]);
-shuffleable!([T; 13]);
-shuffleable!([T; 14]);
-shuffleable!([T; 15]);
-shuffleable!([T; 16]);
-shuffleable!([T; 17]);
-shuffleable!([T; 18]);
-shuffleable!([T; 19]);
-shuffleable!([T; 20]);
-shuffleable!([T; 21]);
-shuffleable!([T; 22]);
-shuffleable!([T; 23]);
-shuffleable!([T; 24]);
-shuffleable!([T; 25]);
-shuffleable!([T; 26]);
-shuffleable!([T; 27]);
-shuffleable!([T; 28]);
-shuffleable!([T; 29]);
-shuffleable!([T; 30]);
-shuffleable!([T; 31]);
-shuffleable!([T; 32]);
+impl<T, const N: usize> Shuffleable for [T; N] {
+    fn shuffle_len(&self) -> usize {
+        N
+    }
+
+    fn shuffle_swap(&mut self, a: usize, b: usize) {
+        self.swap(a, b);
+    }
+}
+
+#[cfg(feature = "smallvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "smallvec")))]
+impl<A: smallvec::Array> Shuffleable for smallvec::SmallVec<A> {
+    fn shuffle_len(&self) -> usize {
+        self.len()
+    }
+
+    fn shuffle_swap(&mut self, a: usize, b: usize) {
+        self.swap(a, b);
+    }
+}
 
 impl<S: Strategy> Strategy for Shuffle<S>
 where
@@ -100,6 +86,7 @@ where
         self.0.new_tree(runner).map(|inner| ShuffleValueTree {
             inner,
             rng,
+            max_swaps: self.1,
             dist: Cell::new(None),
             simplifying_inner: false,
         })
@@ -113,6 +100,8 @@ where
 pub struct ShuffleValueTree<V> {
     inner: V,
     rng: TestRng,
+    /// The number of random swaps to make, or `None` to shuffle fully.
+    max_swaps: Option<usize>,
     /// The maximum amount to move any one element during shuffling.
     ///
     /// This is `Cell` since we can't determine the bounds of the value until
@@ -166,6 +155,19 @@ where
 
         let mut rng = self.rng.clone();
 
+        if let Some(max_swaps) = self.max_swaps {
+            for _ in 0..max_swaps {
+                // Draw both indices before filtering, so that the RNG stays
+                // in step as `max_swap` changes (see below).
+                let a = rng.gen_range(0..len);
+                let b = rng.gen_range(0..len);
+                if a.max(b) - a.min(b) <= max_swap {
+                    value.shuffle_swap(a, b);
+                }
+            }
+            return value;
+        }
+
         for start_index in 0..len - 1 {
             // Determine the other index to be swapped, then skip the swap if
             // it is too far. This ordering is critical, as it ensures that we
@@ -277,6 +279,59 @@ mod test {
         }
     }
 
+    #[test]
+    fn shuffles_arrays_and_deques() {
+        let mut runner = TestRunner::deterministic();
+
+        let mut values = [0u8; 40];
+        for (ix, value) in values.iter_mut().enumerate() {
+            *value = ix as u8;
+        }
+        let mut array = Just(values).prop_shuffle().sample(&mut runner);
+        assert_ne!(values, array);
+        array.sort();
+        assert_eq!(values, array);
+
+        let deque: VecDeque<i32> = VALUES.iter().cloned().collect();
+        let shuffled = Just(deque.clone()).prop_shuffle().sample(&mut runner);
+        assert_ne!(deque, shuffled);
+    }
+
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn shuffles_smallvecs() {
+        let mut runner = TestRunner::deterministic();
+        let values: smallvec::SmallVec<[i32; 8]> =
+            VALUES.iter().cloned().collect();
+        let mut shuffled =
+            Just(values.clone()).prop_shuffle().sample(&mut runner);
+        assert_ne!(values, shuffled);
+        shuffled.sort();
+        assert_eq!(values, shuffled);
+    }
+
+    #[test]
+    fn bounded_shuffle_moves_few_elements() {
+        let mut runner = TestRunner::deterministic();
+        let input = Just(VALUES.to_owned()).prop_shuffle_n(2);
+        let mut moved_any = false;
+        for _ in 0..256 {
+            let mut value = input.new_tree(&mut runner).unwrap().current();
+            let moved = value
+                .iter()
+                .enumerate()
+                .filter(|&(ix, &v)| ix as i32 != v)
+                .count();
+            assert!(moved <= 4, "{:?}", value);
+            moved_any |= moved > 0;
+
+            value.sort();
+            assert_eq!(VALUES, &value[..]);
+        }
+        assert!(moved_any);
+        assert_eq!(VALUES, &input.minimal(&mut runner)[..]);
+    }
+
     #[test]
     fn simplify_complicate_contract_upheld() {
         check_strategy_sanity(
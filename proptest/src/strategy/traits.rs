@@ -8,6 +8,7 @@
 // except according to those terms.
 
 use crate::std_facade::{fmt, Arc, Box, Rc};
+use core::any::type_name;
 use core::cmp;
 
 use crate::strategy::*;
@@ -30,11 +31,10 @@ pub type NewTree<S> = Result<<S as Strategy>::Tree, Reason>;
 
 /// A strategy for producing arbitrary values of a given type.
 ///
-/// `fmt::Debug` is a hard requirement for all strategies currently due to
-/// `prop_flat_map()`. This constraint will be removed when specialisation
-/// becomes stable.
+/// Strategies need not implement `fmt::Debug`, though most of those provided
+/// by proptest do when the strategies and values they wrap do.
 #[must_use = "strategies do nothing unless used"]
-pub trait Strategy: fmt::Debug {
+pub trait Strategy {
     /// The value tree generated by this `Strategy`.
     type Tree: ValueTree<Value = Self::Value>;
 
@@ -238,11 +238,11 @@ pub trait Strategy: fmt::Debug {
     fn prop_flat_map<S: Strategy, F: Fn(Self::Value) -> S>(
         self,
         fun: F,
-    ) -> Flatten<Map<Self, F>>
+    ) -> Flatten<FlatMap<Self, F>>
     where
         Self: Sized,
     {
-        Flatten::new(Map {
+        Flatten::new(FlatMap {
             source: self,
             fun: Arc::new(fun),
         })
@@ -264,11 +264,11 @@ pub trait Strategy: fmt::Debug {
     fn prop_ind_flat_map<S: Strategy, F: Fn(Self::Value) -> S>(
         self,
         fun: F,
-    ) -> IndFlatten<Map<Self, F>>
+    ) -> IndFlatten<FlatMap<Self, F>>
     where
         Self: Sized,
     {
-        IndFlatten(Map {
+        IndFlatten(FlatMap {
             source: self,
             fun: Arc::new(fun),
         })
@@ -357,6 +357,52 @@ pub trait Strategy: fmt::Debug {
         FilterMap::new(self, whence.into(), fun)
     }
 
+    /// Returns a strategy which produces the values `fun` returns `Ok` for,
+    /// and rejects those it returns `Err` for, using the error as the reason
+    /// for the rejection.
+    ///
+    /// This is useful with fallible constructors which validate their input,
+    /// as it avoids repeating the validation in a separate `prop_filter`. As
+    /// with `prop_filter_map`, each `Err` counts as a local rejection, so the
+    /// same caveats about rejection sampling apply.
+    ///
+    /// ```
+    /// use proptest::prelude::*;
+    /// use proptest::test_runner::Reason;
+    ///
+    /// #[derive(Debug)]
+    /// struct Even(u32);
+    ///
+    /// impl Even {
+    ///     fn new(v: u32) -> Result<Self, Reason> {
+    ///         if v % 2 == 0 {
+    ///             Ok(Even(v))
+    ///         } else {
+    ///             Err(format!("{} is odd", v).into())
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// proptest! {
+    ///   # /*
+    ///   #[test]
+    ///   # */
+    ///   fn even_is_even(e in any::<u32>().prop_try_map(Even::new)) {
+    ///     prop_assert_eq!(0, e.0 % 2);
+    ///   }
+    /// }
+    /// # fn main() { even_is_even(); }
+    /// ```
+    fn prop_try_map<F: Fn(Self::Value) -> Result<O, Reason>, O: fmt::Debug>(
+        self,
+        fun: F,
+    ) -> FilterMap<Self, F>
+    where
+        Self: Sized,
+    {
+        FilterMap::new(self, "prop_try_map".into(), fun)
+    }
+
     /// Returns a strategy which picks uniformly from `self` and `other`.
     ///
     /// When shrinking, if a value from `other` was originally chosen but that
@@ -502,7 +548,42 @@ pub trait Strategy: fmt::Debug {
         Self: Sized,
         Self::Value: Shuffleable,
     {
-        Shuffle(self)
+        Shuffle(self, None)
+    }
+
+    /// Partially shuffle the contents of the values produced by this
+    /// strategy, by making at most `max_swaps` swaps of two random elements.
+    ///
+    /// This produces "almost sorted" data from sorted input, with at most
+    /// `2 * max_swaps` elements out of place, which a full shuffle almost
+    /// never does. Shrinking works as for `prop_shuffle()`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use proptest::prelude::*;
+    ///
+    /// proptest! {
+    ///   # /*
+    ///   #[test]
+    ///   # */
+    ///   fn test_nearly_sorted(
+    ///       v in Just((0..100).collect::<Vec<u32>>()).prop_shuffle_n(3)
+    ///   ) {
+    ///       let out_of_place =
+    ///           v.iter().enumerate().filter(|&(ix, &x)| ix as u32 != x);
+    ///       prop_assert!(out_of_place.count() <= 6);
+    ///   }
+    /// }
+    /// #
+    /// # fn main() { test_nearly_sorted(); }
+    /// ```
+    fn prop_shuffle_n(self, max_swaps: usize) -> Shuffle<Self>
+    where
+        Self: Sized,
+        Self::Value: Shuffleable,
+    {
+        Shuffle(self, Some(max_swaps))
     }
 
     /// Erases the type of this `Strategy` so it can be passed around as a
@@ -517,7 +598,7 @@ pub trait Strategy: fmt::Debug {
     where
         Self: Sized + 'static,
     {
-        BoxedStrategy(Arc::new(BoxedStrategyWrapper(self)))
+        BoxedStrategy(Arc::new(BoxedStrategyWrapper(self)), type_name::<Self>())
     }
 
     /// Erases the type of this `Strategy` so it can be passed around as a
@@ -532,7 +613,10 @@ pub trait Strategy: fmt::Debug {
     where
         Self: Sized + Send + Sync + 'static,
     {
-        SBoxedStrategy(Arc::new(BoxedStrategyWrapper(self)))
+        SBoxedStrategy(
+            Arc::new(BoxedStrategyWrapper(self)),
+            type_name::<Self>(),
+        )
     }
 
     /// Wraps this strategy to prevent values from being subject to shrinking.
@@ -550,6 +634,98 @@ pub trait Strategy: fmt::Debug {
     {
         NoShrink(self)
     }
+
+    /// Wraps this strategy as a `rand::distributions::Distribution`, for
+    /// sampling values outside of tests with any random number generator.
+    ///
+    /// Each sample seeds a new `TestRunner` with the default configuration
+    /// from the generator given, so samples are reproducible if the
+    /// generator is. Values are not shrunk.
+    ///
+    /// ```
+    /// use proptest::prelude::*;
+    /// use rand::SeedableRng;
+    ///
+    /// let names = "[a-z]{1,8}".into_distribution();
+    /// let mut rng = rand_chacha::ChaChaRng::seed_from_u64(1);
+    /// for name in (&mut rng).sample_iter(&names).take(10) {
+    ///     assert!(!name.is_empty() && name.len() <= 8);
+    /// }
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Sampling panics if the strategy fails to generate a value, such as
+    /// when it rejects too many values.
+    fn into_distribution(self) -> IntoDistribution<Self>
+    where
+        Self: Sized,
+    {
+        IntoDistribution::new(self)
+    }
+
+    /// Generates a single value from this strategy using `runner`.
+    ///
+    /// This is useful for producing example values outside of a test, such
+    /// as in benchmarks, demos or scripts generating seed data. The value is
+    /// not shrunk.
+    ///
+    /// ```
+    /// use proptest::prelude::*;
+    /// use proptest::test_runner::TestRunner;
+    ///
+    /// let mut runner = TestRunner::default();
+    /// let names = prop::collection::vec("[a-z]{1,8}", 3);
+    /// for name in names.sample(&mut runner) {
+    ///     assert!(!name.is_empty() && name.len() <= 8);
+    /// }
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the strategy fails to generate a value, such as when it
+    /// rejects too many values.
+    fn sample(&self, runner: &mut TestRunner) -> Self::Value {
+        match self.new_tree(runner) {
+            Ok(tree) => tree.current(),
+            Err(reason) => panic!("Failed to generate a value: {}", reason),
+        }
+    }
+
+    /// Generates a value from this strategy using `runner` and simplifies it
+    /// as far as possible, returning the simplest value the strategy can
+    /// shrink it to.
+    ///
+    /// This is the value a test which fails for every input would be reduced
+    /// to, which makes it useful for documenting and testing how a custom
+    /// strategy shrinks. For strategies whose shrinking does not depend on
+    /// the initial value, the result is the same on every call.
+    ///
+    /// ```
+    /// use proptest::prelude::*;
+    /// use proptest::test_runner::TestRunner;
+    ///
+    /// let mut runner = TestRunner::default();
+    /// assert_eq!(10, (10..100).minimal(&mut runner));
+    /// assert_eq!(
+    ///     vec![0, 0],
+    ///     prop::collection::vec(any::<u8>(), 2..8).minimal(&mut runner)
+    /// );
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the strategy fails to generate a value, such as when it
+    /// rejects too many values.
+    fn minimal(&self, runner: &mut TestRunner) -> Self::Value {
+        match self.new_tree(runner) {
+            Ok(mut tree) => {
+                while tree.simplify() {}
+                tree.current()
+            }
+            Err(reason) => panic!("Failed to generate a value: {}", reason),
+        }
+    }
 }
 
 /// A generated value and its associated shrinker.
@@ -698,30 +874,54 @@ type BoxedVT<T> = Box<dyn ValueTree<Value = T>>;
 ///
 /// Strategies of this type afford cheap shallow cloning via reference
 /// counting by using an `Arc` internally.
-#[derive(Debug)]
+///
+/// Since the boxed strategy need not implement `Debug`, this is formatted by
+/// `Debug` as the type name of the boxed strategy.
 #[must_use = "strategies do nothing unless used"]
-pub struct BoxedStrategy<T>(Arc<dyn Strategy<Value = T, Tree = BoxedVT<T>>>);
+pub struct BoxedStrategy<T>(
+    Arc<dyn Strategy<Value = T, Tree = BoxedVT<T>>>,
+    &'static str,
+);
 
 /// A boxed `Strategy` trait object which is also `Sync` and
 /// `Send`, as produced by `Strategy::sboxed()`.
 ///
 /// Strategies of this type afford cheap shallow cloning via reference
 /// counting by using an `Arc` internally.
-#[derive(Debug)]
+///
+/// Like `BoxedStrategy`, this is formatted by `Debug` as the type name of the
+/// boxed strategy.
 #[must_use = "strategies do nothing unless used"]
 pub struct SBoxedStrategy<T>(
     Arc<dyn Strategy<Value = T, Tree = BoxedVT<T>> + Sync + Send>,
+    &'static str,
 );
 
+impl<T> fmt::Debug for BoxedStrategy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("BoxedStrategy")
+            .field(&format_args!("{}", self.1))
+            .finish()
+    }
+}
+
+impl<T> fmt::Debug for SBoxedStrategy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("SBoxedStrategy")
+            .field(&format_args!("{}", self.1))
+            .finish()
+    }
+}
+
 impl<T> Clone for BoxedStrategy<T> {
     fn clone(&self) -> Self {
-        BoxedStrategy(Arc::clone(&self.0))
+        BoxedStrategy(Arc::clone(&self.0), self.1)
     }
 }
 
 impl<T> Clone for SBoxedStrategy<T> {
     fn clone(&self) -> Self {
-        SBoxedStrategy(Arc::clone(&self.0))
+        SBoxedStrategy(Arc::clone(&self.0), self.1)
     }
 }
 
@@ -764,7 +964,7 @@ impl<T: fmt::Debug> Strategy for SBoxedStrategy<T> {
     where
         Self: Sized + 'static,
     {
-        BoxedStrategy(self.0)
+        BoxedStrategy(self.0, self.1)
     }
 }
 
@@ -149,7 +149,7 @@ impl<T: Strategy> Strategy for Union<T> {
         Ok(UnionValueTree {
             options,
             pick,
-            min_pick: 0,
+            max_alt: pick,
             prev_pick: None,
         })
     }
@@ -169,7 +169,9 @@ pub struct UnionValueTree<T: Strategy> {
     // `pick` and `prev_pick` (if Some) always point to initialized
     // trees.
     pick: usize,
-    min_pick: usize,
+    // Options before this index have not yet been tried in place of `pick`
+    // during shrinking.
+    max_alt: usize,
     prev_pick: Option<usize>,
 }
 
@@ -202,22 +204,10 @@ macro_rules! lazy_union_value_tree_body {
                 return true;
             }
 
-            assert!(
-                self.pick >= self.min_pick,
-                "self.pick = {} should never go below self.min_pick = {}",
-                self.pick,
-                self.min_pick,
-            );
-            if self.pick == self.min_pick {
-                // No more simplification to be done.
-                return false;
-            }
-
-            // self.prev_pick is always a valid pick.
-            self.prev_pick = Some(self.pick);
-
-            let mut next_pick = self.pick;
-            while next_pick > self.min_pick {
+            // Fall back to the closest earlier option which has not been
+            // tried yet.
+            let mut next_pick = self.max_alt;
+            while next_pick > 0 {
                 next_pick -= 1;
                 let initialized = $access!([mut] opt = self, next_pick, {
                     opt.maybe_init();
@@ -225,19 +215,26 @@ macro_rules! lazy_union_value_tree_body {
                 });
                 if initialized {
                     // next_pick was correctly initialized above.
+                    self.prev_pick = Some(self.pick);
                     self.pick = next_pick;
+                    self.max_alt = next_pick;
                     return true;
                 }
             }
 
+            // No more simplification to be done.
+            self.max_alt = 0;
+            self.prev_pick = None;
             false
         }
 
         fn complicate(&mut self) -> bool {
             if let Some(pick) = self.prev_pick {
                 // simplify() ensures that the previous pick was initialized.
+                // Options before the one just rejected may still work, so
+                // they are tried next.
+                self.max_alt = self.pick;
                 self.pick = pick;
-                self.min_pick = pick;
                 self.prev_pick = None;
                 true
             } else {
@@ -267,7 +264,7 @@ where
         Self {
             options: self.options.clone(),
             pick: self.pick,
-            min_pick: self.min_pick,
+            max_alt: self.max_alt,
             prev_pick: self.prev_pick,
         }
     }
@@ -281,7 +278,7 @@ where
         f.debug_struct("UnionValueTree")
             .field("options", &self.options)
             .field("pick", &self.pick)
-            .field("min_pick", &self.min_pick)
+            .field("max_alt", &self.max_alt)
             .field("prev_pick", &self.prev_pick)
             .finish()
     }
@@ -326,6 +323,28 @@ def_access_tuple!($ access_tuple7, 1 2 3 4 5 6);
 def_access_tuple!($ access_tuple8, 1 2 3 4 5 6 7);
 def_access_tuple!($ access_tuple9, 1 2 3 4 5 6 7 8);
 def_access_tuple!($ access_tupleA, 1 2 3 4 5 6 7 8 9);
+def_access_tuple!($ access_tupleB, 1 2 3 4 5 6 7 8 9 10);
+def_access_tuple!($ access_tupleC, 1 2 3 4 5 6 7 8 9 10 11);
+def_access_tuple!($ access_tupleD, 1 2 3 4 5 6 7 8 9 10 11 12);
+def_access_tuple!($ access_tupleE, 1 2 3 4 5 6 7 8 9 10 11 12 13);
+def_access_tuple!($ access_tupleF, 1 2 3 4 5 6 7 8 9 10 11 12 13 14);
+def_access_tuple!($ access_tupleG, 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15);
+
+/// Formats `fields` the way a tuple of them is formatted.
+///
+/// The standard library only implements `Debug` for tuples of up to 12
+/// elements, so the tuple unions format their options with this instead.
+struct DebugTuple<'a>(&'a [&'a dyn fmt::Debug]);
+
+impl fmt::Debug for DebugTuple<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut tuple = f.debug_tuple("");
+        for field in self.0 {
+            tuple.field(field);
+        }
+        tuple.finish()
+    }
+}
 
 /// Similar to `Union`, but internally uses a tuple to hold the strategies.
 ///
@@ -336,14 +355,14 @@ def_access_tuple!($ access_tupleA, 1 2 3 4 5 6 7 8 9);
 /// The difference between this and `TupleUnion` is that with this, value trees
 /// for variants that aren't picked at first are generated lazily.
 #[must_use = "strategies do nothing unless used"]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
 pub struct TupleUnion<T>(T);
 
 impl<T> TupleUnion<T> {
     /// Wrap `tuple` in a `TupleUnion`.
     ///
     /// The struct definition allows any `T` for `tuple`, but to be useful, it
-    /// must be a 2- to 10-tuple of `(u32, Arc<impl Strategy>)` pairs where all
+    /// must be a 2- to 16-tuple of `(u32, Arc<impl Strategy>)` pairs where all
     /// strategies ultimately produce the same value. Each `u32` indicates the
     /// relative weight of its corresponding strategy.
     /// You may use `WA<S>` as an alias for `(u32, Arc<S>)`.
@@ -357,6 +376,15 @@ impl<T> TupleUnion<T> {
 
 macro_rules! tuple_union {
     ($($gen:ident $ix:tt)*) => {
+        impl<A : fmt::Debug, $($gen: fmt::Debug),*>
+        fmt::Debug for TupleUnion<(WA<A>, $(WA<$gen>),*)> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_tuple("TupleUnion")
+                    .field(&DebugTuple(&[&(self.0).0, $(&(self.0).$ix),*]))
+                    .finish()
+            }
+        }
+
         impl<A : Strategy, $($gen: Strategy<Value = A::Value>),*>
         Strategy for TupleUnion<(WA<A>, $(WA<$gen>),*)> {
             type Tree = TupleUnionValueTree<
@@ -388,7 +416,7 @@ macro_rules! tuple_union {
                             None
                         }),*),
                     pick: pick,
-                    min_pick: 0,
+                    max_alt: pick,
                     prev_pick: None,
                 })
             }
@@ -405,36 +433,72 @@ tuple_union!(B 1 C 2 D 3 E 4 F 5 G 6);
 tuple_union!(B 1 C 2 D 3 E 4 F 5 G 6 H 7);
 tuple_union!(B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8);
 tuple_union!(B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9);
+tuple_union!(B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9 K 10);
+tuple_union!(B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9 K 10 L 11);
+tuple_union!(B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9 K 10 L 11 M 12);
+tuple_union!(B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9 K 10 L 11 M 12 N 13);
+tuple_union!(B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9 K 10 L 11 M 12 N 13 O 14);
+tuple_union!(B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9 K 10 L 11 M 12 N 13 O 14
+             P 15);
 
 /// `ValueTree` type produced by `TupleUnion`.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
 pub struct TupleUnionValueTree<T> {
     options: T,
     pick: usize,
-    min_pick: usize,
+    max_alt: usize,
     prev_pick: Option<usize>,
 }
 
 macro_rules! value_tree_tuple {
-    ($access:ident, $($gen:ident)*) => {
+    ($access:ident, $($gen:ident $ix:tt)*) => {
         impl<A : Strategy, $($gen: Strategy<Value = A::Value>),*> ValueTree
         for TupleUnionValueTree<
             (LazyValueTree<A>, $(Option<LazyValueTree<$gen>>),*)
         > {
             lazy_union_value_tree_body!(A::Value, $access);
         }
+
+        impl<A : Strategy, $($gen: Strategy<Value = A::Value>),*> fmt::Debug
+        for TupleUnionValueTree<
+            (LazyValueTree<A>, $(Option<LazyValueTree<$gen>>),*)
+        >
+        where
+            A::Tree: fmt::Debug,
+            $($gen::Tree: fmt::Debug),*
+        {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_struct("TupleUnionValueTree")
+                    .field("options", &DebugTuple(
+                        &[&self.options.0, $(&self.options.$ix),*]))
+                    .field("pick", &self.pick)
+                    .field("max_alt", &self.max_alt)
+                    .field("prev_pick", &self.prev_pick)
+                    .finish()
+            }
+        }
     }
 }
 
-value_tree_tuple!(access_tuple2, B);
-value_tree_tuple!(access_tuple3, B C);
-value_tree_tuple!(access_tuple4, B C D);
-value_tree_tuple!(access_tuple5, B C D E);
-value_tree_tuple!(access_tuple6, B C D E F);
-value_tree_tuple!(access_tuple7, B C D E F G);
-value_tree_tuple!(access_tuple8, B C D E F G H);
-value_tree_tuple!(access_tuple9, B C D E F G H I);
-value_tree_tuple!(access_tupleA, B C D E F G H I J);
+value_tree_tuple!(access_tuple2, B 1);
+value_tree_tuple!(access_tuple3, B 1 C 2);
+value_tree_tuple!(access_tuple4, B 1 C 2 D 3);
+value_tree_tuple!(access_tuple5, B 1 C 2 D 3 E 4);
+value_tree_tuple!(access_tuple6, B 1 C 2 D 3 E 4 F 5);
+value_tree_tuple!(access_tuple7, B 1 C 2 D 3 E 4 F 5 G 6);
+value_tree_tuple!(access_tuple8, B 1 C 2 D 3 E 4 F 5 G 6 H 7);
+value_tree_tuple!(access_tuple9, B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8);
+value_tree_tuple!(access_tupleA, B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9);
+value_tree_tuple!(access_tupleB, B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9 K 10);
+value_tree_tuple!(access_tupleC, B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9 K 10 L 11);
+value_tree_tuple!(access_tupleD, B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9 K 10
+                  L 11 M 12);
+value_tree_tuple!(access_tupleE, B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9 K 10
+                  L 11 M 12 N 13);
+value_tree_tuple!(access_tupleF, B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9 K 10
+                  L 11 M 12 N 13 O 14);
+value_tree_tuple!(access_tupleG, B 1 C 2 D 3 E 4 F 5 G 6 H 7 I 8 J 9 K 10
+                  L 11 M 12 N 13 O 14 P 15);
 
 const WEIGHT_BASE: u32 = 0x8000_0000;
 
@@ -514,6 +578,43 @@ mod test {
         );
     }
 
+    fn check_shrinks_through_earlier_options<S: Strategy<Value = usize>>(
+        input: S,
+    ) {
+        // Only the first and last options fail, so shrinking from the last
+        // option must get past the passing ones in between.
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..64 {
+            let case = input.new_tree(&mut runner).unwrap();
+            let result = runner.run_one(case, |v| {
+                prop_assert!(v != 0 && v != 3);
+                Ok(())
+            });
+
+            match result {
+                Ok(true) | Err(TestError::Fail(_, 0)) => (),
+                e => panic!("Unexpected result: {:?}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_union_shrinks_through_earlier_options() {
+        check_shrinks_through_earlier_options(Union::new(
+            (0..4).map(Just).collect::<Vec<_>>(),
+        ));
+    }
+
+    #[test]
+    fn test_tuple_union_shrinks_through_earlier_options() {
+        check_shrinks_through_earlier_options(TupleUnion::new((
+            (1, Arc::new(Just(0usize))),
+            (1, Arc::new(Just(1usize))),
+            (1, Arc::new(Just(2usize))),
+            (1, Arc::new(Just(3usize))),
+        )));
+    }
+
     #[test]
     fn test_union_weighted() {
         let input = Union::new_weighted(vec![
@@ -645,6 +746,12 @@ mod test {
         test!(r, r, r, r, r, r, r); // 8
         test!(r, r, r, r, r, r, r, r); // 9
         test!(r, r, r, r, r, r, r, r, r); // 10
+        test!(r, r, r, r, r, r, r, r, r, r); // 11
+        test!(r, r, r, r, r, r, r, r, r, r, r); // 12
+        test!(r, r, r, r, r, r, r, r, r, r, r, r); // 13
+        test!(r, r, r, r, r, r, r, r, r, r, r, r, r); // 14
+        test!(r, r, r, r, r, r, r, r, r, r, r, r, r, r); // 15
+        test!(r, r, r, r, r, r, r, r, r, r, r, r, r, r, r); // 16
     }
 
     #[test]
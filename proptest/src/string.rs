@@ -9,9 +9,19 @@
 
 //! Strategies for generating strings and byte strings from regular
 //! expressions.
-
-use crate::std_facade::{Box, Cow, String, ToOwned, Vec};
+//!
+//! With the `unicode-segmentation` feature, `graphemes()` additionally
+//! generates strings of a given number of user-perceived characters, and with
+//! the `unicode-normalization` feature, `normalized()` and
+//! `canonically_equivalent()` generate strings in particular normalization
+//! forms. `markov()` generates strings resembling a sample text, and
+//! `cjk_text()`, `rtl_text()`, `emoji_text()` and `mixed_direction_text()`
+//! generate text in writing systems which often trip up text handling.
+
+use crate::std_facade::{Arc, Box, Cow, HashMap, String, ToOwned, Vec};
+use core::cmp::Reverse;
 use core::fmt;
+use core::iter;
 use core::mem;
 use core::ops::RangeInclusive;
 use core::u32;
@@ -21,9 +31,13 @@ use regex_syntax::{Error as ParseError, ParserBuilder};
 
 use crate::bool;
 use crate::char;
-use crate::collection::{size_range, vec, SizeRange};
+use crate::collection::{
+    size_range, vec, SizeRange, VecStrategy, VecValueTree,
+};
+use crate::sample::{Index, IndexStrategy, IndexValueTree};
 use crate::strategy::*;
 use crate::test_runner::*;
+use crate::tuple::TupleValueTree;
 
 /// Wraps the regex that forms the `Strategy` for `String` so that a sensible
 /// `Default` can be given. The default is a string of non-control characters.
@@ -105,6 +119,603 @@ impl Strategy for str {
     }
 }
 
+opaque_strategy_wrapper! {
+    {#[cfg(feature = "unicode-segmentation")]}
+    {#[cfg_attr(docsrs, doc(cfg(feature = "unicode-segmentation")))]}
+    /// Strategy which generates strings of a number of extended grapheme
+    /// clusters.
+    ///
+    /// Created by the `graphemes()` function in the same module.
+    #[derive(Debug)]
+    pub struct GraphemeStrategy[][](SBoxedStrategy<String>)
+        -> GraphemeValueTree;
+    /// `ValueTree` corresponding to `GraphemeStrategy`.
+    pub struct GraphemeValueTree[][](Box<dyn ValueTree<Value = String>>)
+        -> String;
+}
+
+/// Creates a strategy which generates strings made of a number of extended
+/// grapheme clusters (as defined by
+/// [UAX #29](https://www.unicode.org/reports/tr29/)) within `size`.
+///
+/// Unlike the regex-based strategies, the length here is the user-perceived
+/// length of the string. Each cluster is usually a single character,
+/// sometimes followed by combining marks, and occasionally a `"\r\n"`
+/// pair, a flag made of two regional indicators, or an emoji ZWJ sequence.
+/// Adjacent clusters are generated so that they never merge into one.
+///
+/// Shrinks by removing clusters and simplifying the remaining ones towards
+/// plain ASCII.
+#[cfg(feature = "unicode-segmentation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unicode-segmentation")))]
+pub fn graphemes(size: impl Into<SizeRange>) -> GraphemeStrategy {
+    GraphemeStrategy(
+        vec(grapheme_cluster(), size)
+            .prop_map(|clusters| clusters.concat())
+            .sboxed(),
+    )
+}
+
+#[cfg(feature = "unicode-segmentation")]
+fn grapheme_cluster() -> SBoxedStrategy<String> {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    // Characters which pass the single-cluster check below but still join
+    // with an adjacent cluster: Hangul jamo combine into syllables, regional
+    // indicators pair up into flags and a lone CR absorbs a following LF.
+    const JOINING: &[RangeInclusive<char>] = &[
+        '\r'..='\r',
+        '\u{1100}'..='\u{11FF}',
+        '\u{A960}'..='\u{A97F}',
+        '\u{D7B0}'..='\u{D7FF}',
+        '\u{1F1E6}'..='\u{1F1FF}',
+    ];
+    const ZWJ_SEQUENCES: &[&str] = &[
+        "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+        "\u{1F469}\u{200D}\u{1F4BB}",
+        "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}",
+        "\u{1F9D1}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}",
+    ];
+
+    let combined = (
+        char::any().prop_filter("joins with neighbouring clusters", |c| {
+            !JOINING.iter().any(|r| r.contains(c))
+        }),
+        vec(char::range('\u{300}', '\u{36F}'), 0..3),
+    )
+        .prop_map(|(base, marks)| {
+            core::iter::once(base).chain(marks).collect::<String>()
+        })
+        // Reject extending and prepended characters, which attach to their
+        // neighbours instead of standing alone.
+        .prop_filter("not a single grapheme cluster", |s| {
+            format!("a{}a", s).graphemes(true).count() == 3
+        });
+    let flag = (
+        char::range('\u{1F1E6}', '\u{1F1FF}'),
+        char::range('\u{1F1E6}', '\u{1F1FF}'),
+    )
+        .prop_map(|(a, b)| [a, b].iter().collect::<String>());
+    let zwj = crate::sample::select(ZWJ_SEQUENCES).prop_map(String::from);
+
+    prop_oneof![
+        16 => combined,
+        1 => Just("\r\n".to_owned()),
+        1 => flag,
+        1 => zwj,
+    ]
+    .sboxed()
+}
+
+/// A Unicode normalization form, as defined by
+/// [UAX #15](https://www.unicode.org/reports/tr15/).
+#[cfg(feature = "unicode-normalization")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unicode-normalization")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NormalizationForm {
+    /// Canonical decomposition followed by canonical composition.
+    Nfc,
+    /// Canonical decomposition.
+    Nfd,
+    /// Compatibility decomposition followed by canonical composition.
+    Nfkc,
+    /// Compatibility decomposition.
+    Nfkd,
+}
+
+#[cfg(feature = "unicode-normalization")]
+impl NormalizationForm {
+    fn normalize(self, s: &str) -> String {
+        use unicode_normalization::UnicodeNormalization;
+
+        match self {
+            NormalizationForm::Nfc => s.nfc().collect(),
+            NormalizationForm::Nfd => s.nfd().collect(),
+            NormalizationForm::Nfkc => s.nfkc().collect(),
+            NormalizationForm::Nfkd => s.nfkd().collect(),
+        }
+    }
+}
+
+#[cfg(feature = "unicode-normalization")]
+#[derive(Clone, Copy, Debug)]
+struct Normalize(NormalizationForm);
+
+#[cfg(feature = "unicode-normalization")]
+impl statics::MapFn<String> for Normalize {
+    type Output = String;
+
+    fn apply(&self, s: String) -> String {
+        self.0.normalize(&s)
+    }
+}
+
+opaque_strategy_wrapper! {
+    {#[cfg(feature = "unicode-normalization")]}
+    {#[cfg_attr(docsrs, doc(cfg(feature = "unicode-normalization")))]}
+    /// Strategy which normalizes the strings generated by another strategy.
+    ///
+    /// Created by the `normalized()` function in the same module.
+    #[derive(Clone, Debug)]
+    pub struct NormalizedStrategy[<S>][where S : Strategy<Value = String>](
+        statics::Map<S, Normalize>) -> NormalizedValueTree<S::Tree>;
+    /// `ValueTree` corresponding to `NormalizedStrategy`.
+    #[derive(Clone, Debug)]
+    pub struct NormalizedValueTree[<T>][where T : ValueTree<Value = String>](
+        statics::Map<T, Normalize>) -> String;
+}
+
+/// Creates a strategy which generates the strings of `source` converted to
+/// the normalization form `form`.
+///
+/// For example, `normalized("\\PC*", NormalizationForm::Nfd)` generates
+/// arbitrary strings with all precomposed characters decomposed. Shrinking
+/// is delegated to `source`.
+#[cfg(feature = "unicode-normalization")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unicode-normalization")))]
+pub fn normalized<S: Strategy<Value = String>>(
+    source: S,
+    form: NormalizationForm,
+) -> NormalizedStrategy<S> {
+    NormalizedStrategy(statics::Map::new(source, Normalize(form)))
+}
+
+opaque_strategy_wrapper! {
+    {#[cfg(feature = "unicode-normalization")]}
+    {#[cfg_attr(docsrs, doc(cfg(feature = "unicode-normalization")))]}
+    /// Strategy which generates pairs of canonically equivalent strings
+    /// with different bytes.
+    ///
+    /// Created by the `canonically_equivalent()` function in the same module.
+    #[derive(Debug)]
+    pub struct CanonicallyEquivalentStrategy[][](
+        SBoxedStrategy<(String, String)>)
+        -> CanonicallyEquivalentValueTree;
+    /// `ValueTree` corresponding to `CanonicallyEquivalentStrategy`.
+    pub struct CanonicallyEquivalentValueTree[][](
+        Box<dyn ValueTree<Value = (String, String)>>) -> (String, String);
+}
+
+/// Creates a strategy which generates pairs of strings of `size` characters
+/// which are canonically equivalent but not byte-for-byte equal.
+///
+/// The first string of each pair is in NFC and the second in NFD. The
+/// strings mix arbitrary characters with precomposed letters, Hangul
+/// syllables and letters followed by several combining marks, so that
+/// composition, decomposition and the reordering of marks are all exercised.
+/// Since the two strings must differ, `size` counts the characters before
+/// normalization and must allow at least one.
+///
+/// Shrinks by removing characters and simplifying the remaining ones, keeping
+/// at least one which normalizes differently in the two forms.
+#[cfg(feature = "unicode-normalization")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unicode-normalization")))]
+pub fn canonically_equivalent(
+    size: impl Into<SizeRange>,
+) -> CanonicallyEquivalentStrategy {
+    let marked = (
+        char::range('a', 'z'),
+        vec(char::range('\u{300}', '\u{36F}'), 1..4),
+    )
+        .prop_map(|(base, marks)| {
+            core::iter::once(base).chain(marks).collect::<String>()
+        });
+    let part = prop_oneof![
+        char::any().prop_map(String::from),
+        // Latin-1 Supplement and Latin Extended-A, mostly precomposed letters
+        char::range('\u{C0}', '\u{17F}').prop_map(String::from),
+        // Latin Extended Additional
+        char::range('\u{1E00}', '\u{1EFF}').prop_map(String::from),
+        // Hangul syllables
+        char::range('\u{AC00}', '\u{D7A3}').prop_map(String::from),
+        marked,
+    ];
+
+    CanonicallyEquivalentStrategy(
+        vec(part, size)
+            .prop_map(|parts| {
+                let s = parts.concat();
+                (
+                    NormalizationForm::Nfc.normalize(&s),
+                    NormalizationForm::Nfd.normalize(&s),
+                )
+            })
+            .prop_filter("canonical forms are identical", |(nfc, nfd)| {
+                nfc != nfd
+            })
+            .sboxed(),
+    )
+}
+
+/// The character transitions learned from a corpus by `markov()`.
+#[derive(Debug)]
+struct MarkovModel {
+    order: usize,
+    corpus: Vec<char>,
+    /// The characters which follow each context of `order` characters in the
+    /// corpus, repeated as often as they occur and most frequent first.
+    successors: HashMap<Box<[char]>, Vec<char>>,
+}
+
+impl MarkovModel {
+    fn new(corpus: &str, order: usize) -> Self {
+        let corpus = corpus.chars().collect::<Vec<_>>();
+        assert!(!corpus.is_empty(), "Markov corpus must not be empty");
+
+        // Wrap around the end of the corpus, so that every context has at
+        // least one successor.
+        let cyclic = corpus
+            .iter()
+            .chain(corpus.iter().cycle().take(order))
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut counts = HashMap::<Box<[char]>, Vec<(char, usize)>>::new();
+        for window in cyclic.windows(order + 1) {
+            let (context, next) = (&window[..order], window[order]);
+            let seen = counts.entry(context.into()).or_default();
+            match seen.iter_mut().find(|&&mut (c, _)| c == next) {
+                Some((_, count)) => *count += 1,
+                None => seen.push((next, 1)),
+            }
+        }
+
+        let successors = counts
+            .into_iter()
+            .map(|(context, mut seen)| {
+                seen.sort_by_key(|&(c, count)| (Reverse(count), c));
+                let next = seen
+                    .into_iter()
+                    .flat_map(|(c, count)| iter::repeat(c).take(count))
+                    .collect();
+                (context, next)
+            })
+            .collect();
+
+        MarkovModel {
+            order,
+            corpus,
+            successors,
+        }
+    }
+}
+
+/// Walks a `MarkovModel` from a starting position in the corpus, taking one
+/// step per element of the `Vec`.
+#[derive(Clone, Debug)]
+struct MarkovWalk(Arc<MarkovModel>);
+
+impl statics::MapFn<(Index, Vec<Index>)> for MarkovWalk {
+    type Output = String;
+
+    fn apply(&self, (start, steps): (Index, Vec<Index>)) -> String {
+        let model = &*self.0;
+        let mut text = model
+            .corpus
+            .iter()
+            .cycle()
+            .skip(start.index(model.corpus.len()))
+            .take(model.order.min(steps.len()))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for step in &steps[text.len()..] {
+            // The text so far is always a substring of the cyclic corpus, so
+            // its last characters are a known context.
+            let next = &model.successors[&text[text.len() - model.order..]];
+            text.push(next[step.index(next.len())]);
+        }
+
+        text.into_iter().collect()
+    }
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates strings resembling a sample text.
+    ///
+    /// Created by the `markov()` function in the same module.
+    #[derive(Clone, Debug)]
+    pub struct MarkovStrategy[][](
+        statics::Map<(IndexStrategy, VecStrategy<IndexStrategy>), MarkovWalk>)
+        -> MarkovValueTree;
+    /// `ValueTree` corresponding to `MarkovStrategy`.
+    #[derive(Clone, Debug)]
+    pub struct MarkovValueTree[][](
+        statics::Map<TupleValueTree<(IndexValueTree,
+                                     VecValueTree<IndexValueTree>)>,
+                     MarkovWalk>)
+        -> String;
+}
+
+/// Creates a strategy which generates strings of a number of characters
+/// within `length` that resemble the text in `corpus`.
+///
+/// The strings come from a character-level Markov chain of the given
+/// `order`: each character is chosen based on the `order` characters before
+/// it, with the same frequencies as in the corpus. An order of 2 or 3 gives
+/// word-like fragments; higher orders reproduce longer runs of the corpus
+/// verbatim. Every string starts at a random position in the corpus, and the
+/// end of the corpus is treated as running on into its start.
+///
+/// Shrinks towards shorter strings which start at the beginning of the corpus
+/// and follow its most frequent transitions.
+///
+/// ## Panics
+///
+/// Panics if `corpus` is empty.
+///
+/// ## Example
+///
+/// ```
+/// use proptest::prelude::*;
+/// use proptest::string::markov;
+///
+/// const CORPUS: &str = "the quick brown fox jumps over the lazy dog. \
+///                       the dog sleeps while the fox runs away. ";
+///
+/// proptest! {
+///     # /*
+///     #[test]
+///     # */
+///     fn word_counts_agree(text in markov(CORPUS, 2, 0..200)) {
+///         let words = text.split(' ').filter(|w| !w.is_empty()).count();
+///         prop_assert_eq!(words, text.split_whitespace().count());
+///     }
+/// }
+/// #
+/// # fn main() { word_counts_agree(); }
+/// ```
+pub fn markov(
+    corpus: &str,
+    order: usize,
+    length: impl Into<SizeRange>,
+) -> MarkovStrategy {
+    MarkovStrategy(statics::Map::new(
+        (IndexStrategy::new(), vec(IndexStrategy::new(), length)),
+        MarkovWalk(Arc::new(MarkovModel::new(corpus, order))),
+    ))
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates strings of text in a particular writing
+    /// system or mix of writing systems.
+    ///
+    /// Created by `cjk_text()`, `rtl_text()`, `emoji_text()` and
+    /// `mixed_direction_text()`.
+    #[derive(Debug)]
+    pub struct TextPresetStrategy[][](SBoxedStrategy<String>)
+        -> TextPresetValueTree;
+    /// `ValueTree` corresponding to `TextPresetStrategy`.
+    pub struct TextPresetValueTree[][](Box<dyn ValueTree<Value = String>>)
+        -> String;
+}
+
+/// Creates a strategy which generates strings of a number of characters
+/// within `size` which are mostly Chinese, Japanese and Korean.
+///
+/// Most characters are CJK ideographs, with some kana, Hangul syllables,
+/// CJK and fullwidth punctuation, ASCII, and ideographs outside the Basic
+/// Multilingual Plane, which take two UTF-16 code units. Nearly every
+/// character is wide in a terminal and takes three or four bytes in UTF-8,
+/// which trips up code that truncates or pads text by bytes or by `char`s.
+///
+/// Shrinks by removing characters and simplifying the remaining ones
+/// towards common ideographs.
+pub fn cjk_text(size: impl Into<SizeRange>) -> TextPresetStrategy {
+    let cjk = prop_oneof![
+        6 => char::range('\u{4E00}', '\u{9FFF}'),
+        2 => char::range('\u{3041}', '\u{3096}'),
+        2 => char::range('\u{30A1}', '\u{30FA}'),
+        2 => char::range('\u{AC00}', '\u{D7A3}'),
+        1 => char::range('\u{3000}', '\u{3003}'),
+        1 => char::range('\u{FF01}', '\u{FF5E}'),
+        1 => char::range(' ', '~'),
+        1 => char::range('\u{20000}', '\u{2A6DF}'),
+    ];
+
+    TextPresetStrategy(
+        vec(cjk, size)
+            .prop_map(|chars| chars.into_iter().collect())
+            .sboxed(),
+    )
+}
+
+/// Generates a Hebrew word.
+fn hebrew_word() -> impl Strategy<Value = String> {
+    vec(char::range('\u{5D0}', '\u{5EA}'), 1..8)
+        .prop_map(|chars| chars.into_iter().collect())
+}
+
+/// Generates an Arabic word, with some letters carrying a vowel mark.
+fn arabic_word() -> impl Strategy<Value = String> {
+    let letter = (
+        char::range('\u{628}', '\u{64A}'),
+        crate::option::weighted(0.25, char::range('\u{64B}', '\u{652}')),
+    );
+    vec(letter, 1..8).prop_map(|letters| {
+        letters
+            .into_iter()
+            .flat_map(|(letter, mark)| iter::once(letter).chain(mark))
+            .collect()
+    })
+}
+
+/// Generates a number in European or Arabic-Indic digits.
+fn rtl_number() -> impl Strategy<Value = String> {
+    prop_oneof![
+        vec(char::range('0', '9'), 1..6),
+        vec(char::range('\u{660}', '\u{669}'), 1..6),
+    ]
+    .prop_map(|digits| digits.into_iter().collect())
+}
+
+/// Creates a strategy which generates strings of a number of words within
+/// `size`, separated by spaces, in right-to-left scripts.
+///
+/// Words are in Hebrew or in Arabic, sometimes with vowel marks, and are
+/// mixed with numbers, which are laid out left-to-right even within
+/// right-to-left text, punctuation, and the invisible right-to-left, left-to-
+/// right and Arabic letter marks. Such text exercises bidirectional layout,
+/// cursor movement and truncation.
+///
+/// Shrinks by removing words and simplifying the remaining ones towards
+/// short Hebrew words.
+pub fn rtl_text(size: impl Into<SizeRange>) -> TextPresetStrategy {
+    let word = prop_oneof![
+        4 => hebrew_word(),
+        4 => arabic_word(),
+        1 => rtl_number(),
+        1 => prop_oneof![
+            Just("."), Just(","), Just("!"), Just("\u{60C}"), Just("\u{61F}"),
+        ].prop_map(String::from),
+        1 => prop_oneof![
+            Just("\u{200F}"), Just("\u{200E}"), Just("\u{61C}"),
+        ].prop_map(String::from),
+    ];
+
+    TextPresetStrategy(
+        vec(word, size).prop_map(|words| words.join(" ")).sboxed(),
+    )
+}
+
+/// Generates a person emoji, optionally with a skin tone modifier.
+fn person_emoji() -> impl Strategy<Value = String> {
+    (
+        prop_oneof![
+            Just('\u{1F9D1}'),
+            Just('\u{1F469}'),
+            Just('\u{1F468}'),
+            Just('\u{1F467}'),
+            Just('\u{1F466}'),
+        ],
+        crate::option::of(char::range('\u{1F3FB}', '\u{1F3FF}')),
+    )
+        .prop_map(|(person, tone)| iter::once(person).chain(tone).collect())
+}
+
+/// Creates a strategy which generates strings of a number of emoji within
+/// `size`.
+///
+/// Alongside single emoji, the strings contain emoji with skin tone
+/// modifiers, sequences of people and objects joined by zero-width joiners
+/// (such as families and professions), symbols with emoji presentation
+/// selectors, keycaps, and flags made of pairs of regional indicators. Each
+/// of these is a single user-perceived character made of several `char`s,
+/// which code that counts, reverses or truncates text by `char` breaks
+/// apart.
+///
+/// Shrinks by removing emoji and simplifying the remaining ones towards
+/// single smileys.
+pub fn emoji_text(size: impl Into<SizeRange>) -> TextPresetStrategy {
+    let zwj_sequence = prop_oneof![
+        // A family, or a couple with a heart between them.
+        vec(person_emoji(), 2..5).prop_map(|people| people.join("\u{200D}")),
+        (person_emoji(), person_emoji()).prop_map(|(a, b)| {
+            format!("{}\u{200D}\u{2764}\u{FE0F}\u{200D}{}", a, b)
+        }),
+        // A profession, such as a cook or a scientist.
+        (
+            person_emoji(),
+            prop_oneof![
+                Just('\u{1F373}'),
+                Just('\u{1F4BB}'),
+                Just('\u{1F52C}'),
+                Just('\u{1F692}'),
+                Just('\u{1F680}'),
+            ]
+        )
+            .prop_map(|(person, object)| {
+                format!("{}\u{200D}{}", person, object)
+            }),
+    ];
+    let emoji = prop_oneof![
+        3 => char::range('\u{1F600}', '\u{1F64F}').prop_map(String::from),
+        2 => char::range('\u{1F300}', '\u{1F5FF}').prop_map(String::from),
+        2 => person_emoji(),
+        2 => zwj_sequence,
+        1 => char::range('\u{2600}', '\u{26FF}')
+            .prop_map(|symbol| format!("{}\u{FE0F}", symbol)),
+        1 => prop_oneof![char::range('0', '9'), Just('#'), Just('*')]
+            .prop_map(|key| format!("{}\u{FE0F}\u{20E3}", key)),
+        1 => vec(char::range('\u{1F1E6}', '\u{1F1FF}'), 2)
+            .prop_map(|flag| flag.into_iter().collect()),
+    ];
+
+    TextPresetStrategy(
+        vec(emoji, size).prop_map(|emoji| emoji.concat()).sboxed(),
+    )
+}
+
+/// Creates a strategy which generates strings of a number of words within
+/// `size`, separated by spaces, which mix left-to-right and right-to-left
+/// scripts.
+///
+/// Latin, Hebrew and Arabic words and numbers are interleaved, and some are
+/// wrapped in directional isolates, embeddings or overrides. A few
+/// directional formatting characters are left unbalanced, as in text which
+/// has been truncated or spliced together. Such text is where bidirectional
+/// layout, line breaking and sanitisation of spoofed file names go wrong.
+///
+/// Shrinks by removing words and simplifying the remaining ones towards
+/// short Latin words.
+pub fn mixed_direction_text(size: impl Into<SizeRange>) -> TextPresetStrategy {
+    let plain = prop_oneof![
+        2 => vec(char::range('a', 'z'), 1..8)
+            .prop_map(|chars| chars.into_iter().collect::<String>()),
+        2 => hebrew_word(),
+        2 => arabic_word(),
+        1 => rtl_number(),
+    ];
+    // Isolates (LRI, RLI and FSI) are closed by PDI, and embeddings and
+    // overrides (LRE, RLE, LRO and RLO) by PDF.
+    let isolated = (char::range('\u{2066}', '\u{2068}'), plain.clone())
+        .prop_map(|(open, word)| format!("{}{}\u{2069}", open, word));
+    let embedded = (
+        prop_oneof![
+            Just('\u{202A}'),
+            Just('\u{202B}'),
+            Just('\u{202D}'),
+            Just('\u{202E}'),
+        ],
+        plain.clone(),
+    )
+        .prop_map(|(open, word)| format!("{}{}\u{202C}", open, word));
+    let unbalanced = prop_oneof![
+        char::range('\u{202A}', '\u{202E}'),
+        char::range('\u{2066}', '\u{2069}'),
+    ]
+    .prop_map(String::from);
+    let word = prop_oneof![
+        4 => plain,
+        1 => isolated,
+        1 => embedded,
+        1 => unbalanced,
+    ];
+
+    TextPresetStrategy(
+        vec(word, size).prop_map(|words| words.join(" ")).sboxed(),
+    )
+}
+
 type ParseResult<T> = Result<RegexGeneratorStrategy<T>, Error>;
 
 #[doc(hidden)]
@@ -138,19 +749,67 @@ impl StrategyFromRegex for Vec<u8> {
     }
 }
 
+/// How the number of repetitions of a quantified expression is distributed
+/// between its bounds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RepetitionDistribution {
+    /// Every number of repetitions within the bounds is equally likely.
+    #[default]
+    Uniform,
+    /// Fewer repetitions are more likely. The ranges from the minimum to the
+    /// maximum, to halfway to the maximum, to a quarter of the way and so on
+    /// are all equally likely to be picked, and the number of repetitions is
+    /// then chosen uniformly from the picked range.
+    PreferShort,
+}
+
+/// Configuration for how the regex strategies expand repetitions such as
+/// `*`, `+` and `{n,m}`.
+///
+/// The default matches `string_regex()` and `bytes_regex()`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RegexGeneratorConfig {
+    /// The maximum number of times any repetition is repeated, unless its
+    /// minimum is higher, in which case it is repeated exactly that many
+    /// times. Unbounded repetitions are repeated up to this many times.
+    ///
+    /// If `None`, bounded repetitions use their own maximum, `*` and `+` are
+    /// repeated up to 32 times, and `{n,}` up to `2n - 1` times.
+    pub max_repeats: Option<u32>,
+    /// How the number of repetitions is distributed within the bounds.
+    pub distribution: RepetitionDistribution,
+}
+
 /// Creates a strategy which generates strings matching the given regular
 /// expression.
 ///
 /// If you don't need error handling and aren't limited by setup time, it is
 /// also possible to directly use a `&str` as a strategy with the same effect.
 pub fn string_regex(regex: &str) -> ParseResult<String> {
+    string_regex_with_config(regex, &RegexGeneratorConfig::default())
+}
+
+/// Like `string_regex()`, but expands repetitions according to `config`.
+pub fn string_regex_with_config(
+    regex: &str,
+    config: &RegexGeneratorConfig,
+) -> ParseResult<String> {
     let hir = ParserBuilder::new().build().parse(regex)?;
-    string_regex_parsed(&hir)
+    string_regex_parsed_with_config(&hir, config)
 }
 
 /// Like `string_regex()`, but allows providing a pre-parsed expression.
 pub fn string_regex_parsed(expr: &Hir) -> ParseResult<String> {
-    bytes_regex_parsed(expr)
+    string_regex_parsed_with_config(expr, &RegexGeneratorConfig::default())
+}
+
+/// Like `string_regex_parsed()`, but expands repetitions according to
+/// `config`.
+pub fn string_regex_parsed_with_config(
+    expr: &Hir,
+    config: &RegexGeneratorConfig,
+) -> ParseResult<String> {
+    bytes_regex_parsed_with_config(expr, config)
         .map(|v| {
             v.prop_map(|bytes| {
                 String::from_utf8(bytes).expect("non-utf8 string")
@@ -171,15 +830,33 @@ pub fn string_regex_parsed(expr: &Hir) -> ParseResult<String> {
 /// [`regex` crate's documentation](https://docs.rs/regex/*/regex/#opt-out-of-unicode-support)
 /// for more information.
 pub fn bytes_regex(regex: &str) -> ParseResult<Vec<u8>> {
+    bytes_regex_with_config(regex, &RegexGeneratorConfig::default())
+}
+
+/// Like `bytes_regex()`, but expands repetitions according to `config`.
+pub fn bytes_regex_with_config(
+    regex: &str,
+    config: &RegexGeneratorConfig,
+) -> ParseResult<Vec<u8>> {
     let hir = ParserBuilder::new()
         .utf8(false)
         .build()
         .parse(regex)?;
-    bytes_regex_parsed(&hir)
+    bytes_regex_parsed_with_config(&hir, config)
 }
 
 /// Like `bytes_regex()`, but allows providing a pre-parsed expression.
 pub fn bytes_regex_parsed(expr: &Hir) -> ParseResult<Vec<u8>> {
+    bytes_regex_parsed_with_config(expr, &RegexGeneratorConfig::default())
+}
+
+/// Like `bytes_regex_parsed()`, but expands repetitions according to
+/// `config`.
+pub fn bytes_regex_parsed_with_config(
+    expr: &Hir,
+    config: &RegexGeneratorConfig,
+) -> ParseResult<Vec<u8>> {
+    let recurse = |expr| bytes_regex_parsed_with_config(expr, config);
     match expr.kind() {
         Empty => Ok(Just(vec![]).sboxed()),
 
@@ -195,19 +872,20 @@ pub fn bytes_regex_parsed(expr: &Hir) -> ParseResult<Vec<u8>> {
             }
         }),
 
-        Repetition(rep) => {
-            Ok(vec(bytes_regex_parsed(&rep.sub)?, to_range(rep)?)
-                .prop_map(|parts| parts.concat())
-                .sboxed())
-        }
+        Repetition(rep) => Ok(repetition_strategy(
+            recurse(&rep.sub)?.0,
+            to_range(rep, config)?,
+            config.distribution,
+        )),
 
-        Capture(capture) => bytes_regex_parsed(&capture.sub).map(|v| v.0),
+        Capture(capture) => recurse(&capture.sub).map(|v| v.0),
 
         Concat(subs) => {
             let subs = ConcatIter {
                 iter: subs.iter(),
                 buf: vec![],
                 next: None,
+                config,
             };
             let ext = |(mut lhs, rhs): (Vec<_>, _)| {
                 lhs.extend(rhs);
@@ -226,7 +904,7 @@ pub fn bytes_regex_parsed(expr: &Hir) -> ParseResult<Vec<u8>> {
         }
 
         Alternation(subs) => {
-            Ok(Union::try_new(subs.iter().map(bytes_regex_parsed))?.sboxed())
+            Ok(Union::try_new(subs.iter().map(recurse))?.sboxed())
         }
 
         Look(_) => unsupported(
@@ -268,6 +946,7 @@ struct ConcatIter<'a, I> {
     buf: Vec<u8>,
     iter: I,
     next: Option<&'a Hir>,
+    config: &'a RegexGeneratorConfig,
 }
 
 fn flush_lit_buf<I>(
@@ -284,7 +963,7 @@ impl<'a, I: Iterator<Item = &'a Hir>> Iterator for ConcatIter<'a, I> {
     fn next(&mut self) -> Option<Self::Item> {
         // A left-over node, process it first:
         if let Some(next) = self.next.take() {
-            return Some(bytes_regex_parsed(next));
+            return Some(bytes_regex_parsed_with_config(next, self.config));
         }
 
         // Accumulate a literal sequence as long as we can:
@@ -301,7 +980,7 @@ impl<'a, I: Iterator<Item = &'a Hir>> Iterator for ConcatIter<'a, I> {
                         flush_lit_buf(self)
                     } else {
                         // We didn't; just yield this node.
-                        Some(bytes_regex_parsed(next))
+                        Some(bytes_regex_parsed_with_config(next, self.config))
                     };
                 }
             }
@@ -311,12 +990,55 @@ impl<'a, I: Iterator<Item = &'a Hir>> Iterator for ConcatIter<'a, I> {
         if !self.buf.is_empty() {
             flush_lit_buf(self)
         } else {
-            self.next.take().map(bytes_regex_parsed)
+            let config = self.config;
+            self.next
+                .take()
+                .map(|next| bytes_regex_parsed_with_config(next, config))
         }
     }
 }
 
-fn to_range(rep: &Repetition) -> Result<SizeRange, Error> {
+fn repetition_strategy(
+    sub: SBoxedStrategy<Vec<u8>>,
+    size: SizeRange,
+    distribution: RepetitionDistribution,
+) -> SBoxedStrategy<Vec<u8>> {
+    let parts = match distribution {
+        RepetitionDistribution::Uniform => vec(sub, size).sboxed(),
+        RepetitionDistribution::PreferShort => {
+            let (min, max) = size.start_end_incl();
+            // Shortest first, so that shrinking moves towards fewer
+            // repetitions.
+            let mut spans = vec![];
+            let mut span = max - min;
+            while span > 0 {
+                spans.push(span);
+                span /= 2;
+            }
+            spans.push(0);
+            Union::new(
+                spans
+                    .into_iter()
+                    .rev()
+                    .map(|span| vec(sub.clone(), min..=min + span)),
+            )
+            .sboxed()
+        }
+    };
+    parts.prop_map(|parts| parts.concat()).sboxed()
+}
+
+fn to_range(
+    rep: &Repetition,
+    config: &RegexGeneratorConfig,
+) -> Result<SizeRange, Error> {
+    if let Some(max_repeats) = config.max_repeats {
+        let min = rep.min as usize;
+        let max =
+            rep.max.map_or(max_repeats, |max| max.min(max_repeats)) as usize;
+        return Ok(size_range(min..=max.max(min)));
+    }
+
     Ok(match (rep.min, rep.max) {
         // Zero or one
         (0, Some(1)) => size_range(0..=1),
@@ -590,6 +1312,79 @@ mod test {
         | \xb0\x80 | \xbe\x80 | \xbf\xbf )"#, 15, 15, 120);
     }
 
+    fn repetition_counts(
+        pattern: &str,
+        config: &RegexGeneratorConfig,
+    ) -> Vec<usize> {
+        let strategy = string_regex_with_config(pattern, config).unwrap();
+        let mut runner = TestRunner::deterministic();
+        (0..1024)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current().len())
+            .collect()
+    }
+
+    #[test]
+    fn max_repeats_bounds_repetitions() {
+        let config = RegexGeneratorConfig {
+            max_repeats: Some(100),
+            ..RegexGeneratorConfig::default()
+        };
+        let star = repetition_counts("a*", &config);
+        assert!(star.iter().all(|&n| n <= 100));
+        assert!(star.iter().any(|&n| n > 32));
+
+        let bounded = repetition_counts("a{0,1000}", &config);
+        assert!(bounded.iter().all(|&n| n <= 100));
+
+        let at_least = repetition_counts("a{200,}", &config);
+        assert!(at_least.iter().all(|&n| n == 200));
+    }
+
+    #[test]
+    fn prefer_short_distribution_favours_few_repetitions() {
+        let uniform = RegexGeneratorConfig {
+            max_repeats: Some(1000),
+            ..RegexGeneratorConfig::default()
+        };
+        let short = RegexGeneratorConfig {
+            distribution: RepetitionDistribution::PreferShort,
+            ..uniform
+        };
+        let count_short =
+            |counts: Vec<usize>| counts.into_iter().filter(|&n| n < 10).count();
+
+        let short_counts = repetition_counts("a+", &short);
+        assert!(short_counts.iter().all(|&n| (1..=1000).contains(&n)));
+        assert!(short_counts.iter().any(|&n| n > 500));
+        assert!(
+            count_short(short_counts)
+                > 4 * count_short(repetition_counts("a+", &uniform)) + 100
+        );
+    }
+
+    #[test]
+    fn prefer_short_distribution_generates_matching_strings() {
+        let config = RegexGeneratorConfig {
+            distribution: RepetitionDistribution::PreferShort,
+            ..RegexGeneratorConfig::default()
+        };
+        let rx = Regex::new("^(ab|c{2,5})*d{3}$").unwrap();
+        let strategy =
+            string_regex_with_config("(ab|c{2,5})*d{3}", &config).unwrap();
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..256 {
+            let mut value = strategy.new_tree(&mut runner).unwrap();
+            loop {
+                let s = value.current();
+                assert!(rx.is_match(&s), "{:?} does not match", s);
+                if !value.simplify() {
+                    break;
+                }
+            }
+            assert_eq!("ddd", value.current());
+        }
+    }
+
     fn assert_send_and_sync<T: Send + Sync>(_: T) {}
 
     #[test]
@@ -597,6 +1392,195 @@ mod test {
         assert_send_and_sync(string_regex(".").unwrap());
     }
 
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn graphemes_generates_requested_cluster_count() {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let mut runner = TestRunner::deterministic();
+        let strategy = graphemes(3..8);
+        let mut saw_multi_char_cluster = false;
+        for _ in 0..512 {
+            let mut tree = strategy.new_tree(&mut runner).unwrap();
+            loop {
+                let s = tree.current();
+                let count = s.graphemes(true).count();
+                assert!(
+                    (3..8).contains(&count),
+                    "{:?} has {} grapheme clusters",
+                    s,
+                    count
+                );
+                saw_multi_char_cluster |= count < s.chars().count();
+
+                if !tree.simplify() {
+                    break;
+                }
+            }
+        }
+        assert!(saw_multi_char_cluster);
+    }
+
+    #[test]
+    fn markov_follows_corpus_transitions() {
+        const CORPUS: &str = "abracadabra, cabbage, abacus";
+        let cyclic = CORPUS.repeat(2);
+
+        let mut runner = TestRunner::deterministic();
+        for order in 0..4 {
+            let strategy = markov(CORPUS, order, 5..40);
+            for _ in 0..64 {
+                let text = strategy
+                    .new_tree(&mut runner)
+                    .unwrap()
+                    .current()
+                    .chars()
+                    .collect::<Vec<_>>();
+                assert!((5..40).contains(&text.len()));
+                for window in text.windows(order + 1) {
+                    let window = window.iter().collect::<String>();
+                    assert!(cyclic.contains(&window), "{:?}", window);
+                }
+            }
+
+            let minimal = strategy.minimal(&mut runner);
+            assert_eq!(5, minimal.chars().count(), "{:?}", minimal);
+            assert!(CORPUS.starts_with(&minimal[..order]), "{:?}", minimal);
+        }
+
+        // `b` is the most frequent character after `a`, and `a` is as
+        // frequent as `r` after `b`, with ties going to the lesser character.
+        assert_eq!("ababa", markov(CORPUS, 1, 5).minimal(&mut runner));
+    }
+
+    #[test]
+    fn markov_sanity() {
+        check_strategy_sanity(markov("hello, world", 2, 0..4), None);
+    }
+
+    #[test]
+    fn cjk_text_is_mostly_wide_characters() {
+        let mut runner = TestRunner::deterministic();
+        let strategy = cjk_text(10..20);
+        let (mut ascii, mut astral, mut total) = (0, 0, 0);
+        for _ in 0..256 {
+            let s = strategy.new_tree(&mut runner).unwrap().current();
+            assert!((10..20).contains(&s.chars().count()), "{:?}", s);
+            for c in s.chars() {
+                ascii += c.is_ascii() as u32;
+                astral += (c.len_utf16() == 2) as u32;
+                total += 1;
+            }
+        }
+        assert!(ascii * 8 < total, "{} of {}", ascii, total);
+        assert!(astral > 0);
+        assert_eq!("\u{4E00}".repeat(10), strategy.minimal(&mut runner));
+    }
+
+    #[test]
+    fn rtl_text_is_mostly_right_to_left() {
+        let mut runner = TestRunner::deterministic();
+        let strategy = rtl_text(1..10);
+        let mut saw_mark = false;
+        for _ in 0..256 {
+            let s = strategy.new_tree(&mut runner).unwrap().current();
+            assert!((1..10).contains(&s.split(' ').count()), "{:?}", s);
+            assert!(!s.chars().any(|c| c.is_ascii_alphabetic()), "{:?}", s);
+            saw_mark |= s.contains('\u{64E}') || s.contains('\u{200F}');
+        }
+        assert!(saw_mark);
+    }
+
+    #[test]
+    fn emoji_text_has_multi_char_emoji() {
+        let mut runner = TestRunner::deterministic();
+        let strategy = emoji_text(1..5);
+        let (mut saw_zwj, mut saw_tone, mut saw_flag) = (false, false, false);
+        for _ in 0..256 {
+            let s = strategy.new_tree(&mut runner).unwrap().current();
+            assert!(!s.is_empty() && !s.is_ascii(), "{:?}", s);
+            saw_zwj |= s.contains('\u{200D}');
+            saw_tone |=
+                s.chars().any(|c| ('\u{1F3FB}'..='\u{1F3FF}').contains(&c));
+            saw_flag |=
+                s.chars().any(|c| ('\u{1F1E6}'..='\u{1F1FF}').contains(&c));
+        }
+        assert!(saw_zwj && saw_tone && saw_flag);
+        assert_eq!("\u{1F600}", strategy.minimal(&mut runner));
+    }
+
+    #[test]
+    fn mixed_direction_text_mixes_directions() {
+        let mut runner = TestRunner::deterministic();
+        let strategy = mixed_direction_text(5..10);
+        let (mut saw_mixed, mut saw_isolate) = (false, false);
+        for _ in 0..256 {
+            let s = strategy.new_tree(&mut runner).unwrap().current();
+            saw_mixed |= s.chars().any(|c| c.is_ascii_alphabetic())
+                && s.chars().any(|c| ('\u{5D0}'..='\u{64A}').contains(&c));
+            saw_isolate |= s.contains('\u{2069}');
+        }
+        assert!(saw_mixed && saw_isolate);
+        assert_eq!("a a a a a", strategy.minimal(&mut runner));
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn normalized_generates_strings_in_form() {
+        use unicode_normalization::{is_nfc, is_nfd, is_nfkc, is_nfkd};
+
+        let mut runner = TestRunner::deterministic();
+        let checks: [(NormalizationForm, fn(&str) -> bool); 4] = [
+            (NormalizationForm::Nfc, is_nfc),
+            (NormalizationForm::Nfd, is_nfd),
+            (NormalizationForm::Nfkc, is_nfkc),
+            (NormalizationForm::Nfkd, is_nfkd),
+        ];
+        for &(form, check) in checks.iter() {
+            let strategy = normalized("[\\u{C0}-\\u{17F}\\PC]{0,16}", form);
+            for _ in 0..256 {
+                let s = strategy.new_tree(&mut runner).unwrap().current();
+                assert!(check(&s), "{:?} is not in {:?}", s, form);
+            }
+        }
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn normalized_sanity() {
+        check_strategy_sanity(
+            normalized(
+                vec(char::any(), 0..8)
+                    .prop_map(|chars| chars.into_iter().collect::<String>()),
+                NormalizationForm::Nfd,
+            ),
+            None,
+        );
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn canonically_equivalent_generates_differing_pairs() {
+        use unicode_normalization::{is_nfc, is_nfd};
+
+        let mut runner = TestRunner::deterministic();
+        let strategy = canonically_equivalent(0..8);
+        for _ in 0..256 {
+            let mut tree = strategy.new_tree(&mut runner).unwrap();
+            loop {
+                let (nfc, nfd) = tree.current();
+                assert_ne!(nfc, nfd);
+                assert!(is_nfc(&nfc), "{:?} is not in NFC", nfc);
+                assert!(is_nfd(&nfd), "{:?} is not in NFD", nfd);
+                assert_eq!(NormalizationForm::Nfd.normalize(&nfc), nfd);
+
+                if !tree.simplify() {
+                    break;
+                }
+            }
+        }
+    }
+
     macro_rules! consistent {
         ($name:ident, $value:expr) => {
             #[test]
@@ -8,6 +8,11 @@
 // except according to those terms.
 
 use crate::std_facade::fmt;
+use crate::strategy::Strategy;
+use crate::test_runner::{
+    TestCaseError, TestCaseResult, TestError, TestRunner,
+};
+use core::any::Any;
 
 /// Easily define `proptest` tests.
 ///
@@ -77,6 +82,9 @@ use crate::std_facade::fmt;
 /// To override the default configuration, you can start the `proptest!` block
 /// with `#![proptest_config(expr)]`, where `expr` is an expression that
 /// evaluates to a `proptest::test_runner::Config` (or a reference to one).
+/// A test which needs different settings, such as more cases or a timeout,
+/// can instead start its body with `#![proptest_config(expr)]`, which
+/// replaces the configuration of the block for that test alone.
 ///
 /// ```
 /// use proptest::prelude::*;
@@ -91,9 +99,70 @@ use crate::std_facade::fmt;
 ///   fn test_addition(a in 0..10, b in 0..10) {
 ///     prop_assert!(a + b <= 18);
 ///   }
+///
+///   # /*
+///   #[test]
+///   # */
+///   fn test_multiplication(a in 0..10, b in 0..10) {
+///     #![proptest_config(ProptestConfig::with_cases(1000))]
+///     prop_assert!(a * b <= 81);
+///   }
 /// }
 /// #
-/// # fn main() { test_addition(); }
+/// # fn main() { test_addition(); test_multiplication(); }
+/// ```
+///
+/// A test may also be declared to return `Result<(), E>` for any `E:
+/// Debug`, which allows using `?` on fallible operations within the body. An
+/// `Err` fails the test case with the error's `Debug` output as the reason
+/// and is shrunk like any other failure. Since `prop_assert!` and related
+/// macros return `TestCaseError`, they can only be used when the return type
+/// is `TestCaseResult`; errors of that type are passed through unchanged.
+///
+/// ```
+/// use proptest::prelude::*;
+/// use std::num::ParseIntError;
+///
+/// proptest! {
+///   # /*
+///   #[test]
+///   # */
+///   fn parse_roundtrip(x: u32) -> Result<(), ParseIntError> {
+///     let parsed: u32 = x.to_string().parse()?;
+///     assert_eq!(x, parsed);
+///     Ok(())
+///   }
+/// }
+/// #
+/// # fn main() { parse_roundtrip(); }
+/// ```
+///
+/// Tests may also be declared as `async fn`, in which case each test case is
+/// run to completion on the executor returned by
+/// `test_runner::default_executor()`. By default this is a minimal executor
+/// which does not provide any async runtime; enable the `tokio` or
+/// `async-std` feature to run the tests on that runtime instead. Async tests
+/// must be declared in a separate `proptest!` block from non-async tests, and
+/// do not currently support return types. To use some other executor, call
+/// `TestRunner::run_async` directly.
+///
+/// ```
+/// use proptest::prelude::*;
+///
+/// async fn double(x: u32) -> u32 {
+///   x * 2
+/// }
+///
+/// proptest! {
+///   # /*
+///   #[test]
+///   # */
+///   async fn double_is_even(x in 0u32..1000) {
+///     prop_assert_eq!(0, double(x).await % 2);
+///   }
+/// }
+/// #
+/// # fn main() { double_is_even(); }
 /// ```
 ///
 /// ## Closure-Style Invocation
@@ -105,7 +174,8 @@ use crate::std_facade::fmt;
 /// features are _not_ supported in closure style.
 ///
 /// To use a custom configuration, pass the `Config` object as a first
-/// argument.
+/// argument. As with the function form, the closure may declare a
+/// `Result<(), E>` return type, as in `|(x: u32)| -> Result<(), E> { .. }`.
 ///
 /// ### Example
 ///
@@ -152,7 +222,25 @@ macro_rules! proptest {
     (#![proptest_config($config:expr)]
      $(
         $(#[$meta:meta])*
-       fn $test_name:ident($($parm:pat in $strategy:expr),+ $(,)?) $body:block
+       fn $test_name:ident($($parm:pat in $strategy:expr),+ $(,)?)
+           $(-> $ret:ty)? { $($body:tt)* }
+    )*) => {
+        $(
+            $(#[$meta])*
+            fn $test_name() {
+                let mut config = $crate::test_runner::contextualize_config($config.clone());
+                config.test_name = Some(
+                    concat!(module_path!(), "::", stringify!($test_name)));
+                $crate::proptest_helper!(@_FN_CONFIG config
+                    (@_BODY [par] config ($($parm in $strategy),+) [] [$($ret)?])
+                    { $($body)* });
+            }
+        )*
+    };
+    (#![proptest_config($config:expr)]
+     $(
+        $(#[$meta:meta])*
+        fn $test_name:ident($($arg:tt)+) $(-> $ret:ty)? { $($body:tt)* }
     )*) => {
         $(
             $(#[$meta])*
@@ -160,14 +248,34 @@ macro_rules! proptest {
                 let mut config = $crate::test_runner::contextualize_config($config.clone());
                 config.test_name = Some(
                     concat!(module_path!(), "::", stringify!($test_name)));
-                $crate::proptest_helper!(@_BODY config ($($parm in $strategy),+) [] $body);
+                $crate::proptest_helper!(@_FN_CONFIG config
+                    (@_BODY2 [par] config ($($arg)+) [] [$($ret)?])
+                    { $($body)* });
+            }
+        )*
+    };
+
+    (#![proptest_config($config:expr)]
+     $(
+        $(#[$meta:meta])*
+        async fn $test_name:ident($($parm:pat in $strategy:expr),+ $(,)?) { $($body:tt)* }
+    )*) => {
+        $(
+            $(#[$meta])*
+            fn $test_name() {
+                let mut config = $crate::test_runner::contextualize_config($config.clone());
+                config.test_name = Some(
+                    concat!(module_path!(), "::", stringify!($test_name)));
+                $crate::proptest_helper!(@_FN_CONFIG config
+                    (@_ASYNC_BODY config ($($parm in $strategy),+))
+                    { $($body)* });
             }
         )*
     };
     (#![proptest_config($config:expr)]
      $(
         $(#[$meta:meta])*
-        fn $test_name:ident($($arg:tt)+) $body:block
+        async fn $test_name:ident($($arg:tt)+) { $($body:tt)* }
     )*) => {
         $(
             $(#[$meta])*
@@ -175,29 +283,74 @@ macro_rules! proptest {
                 let mut config = $crate::test_runner::contextualize_config($config.clone());
                 config.test_name = Some(
                     concat!(module_path!(), "::", stringify!($test_name)));
-                $crate::proptest_helper!(@_BODY2 config ($($arg)+) [] $body);
+                $crate::proptest_helper!(@_FN_CONFIG config
+                    (@_ASYNC_BODY2 config ($($arg)+))
+                    { $($body)* });
             }
         )*
     };
 
     ($(
         $(#[$meta:meta])*
-        fn $test_name:ident($($parm:pat in $strategy:expr),+ $(,)?) $body:block
+        fn $test_name:ident($($parm:pat in $strategy:expr),+ $(,)?)
+            $(-> $ret:ty)? { $($body:tt)* }
+    )*) => { $crate::proptest! {
+        #![proptest_config($crate::test_runner::Config::default())]
+        $($(#[$meta])*
+          fn $test_name($($parm in $strategy),+) $(-> $ret)? { $($body)* })*
+    } };
+
+    ($(
+        $(#[$meta:meta])*
+        fn $test_name:ident($($arg:tt)+) $(-> $ret:ty)? { $($body:tt)* }
     )*) => { $crate::proptest! {
         #![proptest_config($crate::test_runner::Config::default())]
         $($(#[$meta])*
-          fn $test_name($($parm in $strategy),+) $body)*
+          fn $test_name($($arg)+) $(-> $ret)? { $($body)* })*
     } };
 
     ($(
         $(#[$meta:meta])*
-        fn $test_name:ident($($arg:tt)+) $body:block
+        async fn $test_name:ident($($parm:pat in $strategy:expr),+ $(,)?) { $($body:tt)* }
     )*) => { $crate::proptest! {
         #![proptest_config($crate::test_runner::Config::default())]
         $($(#[$meta])*
-          fn $test_name($($arg)+) $body)*
+          async fn $test_name($($parm in $strategy),+) { $($body)* })*
     } };
 
+    ($(
+        $(#[$meta:meta])*
+        async fn $test_name:ident($($arg:tt)+) { $($body:tt)* }
+    )*) => { $crate::proptest! {
+        #![proptest_config($crate::test_runner::Config::default())]
+        $($(#[$meta])*
+          async fn $test_name($($arg)+) { $($body)* })*
+    } };
+
+    (|($($parm:pat in $strategy:expr),+ $(,)?)| -> $ret:ty $body:block) => {
+        $crate::proptest!(
+            $crate::test_runner::Config::default(),
+            |($($parm in $strategy),+)| -> $ret $body)
+    };
+
+    (move |($($parm:pat in $strategy:expr),+ $(,)?)| -> $ret:ty $body:block) => {
+        $crate::proptest!(
+            $crate::test_runner::Config::default(),
+            move |($($parm in $strategy),+)| -> $ret $body)
+    };
+
+    (|($($arg:tt)+)| -> $ret:ty $body:block) => {
+        $crate::proptest!(
+            $crate::test_runner::Config::default(),
+            |($($arg)+)| -> $ret $body)
+    };
+
+    (move |($($arg:tt)+)| -> $ret:ty $body:block) => {
+        $crate::proptest!(
+            $crate::test_runner::Config::default(),
+            move |($($arg)+)| -> $ret $body)
+    };
+
     (|($($parm:pat in $strategy:expr),+ $(,)?)| $body:expr) => {
         $crate::proptest!(
             $crate::test_runner::Config::default(),
@@ -222,28 +375,52 @@ macro_rules! proptest {
             move |($($arg)+)| $body)
     };
 
+    ($config:expr, |($($parm:pat in $strategy:expr),+ $(,)?)| -> $ret:ty $body:block) => { {
+        let mut config = $crate::test_runner::contextualize_config($config.__sugar_to_owned());
+        $crate::sugar::force_no_fork(&mut config);
+        $crate::proptest_helper!(@_BODY [ser] config ($($parm in $strategy),+) [] [$ret] $body)
+    } };
+
+    ($config:expr, move |($($parm:pat in $strategy:expr),+ $(,)?)| -> $ret:ty $body:block) => { {
+        let mut config = $crate::test_runner::contextualize_config($config.__sugar_to_owned());
+        $crate::sugar::force_no_fork(&mut config);
+        $crate::proptest_helper!(@_BODY [ser] config ($($parm in $strategy),+) [move] [$ret] $body)
+    } };
+
+    ($config:expr, |($($arg:tt)+)| -> $ret:ty $body:block) => { {
+        let mut config = $crate::test_runner::contextualize_config($config.__sugar_to_owned());
+        $crate::sugar::force_no_fork(&mut config);
+        $crate::proptest_helper!(@_BODY2 [ser] config ($($arg)+) [] [$ret] $body);
+    } };
+
+    ($config:expr, move |($($arg:tt)+)| -> $ret:ty $body:block) => { {
+        let mut config = $crate::test_runner::contextualize_config($config.__sugar_to_owned());
+        $crate::sugar::force_no_fork(&mut config);
+        $crate::proptest_helper!(@_BODY2 [ser] config ($($arg)+) [move] [$ret] $body);
+    } };
+
     ($config:expr, |($($parm:pat in $strategy:expr),+ $(,)?)| $body:expr) => { {
         let mut config = $crate::test_runner::contextualize_config($config.__sugar_to_owned());
         $crate::sugar::force_no_fork(&mut config);
-        $crate::proptest_helper!(@_BODY config ($($parm in $strategy),+) [] $body)
+        $crate::proptest_helper!(@_BODY [ser] config ($($parm in $strategy),+) [] [] $body)
     } };
 
     ($config:expr, move |($($parm:pat in $strategy:expr),+ $(,)?)| $body:expr) => { {
         let mut config = $crate::test_runner::contextualize_config($config.__sugar_to_owned());
         $crate::sugar::force_no_fork(&mut config);
-        $crate::proptest_helper!(@_BODY config ($($parm in $strategy),+) [move] $body)
+        $crate::proptest_helper!(@_BODY [ser] config ($($parm in $strategy),+) [move] [] $body)
     } };
 
     ($config:expr, |($($arg:tt)+)| $body:expr) => { {
         let mut config = $crate::test_runner::contextualize_config($config.__sugar_to_owned());
         $crate::sugar::force_no_fork(&mut config);
-        $crate::proptest_helper!(@_BODY2 config ($($arg)+) [] $body);
+        $crate::proptest_helper!(@_BODY2 [ser] config ($($arg)+) [] [] $body);
     } };
 
     ($config:expr, move |($($arg:tt)+)| $body:expr) => { {
         let mut config = $crate::test_runner::contextualize_config($config.__sugar_to_owned());
         $crate::sugar::force_no_fork(&mut config);
-        $crate::proptest_helper!(@_BODY2 config ($($arg)+) [move] $body);
+        $crate::proptest_helper!(@_BODY2 [ser] config ($($arg)+) [move] [] $body);
     } };
 }
 
@@ -274,6 +451,128 @@ macro_rules! prop_assume {
     };
 }
 
+/// Reports a numeric "utility" for the current test input.
+///
+/// Used directly within a function defined with `proptest!` or in any code
+/// called from the test body on the same thread.
+///
+/// When the test is run with `Config::targeted` set, proptest searches for
+/// inputs which maximise the reported utility instead of generating every
+/// input independently. This is useful for steering generation towards
+/// inputs which are "close" to triggering a bug, such as ones which make a
+/// queue nearly full or a computation nearly overflow.
+///
+/// The argument is converted to `f64` with `as`. If invoked more than once in
+/// the same test case, the last value wins. Without `Config::targeted`, this
+/// has no effect.
+///
+/// ## Example
+///
+/// ```
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///   #![proptest_config(ProptestConfig {
+///     targeted: true, .. ProptestConfig::default()
+///   })]
+///   # /*
+///   #[test]
+///   # */
+///   fn sum_stays_small(v in prop::collection::vec(0u8..=255, 8)) {
+///     let sum: u32 = v.iter().map(|&b| u32::from(b)).sum();
+///     prop_target!(sum);
+///     prop_assert!(sum <= 8 * 255);
+///   }
+/// }
+/// #
+/// # fn main() { sum_stays_small(); }
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! prop_target {
+    ($utility:expr) => {
+        $crate::test_runner::target($utility as f64)
+    };
+}
+
+/// Labels the current test case if a condition holds.
+///
+/// Used directly within a function defined with `proptest!` or in any code
+/// called from the test body on the same thread.
+///
+/// The label is any expression which dereferences to `str`. After all test
+/// cases pass, proptest reports the fraction of passing test cases which
+/// carried each label, which shows whether the strategy actually generates
+/// the inputs the test is meant to check. The counts are also available from
+/// `TestRunner::labels()`.
+///
+/// See also `prop_collect!`, which labels each test case with a value.
+///
+/// ## Example
+///
+/// ```
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///   # /*
+///   #[test]
+///   # */
+///   fn reverse_twice(v in prop::collection::vec(0u8..10, 0..10)) {
+///     prop_classify!(v.is_empty(), "empty");
+///     prop_classify!(v.len() > 5, "long");
+///     let mut w = v.clone();
+///     w.reverse();
+///     w.reverse();
+///     prop_assert_eq!(v, w);
+///   }
+/// }
+/// #
+/// # fn main() { reverse_twice(); }
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! prop_classify {
+    ($cond:expr, $label:expr) => {
+        if $cond {
+            $crate::test_runner::classify(&$label)
+        }
+    };
+}
+
+/// Labels the current test case with the `Debug` representation of a value.
+///
+/// This is shorthand for `prop_classify!(true, format!("{:?}", value))`,
+/// useful for seeing the distribution of some small property of the inputs,
+/// such as their length.
+///
+/// ## Example
+///
+/// ```
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///   # /*
+///   #[test]
+///   # */
+///   fn sort_is_idempotent(mut v in prop::collection::vec(0u8..10, 0..4)) {
+///     prop_collect!(v.len());
+///     v.sort();
+///     let sorted = v.clone();
+///     v.sort();
+///     prop_assert_eq!(sorted, v);
+///   }
+/// }
+/// #
+/// # fn main() { sort_is_idempotent(); }
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! prop_collect {
+    ($value:expr) => {
+        $crate::test_runner::classify(&format!("{:?}", $value))
+    };
+}
+
 /// Produce a strategy which picks one of the listed choices.
 ///
 /// This is conceptually equivalent to calling `prop_union` on the first two
@@ -700,6 +999,78 @@ macro_rules! prop_compose {
     };
 }
 
+/// Implements `Arbitrary` for transparent newtypes by delegating to the
+/// wrapped type.
+///
+/// The generated impl has the same `Parameters` as the wrapped type, and
+/// values are generated and shrunk exactly as the wrapped values are. This
+/// is how proptest itself implements `Arbitrary` for `Wrapping<T>`.
+///
+/// The simplest form takes one or more tuple structs with a single field,
+/// written as `Name(Inner)`. For generic newtypes, or ones which are built
+/// by something other than their tuple constructor, give any generic bounds
+/// in brackets, then the type, the wrapped type and a function or
+/// non-capturing closure from the wrapped type to the newtype.
+///
+/// ## Example
+///
+/// ```
+/// use proptest::prelude::*;
+/// use proptest::prop_arbitrary_newtype;
+///
+/// #[derive(Clone, Copy, Debug)]
+/// struct Meters(u32);
+/// #[derive(Clone, Copy, Debug)]
+/// struct Seconds(u64);
+///
+/// prop_arbitrary_newtype!(Meters(u32), Seconds(u64));
+///
+/// #[derive(Clone, Copy, Debug)]
+/// struct Checked<T>(T);
+///
+/// impl<T> Checked<T> {
+///     fn new(value: T) -> Self {
+///         Checked(value)
+///     }
+/// }
+///
+/// prop_arbitrary_newtype!(
+///     [T: Arbitrary] Checked<T>, T => Checked::new
+/// );
+///
+/// proptest! {
+///   # /*
+///   #[test]
+///   # */
+///   fn distance(m: Meters, s in any_with::<Seconds>(())) {
+///     prop_assert!(u64::from(m.0) + s.0 >= s.0);
+///   }
+/// }
+/// #
+/// # fn main() { distance(); let _ = any::<Checked<i8>>(); }
+/// ```
+#[macro_export]
+macro_rules! prop_arbitrary_newtype {
+    ($($name:ident($inner:ty)),+ $(,)?) => {
+        $($crate::prop_arbitrary_newtype!([] $name, $inner => $name);)+
+    };
+
+    ([$($bounds:tt)*] $typ:ty, $inner:ty => $ctor:expr) => {
+        impl<$($bounds)*> $crate::arbitrary::Arbitrary for $typ {
+            type Parameters =
+                <$inner as $crate::arbitrary::Arbitrary>::Parameters;
+            type Strategy = $crate::arbitrary::SMapped<$inner, Self>;
+
+            fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+                $crate::strategy::statics::Map::new(
+                    $crate::arbitrary::any_with::<$inner>(args),
+                    $ctor as fn($inner) -> Self,
+                )
+            }
+        }
+    };
+}
+
 /// Similar to `assert!` from std, but returns a test failure instead of
 /// panicking if the condition fails.
 ///
@@ -959,20 +1330,31 @@ macro_rules! proptest_helper {
     (@_WRAPSTR ($a:pat, $($rest:pat),*)) => {
         (stringify!($a), $crate::proptest_helper!(@_WRAPSTR ($($rest),*)))
     };
+    // apply a `#![proptest_config(..)]` at the start of a test function's body
+    // in place of the block's configuration, then expand the rest of the body.
+    (@_FN_CONFIG $config:ident ($($cont:tt)*)
+     { #![proptest_config($fn_config:expr)] $($body:tt)* }) => {{
+        let test_name = $config.test_name;
+        let mut $config = $crate::test_runner::contextualize_config($fn_config.clone());
+        $config.test_name = test_name;
+        $crate::proptest_helper!($($cont)* { $($body)* })
+    }};
+    (@_FN_CONFIG $config:ident ($($cont:tt)*) { $($body:tt)* }) => {
+        $crate::proptest_helper!($($cont)* { $($body)* })
+    };
     // build a property testing block that when executed, executes the full property test.
-    (@_BODY $config:ident ($($parm:pat in $strategy:expr),+) [$($mod:tt)*] $body:expr) => {{
+    (@_BODY $run:tt $config:ident ($($parm:pat in $strategy:expr),+) [$($mod:tt)*] [$($ret:ty)?] $body:expr) => {{
         $config.source_file = Some(file!());
         let mut runner = $crate::test_runner::TestRunner::new($config);
         let names = $crate::proptest_helper!(@_WRAPSTR ($($parm),*));
-        match runner.run(
-            &$crate::strategy::Strategy::prop_map(
+        match $crate::proptest_helper!(@_RUN $run runner,
+            $crate::strategy::Strategy::prop_map(
                 $crate::proptest_helper!(@_WRAP ($($strategy)*)),
                 |values| $crate::sugar::NamedArguments(names, values)),
             $($mod)* |$crate::sugar::NamedArguments(
                 _, $crate::proptest_helper!(@_WRAPPAT ($($parm),*)))|
             {
-                let (): () = $body;
-                Ok(())
+                $crate::proptest_helper!(@_RESULT [$($ret)?] $body)
             })
         {
             Ok(()) => (),
@@ -980,17 +1362,75 @@ macro_rules! proptest_helper {
         }
     }};
     // build a property testing block that when executed, executes the full property test.
-    (@_BODY2 $config:ident ($($arg:tt)+) [$($mod:tt)*] $body:expr) => {{
+    (@_BODY2 $run:tt $config:ident ($($arg:tt)+) [$($mod:tt)*] [$($ret:ty)?] $body:expr) => {{
         $config.source_file = Some(file!());
         let mut runner = $crate::test_runner::TestRunner::new($config);
         let names = $crate::proptest_helper!(@_EXT _STR ($($arg)*));
-        match runner.run(
-            &$crate::strategy::Strategy::prop_map(
+        match $crate::proptest_helper!(@_RUN $run runner,
+            $crate::strategy::Strategy::prop_map(
                 $crate::proptest_helper!(@_EXT _STRAT ($($arg)*)),
                 |values| $crate::sugar::NamedArguments(names, values)),
             $($mod)* |$crate::sugar::NamedArguments(
                 _, $crate::proptest_helper!(@_EXT _PAT ($($arg)*)))|
             {
+                $crate::proptest_helper!(@_RESULT [$($ret)?] $body)
+            })
+        {
+            Ok(()) => (),
+            Err(e) => panic!("{}\n{}", e, runner),
+        }
+    }};
+
+    // run the test of a property testing block. Tests defined as functions
+    // capture nothing which cannot be shared between threads, so they are run
+    // in parallel when their values can be sent to other threads.
+    (@_RUN [par] $runner:ident, $strategy:expr, $test:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::sugar::{RunParallelTest as _, RunSerialTest as _};
+        (&$crate::sugar::PropTest::new($strategy, $test)).run_test(&mut $runner)
+    }};
+    (@_RUN [ser] $runner:ident, $strategy:expr, $test:expr) => {
+        $runner.run(&$strategy, $test)
+    };
+
+    // build an async property testing block that when executed, runs the full
+    // property test on the default executor.
+    (@_ASYNC_BODY $config:ident ($($parm:pat in $strategy:expr),+) $body:expr) => {{
+        $config.source_file = Some(file!());
+        let mut runner = $crate::test_runner::TestRunner::new($config);
+        let names = $crate::proptest_helper!(@_WRAPSTR ($($parm),*));
+        let executor = $crate::test_runner::default_executor();
+        match runner.run_async(
+            &$crate::strategy::Strategy::prop_map(
+                $crate::proptest_helper!(@_WRAP ($($strategy)*)),
+                |values| $crate::sugar::NamedArguments(names, values)),
+            &executor,
+            |args| async move {
+                let $crate::sugar::NamedArguments(
+                    _, $crate::proptest_helper!(@_WRAPPAT ($($parm),*))) = args;
+                let (): () = $body;
+                Ok(())
+            })
+        {
+            Ok(()) => (),
+            Err(e) => panic!("{}\n{}", e, runner),
+        }
+    }};
+    // build an async property testing block that when executed, runs the full
+    // property test on the default executor.
+    (@_ASYNC_BODY2 $config:ident ($($arg:tt)+) $body:expr) => {{
+        $config.source_file = Some(file!());
+        let mut runner = $crate::test_runner::TestRunner::new($config);
+        let names = $crate::proptest_helper!(@_EXT _STR ($($arg)*));
+        let executor = $crate::test_runner::default_executor();
+        match runner.run_async(
+            &$crate::strategy::Strategy::prop_map(
+                $crate::proptest_helper!(@_EXT _STRAT ($($arg)*)),
+                |values| $crate::sugar::NamedArguments(names, values)),
+            &executor,
+            |args| async move {
+                let $crate::sugar::NamedArguments(
+                    _, $crate::proptest_helper!(@_EXT _PAT ($($arg)*))) = args;
                 let (): () = $body;
                 Ok(())
             })
@@ -1000,6 +1440,15 @@ macro_rules! proptest_helper {
         }
     }};
 
+    // convert the value of a test body into a `TestCaseResult`.
+    (@_RESULT [] $body:expr) => {{
+        let (): () = $body;
+        Ok(())
+    }};
+    (@_RESULT [$ret:ty] $body:expr) => {
+        $crate::sugar::fallible_body_result(|| -> $ret { $body })
+    };
+
     // The logic below helps support `pat: type` in the proptest! macro.
 
     // These matchers define the actual logic:
@@ -1137,6 +1586,83 @@ named_arguments_tuple!(0 AN AV 1 BN BV 2 CN CV 3 DN DV 4 EN EV
 named_arguments_tuple!(0 AN AV 1 BN BV 2 CN CV 3 DN DV 4 EN EV
                        5 FN FV 6 GN GV 7 HN HV 8 IN IV 9 JN JV);
 
+/// Runs the body of a `proptest!` test declared to return `Result<(), E>`.
+///
+/// A `TestCaseError` is passed through unchanged so that rejections and
+/// `prop_assert!` failures keep their meaning; any other error fails the test
+/// case with the error's `Debug` representation as the reason.
+#[doc(hidden)]
+pub fn fallible_body_result<E: fmt::Debug + 'static>(
+    body: impl FnOnce() -> Result<(), E>,
+) -> TestCaseResult {
+    body().map_err(|e| match (&e as &dyn Any).downcast_ref::<TestCaseError>() {
+        Some(e) => e.clone(),
+        None => TestCaseError::fail(format!("{:?}", e)),
+    })
+}
+
+/// A strategy and test run by `proptest!`.
+///
+/// `run_test()` resolves to `RunParallelTest`, which runs the test with
+/// `TestRunner::run_parallel()`, when the test can be shared between threads
+/// and its values sent to them, and to `RunSerialTest`, which uses
+/// `TestRunner::run()`, otherwise. The macro calls it on a reference to this,
+/// which matches the impl of `RunParallelTest` before the one of
+/// `RunSerialTest`, as the latter is for a reference to a reference.
+#[doc(hidden)]
+pub struct PropTest<S, F>(S, F);
+
+impl<S: Strategy, F: Fn(S::Value) -> TestCaseResult> PropTest<S, F> {
+    #[doc(hidden)]
+    pub fn new(strategy: S, test: F) -> Self {
+        PropTest(strategy, test)
+    }
+}
+
+#[doc(hidden)]
+pub trait RunParallelTest<S: Strategy> {
+    fn run_test(
+        &self,
+        runner: &mut TestRunner,
+    ) -> Result<(), TestError<S::Value>>;
+}
+
+#[cfg(feature = "std")]
+impl<S, F> RunParallelTest<S> for PropTest<S, F>
+where
+    S: Strategy,
+    S::Value: Send,
+    F: Fn(S::Value) -> TestCaseResult + Sync,
+{
+    fn run_test(
+        &self,
+        runner: &mut TestRunner,
+    ) -> Result<(), TestError<S::Value>> {
+        runner.run_parallel(&self.0, &self.1)
+    }
+}
+
+#[doc(hidden)]
+pub trait RunSerialTest<S: Strategy> {
+    fn run_test(
+        &self,
+        runner: &mut TestRunner,
+    ) -> Result<(), TestError<S::Value>>;
+}
+
+impl<S, F> RunSerialTest<S> for &PropTest<S, F>
+where
+    S: Strategy,
+    F: Fn(S::Value) -> TestCaseResult,
+{
+    fn run_test(
+        &self,
+        runner: &mut TestRunner,
+    ) -> Result<(), TestError<S::Value>> {
+        runner.run(&self.0, &self.1)
+    }
+}
+
 #[cfg(feature = "std")]
 #[doc(hidden)]
 pub fn force_no_fork(config: &mut crate::test_runner::Config) {
@@ -1468,6 +1994,69 @@ mod test {
     }
 }
 
+#[cfg(all(test, feature = "std"))]
+mod test_threads {
+    use crate::strategy::Strategy;
+    use std::cell::Cell;
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+    use std::thread::{self, ThreadId};
+
+    lazy_static! {
+        static ref THREADS: Mutex<HashSet<ThreadId>> =
+            Mutex::new(HashSet::new());
+    }
+
+    #[test]
+    fn threads_run_proptest_cases_in_parallel() {
+        proptest! {
+            #![proptest_config(crate::test_runner::Config {
+                cases: 256,
+                threads: 4,
+                failure_persistence: None,
+                .. crate::test_runner::Config::default()
+            })]
+
+            fn record_thread(_ in 0u32..1000) {
+                THREADS.lock().unwrap().insert(thread::current().id());
+            }
+        }
+
+        record_thread();
+        assert!(THREADS.lock().unwrap().len() > 1);
+    }
+
+    #[test]
+    fn threads_run_closures_serially() {
+        let count = Cell::new(0);
+        proptest!(crate::test_runner::Config {
+            cases: 16,
+            threads: 4,
+            failure_persistence: None,
+            .. crate::test_runner::Config::default()
+        }, |(_ in 0u32..1000)| {
+            count.set(count.get() + 1);
+        });
+        assert_eq!(16, count.get());
+    }
+
+    proptest! {
+        #![proptest_config(crate::test_runner::Config {
+            cases: 16,
+            threads: 4,
+            failure_persistence: None,
+            .. crate::test_runner::Config::default()
+        })]
+
+        #[test]
+        fn threads_run_unsendable_values_serially(
+            x in (0u32..1000).prop_map(std::rc::Rc::new)
+        ) {
+            prop_assert!(*x < 1000);
+        }
+    }
+}
+
 #[cfg(all(test, feature = "timeout"))]
 mod test_timeout {
     proptest! {
@@ -1483,6 +2072,51 @@ mod test_timeout {
     }
 }
 
+#[cfg(test)]
+mod fn_config_tests {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    static BLOCK_CASES: AtomicUsize = AtomicUsize::new(0);
+    static FN_CASES: AtomicUsize = AtomicUsize::new(0);
+
+    proptest! {
+        #![proptest_config(crate::test_runner::Config {
+            cases: 4,
+            failure_persistence: None,
+            .. crate::test_runner::Config::default()
+        })]
+
+        fn uses_block_config(_ in 0u32..10) {
+            BLOCK_CASES.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn uses_fn_config(_: u32) {
+            #![proptest_config(crate::test_runner::Config {
+                cases: 32,
+                failure_persistence: None,
+                .. crate::test_runner::Config::default()
+            })]
+            FN_CASES.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn fn_config_replaces_block_config() {
+        uses_block_config();
+        uses_fn_config();
+        assert_eq!(4, BLOCK_CASES.load(Ordering::SeqCst));
+        assert_eq!(32, FN_CASES.load(Ordering::SeqCst));
+    }
+
+    proptest! {
+        #[test]
+        fn fn_config_without_block_config(x in 0u32..10) {
+            #![proptest_config(crate::test_runner::Config::with_cases(8))]
+            prop_assert!(x < 10);
+        }
+    }
+}
+
 #[cfg(test)]
 mod another_test {
     use crate::sugar;
@@ -1607,6 +2241,127 @@ mod closure_tests {
     }
 }
 
+#[cfg(all(test, feature = "std"))]
+mod result_tests {
+    use crate::test_runner::{TestCaseError, TestCaseResult};
+    use std::num::ParseIntError;
+    use std::string::ToString;
+
+    proptest! {
+        #[test]
+        fn accepts_result_body(x in 0u32..1000) -> Result<(), ParseIntError> {
+            let parsed: u32 = x.to_string().parse()?;
+            assert_eq!(x, parsed);
+            Ok(())
+        }
+
+        #[test]
+        fn accepts_test_case_result_body(x: u8) -> TestCaseResult {
+            prop_assume!(x != 0);
+            prop_assert_ne!(x, 0);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn closures_accept_result_body() {
+        let conf = crate::test_runner::Config::default();
+
+        proptest!(|(x in 0u32..10)| -> Result<(), ParseIntError> {
+            assert_eq!(x, x.to_string().parse::<u32>()?);
+            Ok(())
+        });
+        proptest!(move |(_x: u32)| -> TestCaseResult { Ok(()) });
+        proptest!(conf, |(x: u8,)| -> TestCaseResult {
+            prop_assert_eq!(x, x);
+            Ok(())
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Test failed: \"odd\"")]
+    fn error_fails_with_debug_output() {
+        let conf = crate::test_runner::Config {
+            failure_persistence: None,
+            ..crate::test_runner::Config::default()
+        };
+
+        proptest!(conf, |(x in 0u32..10)| -> Result<(), &'static str> {
+            if x % 2 == 1 {
+                return Err("odd");
+            }
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_case_error_is_passed_through() {
+        let reject = crate::sugar::fallible_body_result(|| {
+            Err(TestCaseError::reject("nope"))
+        });
+        assert!(matches!(reject, Err(TestCaseError::Reject(_))));
+
+        let fail = crate::sugar::fallible_body_result(|| Err(42));
+        assert!(matches!(fail, Err(TestCaseError::Fail(ref why))
+                         if why.message() == "42"));
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod async_tests {
+    use crate::test_runner::{BlockingExecutor, Config, TestError, TestRunner};
+
+    async fn double(x: u32) -> u32 {
+        x * 2
+    }
+
+    proptest! {
+        #[test]
+        async fn accepts_async_body(x in 0u32..1000) {
+            prop_assert_eq!(x * 2, double(x).await);
+        }
+    }
+
+    proptest! {
+        #![proptest_config(Config::with_cases(10))]
+        #[test]
+        async fn accepts_async_body_with_config(x: u32, ref y in "[a-z]") {
+            prop_assume!(x < u32::MAX / 2);
+            prop_assert_eq!(x * 2, double(x).await);
+            prop_assert_eq!(1, y.len());
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    proptest! {
+        #[test]
+        async fn runs_inside_tokio_runtime(_x: u8) {
+            // Panics if not called from within a runtime.
+            let _ = tokio::runtime::Handle::current();
+        }
+    }
+
+    #[test]
+    fn run_async_shrinks_failures() {
+        let mut runner = TestRunner::new(Config {
+            failure_persistence: None,
+            ..Config::default()
+        });
+        let result = runner.run_async(
+            &(0u32..1000),
+            &BlockingExecutor,
+            |x| async move {
+                prop_assert!(double(x).await < 500);
+                Ok(())
+            },
+        );
+        match result {
+            Err(TestError::Fail(_, x)) => assert_eq!(250, x),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+}
+
 #[cfg(test)]
 mod any_tests {
     proptest! {
@@ -1651,3 +2406,37 @@ mod any_tests {
         let proptest_helper!(@_EXT _PAT( _x in 1..2 )) = 1;
     }
 }
+
+#[cfg(test)]
+mod newtype_tests {
+    use crate::prelude::*;
+    use crate::std_facade::Vec;
+    use crate::test_runner::TestRunner;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Meters(u32);
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Small(u8);
+    #[derive(Clone, Debug, PartialEq)]
+    struct Tagged<T>(T, &'static str);
+
+    prop_arbitrary_newtype!(Meters(u32), Small(u8));
+    prop_arbitrary_newtype!(
+        [T: Arbitrary] Tagged<T>, T => |value| Tagged(value, "tag")
+    );
+
+    #[test]
+    fn newtypes_delegate_parameters_and_shrinking() {
+        let mut runner = TestRunner::deterministic();
+        assert_eq!(Meters(0), any::<Meters>().minimal(&mut runner));
+        assert_eq!(
+            Tagged(Small(0), "tag"),
+            any::<Tagged<Small>>().minimal(&mut runner)
+        );
+        let strategy = any_with::<Tagged<Vec<u8>>>(((1..3).into(), ()));
+        for _ in 0..64 {
+            let len = strategy.sample(&mut runner).0.len();
+            assert!((1..3).contains(&len), "{}", len);
+        }
+    }
+}
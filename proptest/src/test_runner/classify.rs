@@ -0,0 +1,96 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Support for classifying test cases.
+//!
+//! A test body attaches labels to the current input via [`classify`]
+//! (usually through the `prop_classify!` and `prop_collect!` macros). The
+//! runner counts how many passing test cases carried each label and reports
+//! the distribution once the test passes, which shows whether the strategy
+//! actually exercises the interesting parts of the code under test.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+thread_local! {
+    static LABELS: RefCell<BTreeSet<String>> =
+        const { RefCell::new(BTreeSet::new()) };
+}
+
+/// Attach `label` to the test case currently running on this thread.
+///
+/// Each label is counted at most once per test case, no matter how many times
+/// it is attached. Labels are only counted for test cases which pass, so that
+/// the report describes the inputs the test was actually checked against.
+pub fn classify(label: &str) {
+    LABELS.with(|labels| {
+        let mut labels = labels.borrow_mut();
+        if !labels.contains(label) {
+            labels.insert(label.to_string());
+        }
+    });
+}
+
+/// Take the labels attached by the most recent test case.
+pub(crate) fn take_labels() -> BTreeSet<String> {
+    LABELS.with(|labels| core::mem::take(&mut *labels.borrow_mut()))
+}
+
+/// Format the label distribution for `cases` passing test cases, most common
+/// label first.
+pub(crate) fn report(cases: u32, labels: &BTreeMap<String, u32>) -> String {
+    let mut by_count: Vec<(&String, &u32)> = labels.iter().collect();
+    by_count.sort_by(|a, b| b.1.cmp(a.1));
+
+    let mut report =
+        format!("Label distribution over {} passing cases:", cases);
+    for (label, &count) in by_count {
+        let percent = f64::from(count) * 100.0 / f64::from(cases.max(1));
+        let _ = write!(report, "\n\t{:6.2}% {}", percent, label);
+    }
+    report
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn labels_are_counted_once_per_case() {
+        classify("small");
+        classify("small");
+        classify(&format!("{}", 42));
+
+        let labels = take_labels();
+        assert_eq!(
+            vec!["42", "small"],
+            labels.iter().map(String::as_str).collect::<Vec<_>>()
+        );
+        assert!(take_labels().is_empty());
+    }
+
+    #[test]
+    fn report_lists_most_common_labels_first() {
+        let mut labels = BTreeMap::new();
+        labels.insert("empty".to_string(), 5);
+        labels.insert("large".to_string(), 80);
+        labels.insert("small".to_string(), 20);
+
+        assert_eq!(
+            "Label distribution over 100 passing cases:\n\
+             \t 80.00% large\n\
+             \t 20.00% small\n\
+             \t  5.00% empty",
+            report(100, &labels)
+        );
+    }
+}
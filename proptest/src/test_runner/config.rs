@@ -9,10 +9,20 @@
 
 use crate::std_facade::Box;
 use core::{fmt, str, u32};
+#[cfg(feature = "std")]
+use std::path::PathBuf;
+#[cfg(feature = "std")]
+use std::sync::Arc;
 
 use crate::test_runner::result_cache::{noop_result_cache, ResultCache};
 use crate::test_runner::rng::RngAlgorithm;
+#[cfg(feature = "std")]
+use crate::test_runner::CoverageFeedback;
 use crate::test_runner::FailurePersistence;
+#[cfg(feature = "std")]
+use crate::test_runner::JsonOutput;
+#[cfg(feature = "std")]
+use crate::test_runner::TestObserver;
 
 /// Override the config fields from environment variables, if any are set.
 /// Without the `std` feature this function returns config unchanged.
@@ -30,12 +40,17 @@ pub fn contextualize_config(mut result: Config) -> Config {
     const MAX_FLAT_MAP_REGENS: &str = "PROPTEST_MAX_FLAT_MAP_REGENS";
     const MAX_SHRINK_TIME: &str = "PROPTEST_MAX_SHRINK_TIME";
     const MAX_SHRINK_ITERS: &str = "PROPTEST_MAX_SHRINK_ITERS";
+    const MAX_FAILURES: &str = "PROPTEST_MAX_FAILURES";
     const MAX_DEFAULT_SIZE_RANGE: &str = "PROPTEST_MAX_DEFAULT_SIZE_RANGE";
     #[cfg(feature = "fork")]
     const FORK: &str = "PROPTEST_FORK";
     #[cfg(feature = "timeout")]
     const TIMEOUT: &str = "PROPTEST_TIMEOUT";
     const VERBOSE: &str = "PROPTEST_VERBOSE";
+    const TARGETED: &str = "PROPTEST_TARGETED";
+    const JSON_OUTPUT: &str = "PROPTEST_JSON_OUTPUT";
+    const RECORD_CASES: &str = "PROPTEST_RECORD_CASES";
+    const THREADS: &str = "PROPTEST_THREADS";
     const RNG_ALGORITHM: &str = "PROPTEST_RNG_ALGORITHM";
     const RNG_SEED: &str = "PROPTEST_RNG_SEED";
     const DISABLE_FAILURE_PERSISTENCE: &str =
@@ -120,6 +135,13 @@ pub fn contextualize_config(mut result: Config) -> Config {
                 "u32",
                 MAX_SHRINK_ITERS,
             );
+        } else if var == MAX_FAILURES {
+            parse_or_warn(
+                &value,
+                &mut result.max_failures,
+                "u32",
+                MAX_FAILURES,
+            );
         } else if var == MAX_DEFAULT_SIZE_RANGE {
             parse_or_warn(
                 &value,
@@ -129,6 +151,17 @@ pub fn contextualize_config(mut result: Config) -> Config {
             );
         } else if var == VERBOSE {
             parse_or_warn(&value, &mut result.verbose, "u32", VERBOSE);
+        } else if var == TARGETED {
+            parse_or_warn(&value, &mut result.targeted, "bool", TARGETED);
+        } else if var == THREADS {
+            parse_or_warn(&value, &mut result.threads, "u32", THREADS);
+        } else if var == JSON_OUTPUT {
+            result.json_output = Some(match value.to_str() {
+                Some("stderr") => JsonOutput::Stderr,
+                _ => JsonOutput::File(value.into()),
+            });
+        } else if var == RECORD_CASES {
+            result.record_cases = Some(value.into());
         } else if var == RNG_ALGORITHM {
             parse_or_warn(
                 &value,
@@ -175,10 +208,23 @@ fn default_default_config() -> Config {
         #[cfg(feature = "std")]
         max_shrink_time: 0,
         max_shrink_iters: u32::MAX,
+        max_failures: 1,
         max_default_size_range: 100,
         result_cache: noop_result_cache,
         #[cfg(feature = "std")]
         verbose: 0,
+        #[cfg(feature = "std")]
+        targeted: false,
+        #[cfg(feature = "std")]
+        coverage_feedback: None,
+        #[cfg(feature = "std")]
+        json_output: None,
+        #[cfg(feature = "std")]
+        record_cases: None,
+        #[cfg(feature = "std")]
+        observer: None,
+        #[cfg(feature = "std")]
+        threads: 1,
         rng_algorithm: RngAlgorithm::default(),
         rng_seed: RngSeed::Random,
         _non_exhaustive: (),
@@ -191,7 +237,21 @@ fn default_default_config() -> Config {
 lazy_static! {
     static ref DEFAULT_CONFIG: Config = {
         let mut default_config = default_default_config();
-        default_config.failure_persistence = Some(Box::new(crate::test_runner::FileFailurePersistence::default()));
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            default_config.failure_persistence = Some(Box::new(crate::test_runner::FileFailurePersistence::default()));
+        }
+        // There is usually no file system to write to on `wasm32`, so
+        // failures are kept in memory instead, where they are shared by every
+        // test using the default config for the lifetime of the program.
+        #[cfg(target_arch = "wasm32")]
+        {
+            default_config.failure_persistence = Some(Box::new(
+                crate::test_runner::SharedFailurePersistence::new(
+                    crate::test_runner::MapFailurePersistence::default(),
+                ),
+            ));
+        }
         contextualize_config(default_config)
     };
 }
@@ -222,7 +282,7 @@ impl fmt::Display for RngSeed {
 }
 
 /// Configuration for how a proptest test should be run.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone)]
 pub struct Config {
     /// The number of successful test cases that must execute for the test as a
     /// whole to pass.
@@ -264,12 +324,17 @@ pub struct Config {
     /// Indicates whether and how to persist failed test results.
     ///
     /// When compiling with "std" feature (i.e. the standard library is available), the default
-    /// is `Some(Box::new(FileFailurePersistence::SourceParallel("proptest-regressions")))`.
+    /// is `Some(Box::new(FileFailurePersistence::SourceParallel("proptest-regressions")))`,
+    /// except on `wasm32` targets, where it is a `SharedFailurePersistence` holding a
+    /// `MapFailurePersistence`, so that failures are replayed for the rest of the
+    /// program's lifetime without needing a file system.
     ///
     /// Without the standard library, the default is `None`, and no persistence occurs.
     ///
     /// See the docs of [`FileFailurePersistence`](enum.FileFailurePersistence.html)
-    /// and [`MapFailurePersistence`](struct.MapFailurePersistence.html) for more information.
+    /// and [`MapFailurePersistence`](struct.MapFailurePersistence.html) for more information,
+    /// and of [`FailurePersistence`](trait.FailurePersistence.html) for how to
+    /// keep failures elsewhere.
     ///
     /// You can disable failure persistence with the `PROPTEST_DISABLE_FAILURE_PERSISTENCE`
     /// environment variable but its not currently possible to set the persistence file
@@ -345,7 +410,8 @@ pub struct Config {
     /// This will not cause currently running test cases to be interrupted.
     ///
     /// This configuration is only available when the `std` feature is enabled
-    /// (which it is by default).
+    /// (which it is by default). It is ignored on `wasm32` targets, which may
+    /// not have a clock.
     ///
     /// The default is `0` (i.e., no limit), which can be overridden by setting
     /// the `PROPTEST_MAX_SHRINK_TIME` environment variable. (The variable is
@@ -371,6 +437,32 @@ pub struct Config {
     /// considered when the `std` feature is enabled, which it is by default.)
     pub max_shrink_iters: u32,
 
+    /// The number of failing test cases to find before the test as a whole
+    /// stops.
+    ///
+    /// With a value greater than 1, the runner keeps generating new cases
+    /// after a failure, shrinking each failing case it finds, until either
+    /// this many failures have been found or `cases` cases have passed. The
+    /// first failure is returned as the result of the run, and all of them
+    /// are available from `TestRunner::failures()` and are listed when the
+    /// runner is displayed, as it is in the panic message of a failing
+    /// `proptest!` test. This is useful when a test may be failing for
+    /// several independent reasons.
+    ///
+    /// A failure whose minimal failing input has the same `Debug`
+    /// representation as that of an earlier failure is considered a
+    /// duplicate, and is neither reported nor counted towards this limit.
+    /// So that a test which always fails the same way still finishes, the
+    /// run also stops once `cases` duplicates have been found.
+    ///
+    /// Persisted failing cases count towards this limit. It is ignored, as if
+    /// it were 1, when forking.
+    ///
+    /// The default is 1, which can be overridden by setting the
+    /// `PROPTEST_MAX_FAILURES` environment variable. (The variable is only
+    /// considered when the `std` feature is enabled, which it is by default.)
+    pub max_failures: u32,
+
     /// The default maximum size to `proptest::collection::SizeRange`. The default
     /// strategy for collections (like `Vec`) use collections in the range of
     /// `0..max_default_size_range`.
@@ -408,7 +500,8 @@ pub struct Config {
     /// - 0: No extra output.
     /// - 1: Log test failure messages. In state machine tests, this level is
     ///   used to print transitions.
-    /// - 2: Trace low-level details.
+    /// - 2: Trace low-level details, including each input tried while
+    ///   shrinking and whether the test passed, failed or rejected it.
     ///
     /// This is only available with the `std` feature (enabled by default)
     /// since on nostd proptest has no way to produce output.
@@ -420,6 +513,93 @@ pub struct Config {
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     pub verbose: u32,
 
+    /// If true, use the utilities reported by test cases via `prop_target!`
+    /// to guide the generation of new inputs towards maximising them.
+    ///
+    /// In this mode, each input is generated from a byte buffer rather than a
+    /// conventional RNG seed, and the runner searches for buffers producing
+    /// higher utilities using simulated annealing. Failing inputs are still
+    /// persisted and shrunk as normal. Targeted search is not supported
+    /// together with forking and is ignored in that case.
+    ///
+    /// This is only available with the `std` feature (enabled by default).
+    ///
+    /// The default is `false`, which can be overridden by setting the
+    /// `PROPTEST_TARGETED` environment variable. (The variable is only
+    /// considered when the `std` feature is enabled, which it is by default.)
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub targeted: bool,
+
+    /// A function to create the source of coverage information used to guide
+    /// the generation of new inputs, if any.
+    ///
+    /// When set, each input is generated from a byte buffer rather than a
+    /// conventional RNG seed. Inputs for which the `CoverageFeedback` reports
+    /// new coverage are kept, and later inputs are mostly produced by mutating
+    /// them. This takes precedence over `targeted`, and is not supported
+    /// together with forking.
+    ///
+    /// This is only available with the `std` feature (enabled by default).
+    ///
+    /// The default is `None`.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub coverage_feedback: Option<fn() -> Box<dyn CoverageFeedback>>,
+
+    /// If set, a machine-readable report of each test failure is written here
+    /// as a line of JSON. See `JsonOutput` for the format.
+    ///
+    /// The default is `None`, which can be overridden by setting the
+    /// `PROPTEST_JSON_OUTPUT` environment variable to `stderr` or to the path
+    /// of a file to append to.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub json_output: Option<JsonOutput>,
+
+    /// If set, each generated test case is appended to the file at this path
+    /// as a line of JSON, so that what a run tested can be examined
+    /// afterwards. Each line holds an object with the following fields:
+    ///
+    /// - `test_name`: as above, or `null` if unset.
+    /// - `input`: the `Debug` representation of the input.
+    /// - `outcome`: `"passed"`, `"rejected"` or `"failed"`.
+    /// - `reason`: the reason the input was rejected or failed, or `null` if
+    ///   it passed.
+    ///
+    /// The steps taken while shrinking a failing input are not recorded.
+    ///
+    /// The default is `None`, which can be overridden by setting the
+    /// `PROPTEST_RECORD_CASES` environment variable to the path of a file to
+    /// append to.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub record_cases: Option<PathBuf>,
+
+    /// If set, this function is called at the start of each test run to
+    /// create a `TestObserver`, which is then notified of the progress of the
+    /// run. It may capture state, such as a channel to send events on, which
+    /// is shared by the observers of every run.
+    ///
+    /// The default is `None`.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub observer: Option<Arc<dyn Fn() -> Box<dyn TestObserver> + Send + Sync>>,
+
+    /// The number of threads `TestRunner::run_parallel` runs test cases on.
+    /// Values of 0 and 1 both run every test case on the current thread.
+    ///
+    /// Test functions defined with `proptest!` also use `run_parallel` when
+    /// their input values are `Send`. This has no effect on `TestRunner::run`,
+    /// on tests whose values are not `Send`, or on the closure form of
+    /// `proptest!`, whose closure may capture state not shared across threads.
+    ///
+    /// The default is 1, which can be overridden by setting the
+    /// `PROPTEST_THREADS` environment variable.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub threads: u32,
+
     /// The RNG algorithm to use when not using a user-provided RNG.
     ///
     /// The default is `RngAlgorithm::default()`, which can be overridden by
@@ -434,6 +614,13 @@ pub struct Config {
 
     /// Seed used for the RNG. Set by using the PROPTEST_RNG_SEED environment variable
     /// If the environment variable is undefined, a random seed is generated (this is the default option).
+    ///
+    /// `TestRunner::new()` reports the seed it used when a test fails, so
+    /// setting this to that value repeats the failing run exactly. So that it
+    /// can be reported this way, a random seed is a single `u64`, which the
+    /// RNG's full seed is derived from. Runs therefore draw from at most 2^64
+    /// distinct sequences of inputs, rather than the 2^256 a fully random
+    /// ChaCha seed would allow.
     pub rng_seed: RngSeed,
 
     // Needs to be public so FRU syntax can be used.
@@ -441,6 +628,88 @@ pub struct Config {
     pub _non_exhaustive: (),
 }
 
+impl fmt::Debug for Config {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut debug = f.debug_struct("Config");
+        debug
+            .field("cases", &self.cases)
+            .field("max_local_rejects", &self.max_local_rejects)
+            .field("max_global_rejects", &self.max_global_rejects)
+            .field("max_flat_map_regens", &self.max_flat_map_regens)
+            .field("failure_persistence", &self.failure_persistence)
+            .field("source_file", &self.source_file)
+            .field("test_name", &self.test_name);
+        #[cfg(feature = "fork")]
+        debug.field("fork", &self.fork);
+        #[cfg(feature = "timeout")]
+        debug.field("timeout", &self.timeout);
+        #[cfg(feature = "std")]
+        debug.field("max_shrink_time", &self.max_shrink_time);
+        debug
+            .field("max_shrink_iters", &self.max_shrink_iters)
+            .field("max_failures", &self.max_failures)
+            .field("max_default_size_range", &self.max_default_size_range)
+            .field("result_cache", &self.result_cache);
+        #[cfg(feature = "std")]
+        debug
+            .field("verbose", &self.verbose)
+            .field("targeted", &self.targeted)
+            .field("coverage_feedback", &self.coverage_feedback)
+            .field("json_output", &self.json_output)
+            .field("record_cases", &self.record_cases)
+            .field("observer", &self.observer.as_ref().map(|_| "<function>"))
+            .field("threads", &self.threads);
+        debug
+            .field("rng_algorithm", &self.rng_algorithm)
+            .field("rng_seed", &self.rng_seed)
+            .finish()
+    }
+}
+
+impl PartialEq for Config {
+    /// Compares every field except `coverage_feedback`, since the same
+    /// function may have different addresses, or different functions the
+    /// same address. `observer`s are only equal if they are the same `Arc`.
+    fn eq(&self, other: &Self) -> bool {
+        #[allow(unused_mut)]
+        let mut eq = self.cases == other.cases
+            && self.max_local_rejects == other.max_local_rejects
+            && self.max_global_rejects == other.max_global_rejects
+            && self.max_flat_map_regens == other.max_flat_map_regens
+            && self.failure_persistence == other.failure_persistence
+            && self.source_file == other.source_file
+            && self.test_name == other.test_name
+            && self.max_shrink_iters == other.max_shrink_iters
+            && self.max_failures == other.max_failures
+            && self.max_default_size_range == other.max_default_size_range
+            && self.result_cache as usize == other.result_cache as usize
+            && self.rng_algorithm == other.rng_algorithm
+            && self.rng_seed == other.rng_seed;
+        #[cfg(feature = "fork")]
+        {
+            eq &= self.fork == other.fork;
+        }
+        #[cfg(feature = "timeout")]
+        {
+            eq &= self.timeout == other.timeout;
+        }
+        #[cfg(feature = "std")]
+        {
+            eq &= self.max_shrink_time == other.max_shrink_time
+                && self.verbose == other.verbose
+                && self.targeted == other.targeted
+                && self.json_output == other.json_output
+                && self.record_cases == other.record_cases
+                && match (&self.observer, &other.observer) {
+                    (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+                    (a, b) => a.is_none() && b.is_none(),
+                }
+                && self.threads == other.threads;
+        }
+        eq
+    }
+}
+
 impl Config {
     /// Constructs a `Config` only differing from the `default()` in the
     /// number of test cases required to pass the test successfully.
@@ -0,0 +1,170 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Support for coverage-guided input generation.
+//!
+//! Proptest does not collect coverage itself. Instead, an external source of
+//! coverage (for example SanitizerCoverage callbacks or the counters emitted
+//! by `-C instrument-coverage`) is adapted to the [`CoverageFeedback`] trait
+//! and installed with `Config::coverage_feedback`. The runner then keeps a
+//! corpus of the inputs which reached new coverage and generates further
+//! inputs mostly by mutating members of that corpus.
+
+use crate::std_facade::{Box, Vec};
+use std::fmt;
+use std::sync::Arc;
+
+use rand::Rng;
+
+use crate::test_runner::rng::Seed;
+use crate::test_runner::target::{
+    mutate_buffer, random_buffer, RESTART_PROBABILITY,
+};
+use crate::test_runner::TestRng;
+
+/// A source of coverage information used to guide input generation.
+///
+/// The runner calls `begin_case` immediately before generating and running
+/// each new test case, and `end_case` immediately after. Inputs for which
+/// `end_case` reports new coverage are kept and used as the basis for later
+/// inputs.
+///
+/// Persisted failures are replayed without consulting the feedback, and
+/// coverage-guided generation is not available when forking is enabled.
+pub trait CoverageFeedback: fmt::Debug {
+    /// Prepare to observe a new test case, e.g. by clearing any per-case
+    /// counters.
+    fn begin_case(&mut self) {}
+
+    /// Return whether the test case which just finished reached any coverage
+    /// (such as a control-flow edge) that no earlier test case reached.
+    fn end_case(&mut self) -> bool;
+}
+
+/// Mutation-based search over the byte buffers used to generate test inputs,
+/// driven by a `CoverageFeedback`.
+#[derive(Debug)]
+pub(crate) struct CoverageSearch {
+    feedback: Box<dyn CoverageFeedback>,
+    rng: TestRng,
+    corpus: Vec<Arc<[u8]>>,
+    candidate: Arc<[u8]>,
+}
+
+impl CoverageSearch {
+    pub(crate) fn new(
+        feedback: Box<dyn CoverageFeedback>,
+        rng: TestRng,
+    ) -> Self {
+        CoverageSearch {
+            feedback,
+            rng,
+            corpus: Vec::new(),
+            candidate: Arc::from(&[][..]),
+        }
+    }
+
+    /// The number of inputs which have reached new coverage so far.
+    pub(crate) fn corpus_len(&self) -> usize {
+        self.corpus.len()
+    }
+
+    /// Choose the next input to try and return the seed which generates it.
+    ///
+    /// This also notifies the feedback that a new test case is beginning.
+    pub(crate) fn next_seed(&mut self) -> Seed {
+        let buffer = if self.corpus.is_empty()
+            || self.rng.gen_bool(RESTART_PROBABILITY)
+        {
+            random_buffer(&mut self.rng)
+        } else {
+            let parent = self.rng.gen_range(0..self.corpus.len());
+            mutate_buffer(&mut self.rng, &self.corpus[parent])
+        };
+
+        self.candidate = Arc::from(buffer);
+        self.feedback.begin_case();
+        Seed::PassThrough(None, Arc::clone(&self.candidate))
+    }
+
+    /// Ask the feedback whether the input from the last call to
+    /// `next_seed()` reached new coverage, keeping it if so.
+    pub(crate) fn observe(&mut self) -> bool {
+        let new_coverage = self.feedback.end_case();
+        if new_coverage {
+            self.corpus.push(Arc::clone(&self.candidate));
+        }
+        new_coverage
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell;
+    use std::collections::BTreeSet;
+
+    use super::*;
+    use crate::test_runner::RngAlgorithm;
+
+    thread_local! {
+        static REACHED: Cell<u32> = const { Cell::new(0) };
+    }
+
+    /// Treats each depth reached in a chain of nested conditions as an edge.
+    #[derive(Debug, Default)]
+    struct DepthFeedback {
+        seen: BTreeSet<u32>,
+    }
+
+    impl CoverageFeedback for DepthFeedback {
+        fn begin_case(&mut self) {
+            REACHED.with(|r| r.set(0));
+        }
+
+        fn end_case(&mut self) -> bool {
+            self.seen.insert(REACHED.with(|r| r.get()))
+        }
+    }
+
+    fn nested(data: &[u8]) {
+        let mut depth = 0;
+        for (&byte, &magic) in data.iter().zip(b"MAGIC") {
+            if byte != magic {
+                break;
+            }
+            depth += 1;
+        }
+        REACHED.with(|r| r.set(depth));
+    }
+
+    #[test]
+    fn search_finds_deeply_nested_input() {
+        let mut search = CoverageSearch::new(
+            Box::new(DepthFeedback::default()),
+            TestRng::deterministic_rng(RngAlgorithm::ChaCha),
+        );
+
+        let mut found = false;
+        for _ in 0..200_000 {
+            match search.next_seed() {
+                Seed::PassThrough(_, ref data) => nested(data),
+                _ => unreachable!(),
+            }
+            search.observe();
+            if REACHED.with(|r| r.get()) == 5 {
+                found = true;
+                break;
+            }
+        }
+
+        // Blind generation would need around 2^40 attempts.
+        assert!(found);
+        assert_eq!(6, search.corpus_len());
+    }
+}
@@ -0,0 +1,153 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Support for running async property tests.
+
+use core::future::Future;
+use core::task::{Context, Poll, Waker};
+use std::boxed::Box;
+use std::sync::Arc;
+use std::task::Wake;
+use std::thread::{self, Thread};
+
+/// Something which can drive a future to completion on the current thread.
+///
+/// This is used by `TestRunner::run_async` and by `async fn` tests in
+/// `proptest!` to run each test case. Implementations are provided for
+/// tokio's `Runtime` with the `tokio` feature and for `AsyncStdExecutor` with
+/// the `async-std` feature, and `BlockingExecutor` works for futures which do
+/// not depend on any particular runtime.
+pub trait Executor {
+    /// Run `future` to completion, blocking the current thread until it
+    /// finishes, and return its output.
+    fn block_on<F: Future>(&self, future: F) -> F::Output;
+}
+
+impl<E: Executor + ?Sized> Executor for &E {
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        (**self).block_on(future)
+    }
+}
+
+/// A minimal executor which polls the future on the current thread, parking
+/// the thread whenever the future is not ready.
+///
+/// This does not provide timers, IO or any other facilities of an async
+/// runtime, so futures which need one (for example most tokio types) should
+/// be run on that runtime's executor instead.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BlockingExecutor;
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+impl Executor for BlockingExecutor {
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+impl Executor for tokio::runtime::Runtime {
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        tokio::runtime::Runtime::block_on(self, future)
+    }
+}
+
+/// Runs futures with `async_std::task::block_on`.
+#[cfg(feature = "async-std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async-std")))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AsyncStdExecutor;
+
+#[cfg(feature = "async-std")]
+impl Executor for AsyncStdExecutor {
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        async_std::task::block_on(future)
+    }
+}
+
+/// Create the executor used for `async fn` tests in `proptest!`.
+///
+/// With the `tokio` feature this is a new current-thread tokio runtime with
+/// all of the drivers enabled in the build. Otherwise, with the `async-std`
+/// feature this is `AsyncStdExecutor`, and without either feature it is
+/// `BlockingExecutor`.
+pub fn default_executor() -> impl Executor {
+    #[cfg(feature = "tokio")]
+    {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("Failed to create tokio runtime")
+    }
+    #[cfg(all(feature = "async-std", not(feature = "tokio")))]
+    {
+        AsyncStdExecutor
+    }
+    #[cfg(not(any(feature = "tokio", feature = "async-std")))]
+    {
+        BlockingExecutor
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// A future which is pending once and wakes itself from another thread.
+    struct WakeFromThread(Arc<AtomicBool>);
+
+    impl Future for WakeFromThread {
+        type Output = u32;
+
+        fn poll(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<u32> {
+            if self.0.load(Ordering::SeqCst) {
+                return Poll::Ready(42);
+            }
+
+            let done = Arc::clone(&self.0);
+            let waker = cx.waker().clone();
+            thread::spawn(move || {
+                done.store(true, Ordering::SeqCst);
+                waker.wake();
+            });
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn blocking_executor_waits_for_wakeup() {
+        let future = WakeFromThread(Arc::new(AtomicBool::new(false)));
+        assert_eq!(42, BlockingExecutor.block_on(future));
+    }
+
+    #[test]
+    fn default_executor_runs_futures() {
+        assert_eq!(3, default_executor().block_on(async { 1 + 2 }));
+    }
+}
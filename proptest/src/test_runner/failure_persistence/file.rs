@@ -31,6 +31,26 @@ use crate::test_runner::failure_persistence::{
 ///
 /// In all cases, if a derived path references a directory which does not yet
 /// exist, proptest will attempt to create all necessary parent directories.
+///
+/// ## File format
+///
+/// The file is line-oriented. Everything from a `#` to the end of a line is a
+/// comment, and blank lines are ignored. Each other line holds one seed, in
+/// the form shown by `PersistedSeed`, optionally followed by `key=value`
+/// fields. Unknown fields are reserved for future versions and ignored, and
+/// lines which cannot be parsed are reported and skipped.
+///
+/// When a test has a name, as given by `Config::test_name`, its seeds are
+/// saved with a comment starting `# test=<name>`, and are only replayed by
+/// that test. Since this is a comment, older versions of proptest still read
+/// these seeds, and replay them for every test. Seeds without a name, such as
+/// those saved by older versions of proptest or by tests without a name, are
+/// replayed by every test using the file.
+///
+/// New failures are appended to the end of the file, and the most recently
+/// added ones are replayed first. Since every seed is a single line,
+/// branches which each add failures can be merged by taking the lines of
+/// both, for example with `merge=union` in `.gitattributes`.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum FileFailurePersistence {
     /// Completely disables persistence of failing test cases.
@@ -84,6 +104,14 @@ impl FailurePersistence for FileFailurePersistence {
     fn load_persisted_failures2(
         &self,
         source_file: Option<&'static str>,
+    ) -> Vec<PersistedSeed> {
+        self.load_persisted_failures_for_test(source_file, None)
+    }
+
+    fn load_persisted_failures_for_test(
+        &self,
+        source_file: Option<&'static str>,
+        test_name: Option<&'static str>,
     ) -> Vec<PersistedSeed> {
         let p = self.resolve(
             source_file
@@ -103,7 +131,12 @@ impl FailurePersistence for FileFailurePersistence {
                     .enumerate()
                     .filter_map(|(lineno, line)| match line {
                         Err(err) => Some(Err(err)),
-                        Ok(line) => parse_seed_line(line, path, lineno).map(Ok),
+                        Ok(line) => {
+                            if !is_for_test(&line, test_name) {
+                                return None;
+                            }
+                            parse_seed_line(line, path, lineno).map(Ok)
+                        }
                     })
                     .collect()
             },
@@ -128,6 +161,21 @@ impl FailurePersistence for FileFailurePersistence {
         source_file: Option<&'static str>,
         seed: PersistedSeed,
         shrunken_value: &dyn Debug,
+    ) {
+        self.save_persisted_failure_for_test(
+            source_file,
+            None,
+            seed,
+            shrunken_value,
+        )
+    }
+
+    fn save_persisted_failure_for_test(
+        &mut self,
+        source_file: Option<&'static str>,
+        test_name: Option<&'static str>,
+        seed: PersistedSeed,
+        shrunken_value: &dyn Debug,
     ) {
         let path = self.resolve(source_file.map(Path::new));
         if let Some(path) = path {
@@ -141,7 +189,8 @@ impl FailurePersistence for FileFailurePersistence {
                     .expect("proptest: couldn't write header.");
             }
 
-            write_seed_line(&mut to_write, &seed, shrunken_value)
+            let entry = format_seed_entry(&seed, test_name);
+            write_seed_line(&mut to_write, &entry, test_name, shrunken_value)
                 .expect("proptest: couldn't write seed line.");
 
             if let Err(e) = write_seed_data_to_file(&path, &to_write) {
@@ -158,7 +207,7 @@ impl FailurePersistence for FileFailurePersistence {
                      {}",
                     path.display(),
                     if is_new { " (You may need to create it.)" } else { "" },
-                    seed);
+                    entry);
             }
         }
     }
@@ -250,17 +299,13 @@ fn absolutize_source_file_with_cwd<'a>(
 }
 
 fn parse_seed_line(
-    mut line: String,
+    line: String,
     path: &Path,
     lineno: usize,
 ) -> Option<PersistedSeed> {
-    // Remove anything after and including '#':
-    if let Some(comment_start) = line.find('#') {
-        line.truncate(comment_start);
-    }
-
-    if line.len() > 0 {
-        let ret = line.parse::<PersistedSeed>().ok();
+    let seed = seed_text(&line);
+    if !seed.is_empty() {
+        let ret = seed.parse::<PersistedSeed>().ok();
         if !ret.is_some() {
             eprintln!(
                 "proptest: {}:{}: unparsable line, ignoring",
@@ -274,17 +319,69 @@ fn parse_seed_line(
     None
 }
 
+/// Returns the seed on `line`, without any comment or `key=value` fields, or
+/// an empty string if the line holds no seed.
+fn seed_text(line: &str) -> String {
+    // Remove anything after and including '#':
+    let line = line.split('#').next().unwrap_or("");
+
+    line.split_whitespace()
+        .filter(|part| !part.contains('='))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the name of the test which saved the seed on `line`, if any. It is
+/// the first word of the comment, after `test=`, so that older versions of
+/// proptest ignore it.
+fn saved_test_name(line: &str) -> Option<&str> {
+    line.split_once('#')?
+        .1
+        .split_whitespace()
+        .next()?
+        .strip_prefix(TEST_NAME_PREFIX)
+}
+
+/// Returns whether the seed on `line` should be replayed by the test named
+/// `test_name`: seeds saved by other tests are not, while seeds saved without
+/// a test name are replayed by every test, as are all seeds when the running
+/// test has no name.
+fn is_for_test(line: &str, test_name: Option<&str>) -> bool {
+    match (saved_test_name(line), test_name) {
+        (Some(saved), Some(running)) => saved == running,
+        _ => true,
+    }
+}
+
+/// Precedes the name of the test which saved a seed in its comment.
+const TEST_NAME_PREFIX: &str = "test=";
+
+/// Formats `seed` with the comment naming the test which saved it, if any.
+fn format_seed_entry(
+    seed: &PersistedSeed,
+    test_name: Option<&'static str>,
+) -> String {
+    match test_name {
+        Some(name) => format!("{} # {}{}", seed, TEST_NAME_PREFIX, name),
+        None => seed.to_string(),
+    }
+}
+
 fn write_seed_line(
     buf: &mut Vec<u8>,
-    seed: &PersistedSeed,
+    entry: &str,
+    test_name: Option<&'static str>,
     shrunken_value: &dyn Debug,
 ) -> io::Result<()> {
-    // Write the seed itself
-    write!(buf, "{}", seed.to_string())?;
+    // Write the seed itself, and the name of the test if any
+    write!(buf, "{}", entry)?;
 
-    // Write out comment:
+    // Write out comment, which the test name has already started:
     let debug_start = buf.len();
-    write!(buf, " # shrinks to {:?}", shrunken_value)?;
+    if test_name.is_none() {
+        write!(buf, " #")?;
+    }
+    write!(buf, " shrinks to {:?}", shrunken_value)?;
 
     // Ensure there are no newlines in the debug output
     for byte in &mut buf[debug_start..] {
@@ -307,7 +404,9 @@ fn write_header(buf: &mut Vec<u8>) -> io::Result<()> {
 # novel cases are generated.
 #
 # It is recommended to check this file in to source control so that
-# everyone who runs the test benefits from these saved cases."
+# everyone who runs the test benefits from these saved cases. Each seed
+# is on its own line, so concurrent additions can be merged by keeping
+# both sides' lines (e.g. `merge=union` in `.gitattributes`)."
     )
 }
 
@@ -415,6 +514,7 @@ lazy_static! {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_runner::failure_persistence::tests::*;
 
     struct TestPaths {
         crate_root: &'static Path,
@@ -499,6 +599,47 @@ mod tests {
         assert_eq!(None, SourceParallel("ext").resolve(None));
     }
 
+    #[test]
+    fn unknown_fields_are_ignored() {
+        let path = Path::new("regressions.txt");
+        let line = format!("{} future=1 # shrinks to 0", INC_SEED);
+        assert_eq!(Some(INC_SEED), parse_seed_line(line, path, 0));
+        assert_eq!(None, parse_seed_line(" # comment".to_owned(), path, 1));
+    }
+
+    #[test]
+    fn seeds_are_replayed_by_the_test_which_saved_them() {
+        let path = env::temp_dir()
+            .join(format!("proptest-test-names-{}.txt", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let path: &'static str =
+            Box::leak(path.to_str().unwrap().to_owned().into_boxed_str());
+
+        let mut p = Direct(path);
+        p.save_persisted_failure_for_test(None, Some("a::b"), INC_SEED, &1);
+        p.save_persisted_failure2(None, INC_SEED, &2);
+        let contents = fs::read_to_string(path).unwrap();
+        // Seeds are appended in the order they were saved.
+        assert!(contents.ends_with(&format!(
+            "\n{0} # test=a::b shrinks to 1\n{0} # shrinks to 2\n",
+            INC_SEED
+        )));
+        // Older versions require exactly the seed before the comment.
+        for line in contents.lines().filter(|line| line.contains("test=")) {
+            assert_eq!(
+                INC_SEED.to_string(),
+                line.split('#').next().unwrap().trim()
+            );
+        }
+
+        let seeds =
+            |test_name| p.load_persisted_failures_for_test(None, test_name);
+        assert_eq!(2, seeds(Some("a::b")).len());
+        assert_eq!(1, seeds(Some("a::c")).len());
+        assert_eq!(2, seeds(None).len());
+        fs::remove_file(path).unwrap();
+    }
+
     #[test]
     fn relative_source_files_absolutified() {
         const TEST_RUNNER_PATH: &[&str] = &["src", "test_runner", "mod.rs"];
@@ -17,10 +17,16 @@ use crate::test_runner::failure_persistence::PersistedSeed;
 /// on the heap. This may be useful when accumulating test failures
 /// across multiple `TestRunner` instances for external reporting
 /// or batched persistence.
+///
+/// As with `FileFailurePersistence`, a seed saved by a named test is only
+/// replayed by that test, while seeds saved without a test name are replayed
+/// by every test in the same source file.
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct MapFailurePersistence {
-    /// Backing map, keyed by source_file.
-    pub map: BTreeMap<&'static str, BTreeSet<PersistedSeed>>,
+    /// Backing map, keyed by source_file and the name of the test which saved
+    /// the seeds, if any.
+    pub map:
+        BTreeMap<(&'static str, Option<&'static str>), BTreeSet<PersistedSeed>>,
 }
 
 impl FailurePersistence for MapFailurePersistence {
@@ -28,23 +34,59 @@ impl FailurePersistence for MapFailurePersistence {
         &self,
         source_file: Option<&'static str>,
     ) -> Vec<PersistedSeed> {
-        source_file
-            .and_then(|source| self.map.get(source))
-            .map(|seeds| seeds.iter().cloned().collect::<Vec<_>>())
-            .unwrap_or_default()
+        self.load_persisted_failures_for_test(source_file, None)
+    }
+
+    fn load_persisted_failures_for_test(
+        &self,
+        source_file: Option<&'static str>,
+        test_name: Option<&'static str>,
+    ) -> Vec<PersistedSeed> {
+        let source = match source_file {
+            Some(sf) => sf,
+            None => return Vec::new(),
+        };
+        self.map
+            .iter()
+            .filter(|&(&(sf, saved), _)| {
+                sf == source
+                    && match (saved, test_name) {
+                        (Some(saved), Some(running)) => saved == running,
+                        _ => true,
+                    }
+            })
+            .flat_map(|(_, seeds)| seeds.iter().cloned())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
     }
 
     fn save_persisted_failure2(
         &mut self,
         source_file: Option<&'static str>,
         seed: PersistedSeed,
+        shrunken_value: &dyn fmt::Debug,
+    ) {
+        self.save_persisted_failure_for_test(
+            source_file,
+            None,
+            seed,
+            shrunken_value,
+        )
+    }
+
+    fn save_persisted_failure_for_test(
+        &mut self,
+        source_file: Option<&'static str>,
+        test_name: Option<&'static str>,
+        seed: PersistedSeed,
         _shrunken_value: &dyn fmt::Debug,
     ) {
         let s = match source_file {
             Some(sf) => sf,
             None => return,
         };
-        let set = self.map.entry(s).or_insert_with(BTreeSet::new);
+        let set = self.map.entry((s, test_name)).or_insert_with(BTreeSet::new);
         set.insert(seed);
     }
 
@@ -68,6 +110,7 @@ impl FailurePersistence for MapFailurePersistence {
 mod tests {
     use super::*;
     use crate::test_runner::failure_persistence::tests::*;
+    use crate::test_runner::rng::Seed;
 
     #[test]
     fn initial_map_is_empty() {
@@ -96,4 +139,18 @@ mod tests {
         let restored = p.load_persisted_failures2(HI_PATH);
         assert_eq!(1, restored.len());
     }
+
+    #[test]
+    fn seeds_are_replayed_by_the_test_which_saved_them() {
+        let other_seed = PersistedSeed(Seed::XorShift([1; 16]));
+        let mut p = MapFailurePersistence::default();
+        p.save_persisted_failure_for_test(HI_PATH, Some("a::b"), INC_SEED, &"");
+        p.save_persisted_failure2(HI_PATH, other_seed.clone(), &"");
+
+        let seeds =
+            |test_name| p.load_persisted_failures_for_test(HI_PATH, test_name);
+        assert_eq!(2, seeds(Some("a::b")).len());
+        assert_eq!(vec![other_seed], seeds(Some("a::c")));
+        assert_eq!(2, seeds(None).len());
+    }
 }
@@ -18,10 +18,16 @@ use core::str::FromStr;
 mod file;
 mod map;
 mod noop;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod shared;
 
 #[cfg(feature = "std")]
 pub use self::file::*;
 pub use self::map::*;
+pub use self::noop::*;
+#[cfg(feature = "std")]
+pub use self::shared::*;
 
 use crate::test_runner::Seed;
 
@@ -48,6 +54,79 @@ impl FromStr for PersistedSeed {
 
 /// Provides external persistence for historical test failures by storing seeds.
 ///
+/// The backend used by a test is set with `Config::failure_persistence`.
+/// Proptest provides `FileFailurePersistence`, which keeps seeds in files
+/// alongside the source (the default), `MapFailurePersistence`, which keeps
+/// them in memory, `SharedFailurePersistence`, which shares one backend
+/// between the runs of a process, and `NoopFailurePersistence`, which keeps
+/// nothing.
+///
+/// Other places to keep seeds, such as a database, a bucket in an object
+/// store, or a directory outside the source tree where the sources are
+/// read-only, are supported by implementing this trait:
+///
+/// ```
+/// use std::any::Any;
+/// use std::fmt::Debug;
+/// use std::path::PathBuf;
+/// use std::{env, fs};
+///
+/// use proptest::test_runner::{Config, FailurePersistence, PersistedSeed};
+///
+/// /// Keeps seeds in a directory named by an environment variable, with one
+/// /// file per source file.
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct OutputDirPersistence(&'static str);
+///
+/// impl OutputDirPersistence {
+///     fn path(&self, source_file: Option<&'static str>) -> Option<PathBuf> {
+///         let dir = PathBuf::from(env::var_os(self.0)?);
+///         Some(dir.join(source_file?.replace(['/', '\\'], "_")))
+///     }
+/// }
+///
+/// impl FailurePersistence for OutputDirPersistence {
+///     fn load_persisted_failures2(
+///         &self,
+///         source_file: Option<&'static str>,
+///     ) -> Vec<PersistedSeed> {
+///         self.path(source_file)
+///             .and_then(|path| fs::read_to_string(path).ok())
+///             .map(|seeds| seeds.lines().filter_map(|l| l.parse().ok()).collect())
+///             .unwrap_or_default()
+///     }
+///
+///     fn save_persisted_failure2(
+///         &mut self,
+///         source_file: Option<&'static str>,
+///         seed: PersistedSeed,
+///         _shrunken_value: &dyn Debug,
+///     ) {
+///         if let Some(path) = self.path(source_file) {
+///             let mut seeds = fs::read_to_string(&path).unwrap_or_default();
+///             seeds.push_str(&format!("{}\n", seed));
+///             let _ = fs::write(path, seeds);
+///         }
+///     }
+///
+///     fn box_clone(&self) -> Box<dyn FailurePersistence> {
+///         Box::new(self.clone())
+///     }
+///
+///     fn eq(&self, other: &dyn FailurePersistence) -> bool {
+///         other.as_any().downcast_ref::<Self>() == Some(self)
+///     }
+///
+///     fn as_any(&self) -> &dyn Any {
+///         self
+///     }
+/// }
+///
+/// let config = Config::with_failure_persistence(
+///     OutputDirPersistence("PROPTEST_REGRESSIONS_DIR"),
+/// );
+/// ```
+///
 /// **Note**: Implementing `load_persisted_failures` and
 /// `save_persisted_failures` is **deprecated** and these methods will be
 /// removed in proptest 0.10.0. Instead, implement `load_persisted_failures2`
@@ -71,6 +150,25 @@ pub trait FailurePersistence: Send + Sync + fmt::Debug {
             .collect()
     }
 
+    /// Supply the seeds associated with the test named `test_name` in the
+    /// given `source_file`, along with any seeds saved without a test name.
+    ///
+    /// `test_name` is `Config::test_name`, which `proptest!` sets to the path
+    /// of the test function. A `TestRunner` loads seeds through this method,
+    /// so that a test does not replay the failures of the other tests in the
+    /// same file.
+    ///
+    /// The default implementation ignores `test_name` and delegates to
+    /// `load_persisted_failures2`.
+    fn load_persisted_failures_for_test(
+        &self,
+        source_file: Option<&'static str>,
+        test_name: Option<&'static str>,
+    ) -> Vec<PersistedSeed> {
+        let _ = test_name;
+        self.load_persisted_failures2(source_file)
+    }
+
     /// Use `load_persisted_failures2` instead.
     ///
     /// This function inadvertently exposes the implementation of seeds prior
@@ -103,6 +201,25 @@ pub trait FailurePersistence: Send + Sync + fmt::Debug {
         }
     }
 
+    /// Store a new failure-generating seed associated with the test named
+    /// `test_name` in the given `source_file`.
+    ///
+    /// A `TestRunner` saves seeds through this method; see
+    /// `load_persisted_failures_for_test`.
+    ///
+    /// The default implementation ignores `test_name` and delegates to
+    /// `save_persisted_failure2`.
+    fn save_persisted_failure_for_test(
+        &mut self,
+        source_file: Option<&'static str>,
+        test_name: Option<&'static str>,
+        seed: PersistedSeed,
+        shrunken_value: &dyn fmt::Debug,
+    ) {
+        let _ = test_name;
+        self.save_persisted_failure2(source_file, seed, shrunken_value)
+    }
+
     /// Use `save_persisted_failures2` instead.
     ///
     /// This function inadvertently exposes the implementation of seeds prior
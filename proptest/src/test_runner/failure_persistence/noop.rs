@@ -15,8 +15,11 @@ use crate::test_runner::failure_persistence::{
 };
 
 /// Failure persistence option that loads and saves nothing at all.
-#[derive(Debug, Default, PartialEq)]
-struct NoopFailurePersistence;
+///
+/// Unlike `FileFailurePersistence::Off`, this is available without the `std`
+/// feature.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct NoopFailurePersistence;
 
 impl FailurePersistence for NoopFailurePersistence {
     fn load_persisted_failures2(
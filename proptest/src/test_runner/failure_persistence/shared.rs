@@ -0,0 +1,140 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::any::Any;
+use core::fmt::Debug;
+use std::boxed::Box;
+use std::sync::{Arc, Mutex};
+use std::vec::Vec;
+
+use crate::test_runner::failure_persistence::{
+    FailurePersistence, PersistedSeed,
+};
+
+/// Failure persistence which shares one instance of another
+/// `FailurePersistence` between all its clones.
+///
+/// A `Config` is cloned for every test run, and its failure persistence
+/// with it, so a failure saved in a `MapFailurePersistence` is normally only
+/// seen by the run which saved it. Wrapping it here instead makes the
+/// failures saved by any run replay in every other run using a clone of the
+/// same `Config`, which gives in-memory persistence for the lifetime of the
+/// process, such as for doctests or on platforms without a file system.
+///
+/// This is also a convenient way to use backends which are expensive to
+/// create or should not be copied, such as one holding a connection to a
+/// database.
+///
+/// Two `SharedFailurePersistence` values are equal when they share the same
+/// instance.
+///
+/// ```
+/// use proptest::test_runner::{
+///     Config, MapFailurePersistence, SharedFailurePersistence,
+/// };
+///
+/// let config = Config::with_failure_persistence(
+///     SharedFailurePersistence::new(MapFailurePersistence::default()),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct SharedFailurePersistence {
+    inner: Arc<Mutex<Box<dyn FailurePersistence>>>,
+}
+
+impl SharedFailurePersistence {
+    /// Share `inner` between all clones of the returned value.
+    pub fn new(inner: impl FailurePersistence + 'static) -> Self {
+        SharedFailurePersistence {
+            inner: Arc::new(Mutex::new(Box::new(inner))),
+        }
+    }
+}
+
+impl FailurePersistence for SharedFailurePersistence {
+    fn load_persisted_failures2(
+        &self,
+        source_file: Option<&'static str>,
+    ) -> Vec<PersistedSeed> {
+        // Continue with the data of a run which panicked while saving
+        let inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.load_persisted_failures2(source_file)
+    }
+
+    fn save_persisted_failure2(
+        &mut self,
+        source_file: Option<&'static str>,
+        seed: PersistedSeed,
+        shrunken_value: &dyn Debug,
+    ) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.save_persisted_failure2(source_file, seed, shrunken_value)
+    }
+
+    fn load_persisted_failures_for_test(
+        &self,
+        source_file: Option<&'static str>,
+        test_name: Option<&'static str>,
+    ) -> Vec<PersistedSeed> {
+        let inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.load_persisted_failures_for_test(source_file, test_name)
+    }
+
+    fn save_persisted_failure_for_test(
+        &mut self,
+        source_file: Option<&'static str>,
+        test_name: Option<&'static str>,
+        seed: PersistedSeed,
+        shrunken_value: &dyn Debug,
+    ) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.save_persisted_failure_for_test(
+            source_file,
+            test_name,
+            seed,
+            shrunken_value,
+        )
+    }
+
+    fn box_clone(&self) -> Box<dyn FailurePersistence> {
+        Box::new(self.clone())
+    }
+
+    fn eq(&self, other: &dyn FailurePersistence) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<Self>()
+            .map_or(false, |x| Arc::ptr_eq(&x.inner, &self.inner))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_runner::failure_persistence::tests::*;
+    use crate::test_runner::MapFailurePersistence;
+
+    #[test]
+    fn clones_share_seeds() {
+        let p = SharedFailurePersistence::new(MapFailurePersistence::default());
+        let mut clone = p.box_clone();
+        clone.save_persisted_failure2(HI_PATH, INC_SEED, &"");
+        assert_eq!(vec![INC_SEED], p.load_persisted_failures2(HI_PATH));
+        assert!(p.load_persisted_failures2(UNREL_PATH).is_empty());
+
+        assert!(FailurePersistence::eq(&*clone, &p));
+        let other =
+            SharedFailurePersistence::new(MapFailurePersistence::default());
+        assert!(!FailurePersistence::eq(&*clone, &other));
+    }
+}
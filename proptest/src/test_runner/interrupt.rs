@@ -0,0 +1,116 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Support for interrupting shrinking.
+//!
+//! While any `TestRunner` in the process is shrinking, [`interrupt_shrinking`]
+//! makes all of them stop and report the smallest failing input found so
+//! far, which is persisted as usual. With the `ctrlc` feature, the runner
+//! calls it when Ctrl-C is pressed during shrinking.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// The number of runners currently shrinking.
+static SHRINKING: AtomicUsize = AtomicUsize::new(0);
+/// Whether the current shrinks have been asked to stop.
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Make every `TestRunner` which is currently shrinking stop and report the
+/// smallest failing input it has found so far, as if it had reached
+/// `Config::max_shrink_iters`.
+///
+/// Returns whether any runner was shrinking. The request applies only to the
+/// shrinks in progress: once they have all stopped, later shrinks run in
+/// full.
+///
+/// This allows a custom signal handler or watchdog to cut a long shrink
+/// short without losing the failure. With the `ctrlc` feature enabled, the
+/// runner installs a Ctrl-C handler which calls this when it first starts
+/// shrinking; pressing Ctrl-C while nothing is shrinking, or a second time,
+/// exits the process as usual.
+///
+/// ```
+/// use proptest::prelude::*;
+/// use proptest::test_runner::{interrupt_shrinking, TestError, TestRunner};
+///
+/// let mut runner = TestRunner::new(ProptestConfig {
+///     failure_persistence: None,
+///     ..ProptestConfig::default()
+/// });
+/// let result = runner.run(&(0u32..1_000_000), |v| {
+///     if v < 1000 {
+///         // Stands in for a signal arriving part way through shrinking.
+///         interrupt_shrinking();
+///     }
+///     prop_assert!(v < 10);
+///     Ok(())
+/// });
+/// match result {
+///     Err(TestError::Fail(_, v)) => assert!(v >= 10),
+///     result => panic!("unexpected result {:?}", result),
+/// }
+/// ```
+pub fn interrupt_shrinking() -> bool {
+    if SHRINKING.load(Ordering::SeqCst) == 0 {
+        return false;
+    }
+    INTERRUPTED.store(true, Ordering::SeqCst);
+    true
+}
+
+/// Returns whether the shrinks in progress have been asked to stop.
+pub(crate) fn is_interrupted() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+/// Marks a runner as shrinking for as long as it is alive.
+pub(crate) struct ShrinkGuard(());
+
+impl ShrinkGuard {
+    pub(crate) fn new() -> Self {
+        #[cfg(feature = "ctrlc")]
+        install_handler();
+        SHRINKING.fetch_add(1, Ordering::SeqCst);
+        ShrinkGuard(())
+    }
+}
+
+impl Drop for ShrinkGuard {
+    fn drop(&mut self) {
+        if SHRINKING.fetch_sub(1, Ordering::SeqCst) == 1 {
+            INTERRUPTED.store(false, Ordering::SeqCst);
+        }
+    }
+}
+
+/// Install the Ctrl-C handler, the first time this is called.
+#[cfg(feature = "ctrlc")]
+fn install_handler() {
+    use std::sync::Once;
+
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let result = ctrlc::set_handler(|| {
+            if is_interrupted() || !interrupt_shrinking() {
+                std::process::exit(130);
+            }
+            eprintln!(
+                "proptest: Interrupted, stopping shrinking. \
+                 Press Ctrl-C again to exit."
+            );
+        });
+        if let Err(e) = result {
+            eprintln!(
+                "proptest: Failed to install the Ctrl-C handler, so \
+                 shrinking cannot be interrupted: {}",
+                e
+            );
+        }
+    });
+}
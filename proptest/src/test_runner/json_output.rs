@@ -0,0 +1,263 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Machine-readable failure reports and case records.
+
+use core::fmt::{self, Write as _};
+use std::fs::OpenOptions;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::string::String;
+
+/// Where `TestRunner` writes machine-readable reports of test failures.
+///
+/// Each report is a single line holding a JSON object with the following
+/// fields:
+///
+/// - `test_name` and `source_file`: as in `Config`, or `null` if unset.
+/// - `reason`: the message the minimal failing input failed with.
+/// - `input`: the `Debug` representation of the minimal failing input. Inputs
+///   are not serialized with `serde`, since test values need not implement
+///   `Serialize`.
+/// - `seed`: the seed of the failing test case, in the form used by failure
+///   persistence files.
+/// - `rng_seed`: the seed of the whole run as reported by `TestRunner`, as a
+///   string of decimal digits, or `null` if the runner was given an RNG
+///   directly.
+/// - `successes`, `local_rejects` and `global_rejects`: the runner's counters
+///   when the failure was found.
+/// - `shrink_iterations`: the number of shrinking steps taken.
+///
+/// Further fields may be added in the future.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JsonOutput {
+    /// Write each report to standard error.
+    Stderr,
+    /// Append each report to the file at the given path, creating it if it
+    /// does not exist.
+    File(PathBuf),
+}
+
+impl FromStr for JsonOutput {
+    type Err = ();
+
+    /// Parses `stderr` as `JsonOutput::Stderr` and anything else as a path.
+    fn from_str(s: &str) -> Result<Self, ()> {
+        Ok(match s {
+            "stderr" => JsonOutput::Stderr,
+            path => JsonOutput::File(PathBuf::from(path)),
+        })
+    }
+}
+
+impl fmt::Display for JsonOutput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            JsonOutput::Stderr => write!(f, "stderr"),
+            JsonOutput::File(ref path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// The contents of one failure report.
+#[derive(Debug)]
+pub(crate) struct FailureReport<'a> {
+    pub(crate) test_name: Option<&'a str>,
+    pub(crate) source_file: Option<&'a str>,
+    pub(crate) reason: &'a str,
+    pub(crate) input: String,
+    pub(crate) seed: String,
+    pub(crate) rng_seed: Option<u64>,
+    pub(crate) successes: u32,
+    pub(crate) local_rejects: u32,
+    pub(crate) global_rejects: u32,
+    pub(crate) shrink_iterations: u32,
+}
+
+impl FailureReport<'_> {
+    pub(crate) fn to_json(&self) -> String {
+        let mut json = String::from("{");
+        json.push_str("\"test_name\":");
+        push_opt_str(&mut json, self.test_name);
+        json.push_str(",\"source_file\":");
+        push_opt_str(&mut json, self.source_file);
+        json.push_str(",\"reason\":");
+        push_str(&mut json, self.reason);
+        json.push_str(",\"input\":");
+        push_str(&mut json, &self.input);
+        json.push_str(",\"seed\":");
+        push_str(&mut json, &self.seed);
+        json.push_str(",\"rng_seed\":");
+        // As a string, since JSON numbers beyond 2^53 lose precision in many
+        // parsers.
+        push_opt_str(
+            &mut json,
+            self.rng_seed.map(|seed| format!("{}", seed)).as_deref(),
+        );
+        let _ = write!(
+            json,
+            ",\"successes\":{},\"local_rejects\":{},\
+             \"global_rejects\":{},\"shrink_iterations\":{}}}",
+            self.successes,
+            self.local_rejects,
+            self.global_rejects,
+            self.shrink_iterations
+        );
+        json
+    }
+
+    /// Write the report to `output`, warning on stderr if that fails.
+    pub(crate) fn write(&self, output: &JsonOutput) {
+        let json = self.to_json();
+        match *output {
+            JsonOutput::Stderr => eprintln!("{}", json),
+            JsonOutput::File(ref path) => {
+                append_line(path, &json, "failure report")
+            }
+        }
+    }
+}
+
+/// The record of one generated test case, as written to
+/// `Config::record_cases`.
+#[derive(Debug)]
+pub(crate) struct CaseRecord<'a> {
+    pub(crate) test_name: Option<&'a str>,
+    pub(crate) input: String,
+    pub(crate) outcome: &'a str,
+    pub(crate) reason: Option<&'a str>,
+}
+
+impl CaseRecord<'_> {
+    pub(crate) fn to_json(&self) -> String {
+        let mut json = String::from("{");
+        json.push_str("\"test_name\":");
+        push_opt_str(&mut json, self.test_name);
+        json.push_str(",\"input\":");
+        push_str(&mut json, &self.input);
+        json.push_str(",\"outcome\":");
+        push_str(&mut json, self.outcome);
+        json.push_str(",\"reason\":");
+        push_opt_str(&mut json, self.reason);
+        json.push('}');
+        json
+    }
+
+    /// Append the record to the file at `path`, warning on stderr if that
+    /// fails.
+    pub(crate) fn write(&self, path: &Path) {
+        append_line(path, &self.to_json(), "case record");
+    }
+}
+
+/// Append `line` to the file at `path`, creating it if it does not exist, and
+/// warn on stderr if that fails.
+fn append_line(path: &Path, line: &str, what: &str) {
+    let result = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(format!("{}\n", line).as_bytes()));
+    if let Err(e) = result {
+        eprintln!(
+            "proptest: failed to write {} to {}: {}",
+            what,
+            path.display(),
+            e
+        );
+    }
+}
+
+fn push_opt_str(json: &mut String, s: Option<&str>) {
+    match s {
+        Some(s) => push_str(json, s),
+        None => json.push_str("null"),
+    }
+}
+
+fn push_str(json: &mut String, s: &str) {
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c < ' ' => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn report_is_escaped_json() {
+        let report = FailureReport {
+            test_name: Some("crate::tests::parse"),
+            source_file: None,
+            reason: "assertion failed: \"a\"\n\tat src/lib.rs",
+            input: "\"\\u{1}\"".into(),
+            seed: "cc 00ff".into(),
+            rng_seed: Some(42),
+            successes: 3,
+            local_rejects: 0,
+            global_rejects: 1,
+            shrink_iterations: 7,
+        };
+
+        assert_eq!(
+            "{\"test_name\":\"crate::tests::parse\",\"source_file\":null,\
+             \"reason\":\"assertion failed: \\\"a\\\"\\n\\tat src/lib.rs\",\
+             \"input\":\"\\\"\\\\u{1}\\\"\",\"seed\":\"cc 00ff\",\
+             \"rng_seed\":\"42\",\"successes\":3,\"local_rejects\":0,\
+             \"global_rejects\":1,\"shrink_iterations\":7}",
+            report.to_json()
+        );
+    }
+
+    #[test]
+    fn control_characters_are_escaped() {
+        let mut json = String::new();
+        push_str(&mut json, "\u{1}\u{7f}é");
+        assert_eq!("\"\\u0001\u{7f}é\"", json);
+    }
+
+    #[test]
+    fn case_record_is_escaped_json() {
+        let record = CaseRecord {
+            test_name: None,
+            input: "(1, \"a\")".into(),
+            outcome: "rejected",
+            reason: Some("odd\n"),
+        };
+
+        assert_eq!(
+            "{\"test_name\":null,\"input\":\"(1, \\\"a\\\")\",\
+             \"outcome\":\"rejected\",\"reason\":\"odd\\n\"}",
+            record.to_json()
+        );
+    }
+
+    #[test]
+    fn output_parses_stderr_or_path() {
+        assert_eq!(Ok(JsonOutput::Stderr), "stderr".parse());
+        assert_eq!(
+            Ok(JsonOutput::File(PathBuf::from("target/failures.jsonl"))),
+            "target/failures.jsonl".parse()
+        );
+    }
+}
@@ -12,21 +12,51 @@
 //! You do not normally need to access things in this module directly except
 //! when implementing new low-level strategies.
 
+#[cfg(feature = "std")]
+mod classify;
 mod config;
+#[cfg(feature = "std")]
+mod coverage;
 mod errors;
+#[cfg(feature = "std")]
+mod executor;
 mod failure_persistence;
+#[cfg(feature = "std")]
+mod interrupt;
+#[cfg(feature = "std")]
+mod json_output;
+#[cfg(feature = "std")]
+mod observer;
+#[cfg(feature = "std")]
+mod parallel;
 mod reason;
 #[cfg(feature = "fork")]
 mod replay;
 mod result_cache;
 mod rng;
 mod runner;
-mod scoped_panic_hook;
+pub(crate) mod scoped_panic_hook;
+#[cfg(feature = "std")]
+mod target;
 
+#[cfg(feature = "std")]
+pub use self::classify::classify;
 pub use self::config::*;
+#[cfg(feature = "std")]
+pub use self::coverage::CoverageFeedback;
 pub use self::errors::*;
+#[cfg(feature = "std")]
+pub use self::executor::*;
 pub use self::failure_persistence::*;
+#[cfg(feature = "std")]
+pub use self::interrupt::interrupt_shrinking;
+#[cfg(feature = "std")]
+pub use self::json_output::JsonOutput;
+#[cfg(feature = "std")]
+pub use self::observer::TestObserver;
 pub use self::reason::*;
 pub use self::result_cache::*;
 pub use self::rng::*;
 pub use self::runner::*;
+#[cfg(feature = "std")]
+pub use self::target::target;
@@ -0,0 +1,56 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Support for observing the progress of test runs.
+
+use core::fmt;
+
+use crate::test_runner::{Reason, TestError};
+
+/// Receives notifications about the progress of a test run.
+///
+/// An observer is installed with `Config::observer`. A new observer is
+/// created at the start of each call to `TestRunner::run` and is notified of
+/// every test case the runner executes, including those replayed from
+/// persisted failures, until the run finishes. This makes it possible to
+/// build progress bars, logging or metrics on top of proptest.
+///
+/// Every method has an empty default implementation, so implementations only
+/// need to override the events they are interested in.
+///
+/// When forking is enabled, test cases are executed in a child process, which
+/// has observers of its own. The observer in the parent process is still
+/// notified of every test case as the child's results are replayed.
+pub trait TestObserver: Send + Sync + fmt::Debug {
+    /// Called with each newly generated input, before the test is run on it.
+    fn case_generated(&mut self, _value: &dyn fmt::Debug) {}
+
+    /// Called after the test passes for the most recently generated input.
+    fn case_passed(&mut self) {}
+
+    /// Called after the test rejects the most recently generated input, with
+    /// the reason for the rejection.
+    fn case_rejected(&mut self, _whence: &Reason) {}
+
+    /// Called after each attempt to shrink a failing input, with the input
+    /// which was tried and whether the test still failed with it.
+    fn shrink_step(&mut self, _value: &dyn fmt::Debug, _failed: bool) {}
+
+    /// Called each time shrinking finds a simpler input with which the test
+    /// still fails, with that input and the number of shrink steps taken so
+    /// far.
+    ///
+    /// Unlike `shrink_step`, this is only called when shrinking makes
+    /// progress, so the value passed is always the smallest failing input
+    /// found so far. This suits progress bars and logs for long shrinks.
+    fn shrink_progress(&mut self, _value: &dyn fmt::Debug, _step: u32) {}
+
+    /// Called once when the run completes, with its overall result.
+    fn finished(&mut self, _result: &Result<(), TestError<&dyn fmt::Debug>>) {}
+}
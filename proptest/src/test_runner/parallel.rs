@@ -0,0 +1,137 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Support for running test cases on several threads.
+//!
+//! Inputs are still generated on the runner's own thread, from seeds drawn
+//! from a dedicated RNG, so that a run is as reproducible as a serial one.
+//! Only the test function itself runs on the worker threads. The runner then
+//! processes the cases in order, using the precomputed results in place of
+//! running the test again, so that shrinking, failure persistence and so on
+//! work exactly as they do for serial runs.
+
+use std::collections::VecDeque;
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use std::vec::Vec;
+
+use crate::strategy::{Strategy, ValueTree};
+use crate::test_runner::rng::Seed;
+use crate::test_runner::runner::CaseSource;
+use crate::test_runner::scoped_panic_hook;
+use crate::test_runner::{TestCaseResult, TestRng, TestRunner};
+
+/// The number of test cases each thread is given at a time.
+const CASES_PER_THREAD: usize = 4;
+
+/// Generates batches of test cases and runs them on a pool of scoped threads.
+pub(crate) struct ParallelCases<'a, S, F> {
+    strategy: &'a S,
+    test: &'a F,
+    threads: usize,
+    seeds: TestRng,
+    pending: VecDeque<(Seed, Option<TestCaseResult>)>,
+}
+
+impl<'a, S, F> ParallelCases<'a, S, F>
+where
+    S: Strategy,
+    S::Value: Send,
+    F: Fn(S::Value) -> TestCaseResult + Sync,
+{
+    pub(crate) fn new(
+        strategy: &'a S,
+        test: &'a F,
+        threads: usize,
+        seeds: TestRng,
+    ) -> Self {
+        ParallelCases {
+            strategy,
+            test,
+            threads,
+            seeds,
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn fill(&mut self, runner: &TestRunner) {
+        // Generate on a copy of the runner so that its own state, such as its
+        // count of local rejections, is only updated when it processes each
+        // case itself.
+        let mut generator = runner.clone();
+        let mut seeds = Vec::new();
+        let mut inputs = Vec::new();
+        for _ in 0..self.threads * CASES_PER_THREAD {
+            let seed = self.seeds.new_rng_seed();
+            generator.rng().set_seed(seed.clone());
+            inputs.push(
+                self.strategy
+                    .new_tree(&mut generator)
+                    .ok()
+                    .map(|tree| tree.current()),
+            );
+            seeds.push(seed);
+        }
+
+        let test = self.test;
+        let results = thread::scope(|scope| {
+            let mut workers = Vec::new();
+            while !inputs.is_empty() {
+                let len = CASES_PER_THREAD.min(inputs.len());
+                let rest = inputs.split_off(len);
+                let chunk = core::mem::replace(&mut inputs, rest);
+                workers.push(scope.spawn(move || {
+                    chunk
+                        .into_iter()
+                        .map(|input| input.and_then(|input| run(test, input)))
+                        .collect::<Vec<_>>()
+                }));
+            }
+
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        self.pending.extend(seeds.into_iter().zip(results));
+    }
+}
+
+/// Run `test` on `input`, or return `None` if it panics.
+///
+/// Panicking cases are run again by the runner itself, which turns the panic
+/// into a failure in the usual way.
+fn run<V, F: Fn(V) -> TestCaseResult>(
+    test: &F,
+    input: V,
+) -> Option<TestCaseResult> {
+    scoped_panic_hook::with_hook(
+        |_| { /* Silence out panic backtrace */ },
+        || panic::catch_unwind(AssertUnwindSafe(|| test(input))),
+    )
+    .ok()
+}
+
+impl<S, F> CaseSource for ParallelCases<'_, S, F>
+where
+    S: Strategy,
+    S::Value: Send,
+    F: Fn(S::Value) -> TestCaseResult + Sync,
+{
+    fn next_case(
+        &mut self,
+        runner: &TestRunner,
+    ) -> (Seed, Option<TestCaseResult>) {
+        if self.pending.is_empty() {
+            self.fill(runner);
+        }
+        self.pending.pop_front().unwrap()
+    }
+}
@@ -7,7 +7,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::std_facade::{Arc, String, ToOwned, Vec};
+use crate::std_facade::{Arc, Box, String, ToOwned, Vec};
+use core::any::Any;
 use core::result::Result;
 use core::{fmt, str, u8, convert::TryInto};
 use crate::test_runner::{config, RngSeed};
@@ -124,6 +125,62 @@ enum TestRngImpl {
         rng: ChaChaRng,
         record: Vec<u8>,
     },
+    Custom(Box<dyn CustomRng>),
+}
+
+/// Object-safe interface to a user-provided RNG type.
+trait CustomRng: RngCore + fmt::Debug + Send + Sync {
+    fn clone_box(&self) -> Box<dyn CustomRng>;
+    fn seed_len(&self) -> usize;
+    fn new_seed(&mut self) -> Vec<u8>;
+    fn reseeded(&self, seed: &[u8]) -> Box<dyn CustomRng>;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<R> CustomRng for R
+where
+    R: RngCore + SeedableRng + Clone + fmt::Debug + Send + Sync + 'static,
+{
+    fn clone_box(&self) -> Box<dyn CustomRng> {
+        Box::new(self.clone())
+    }
+
+    fn seed_len(&self) -> usize {
+        R::Seed::default().as_mut().len()
+    }
+
+    fn new_seed(&mut self) -> Vec<u8> {
+        let mut seed = R::Seed::default();
+        self.fill_bytes(seed.as_mut());
+
+        // As with XorShift, some algorithms would produce an RNG identical
+        // to this one if seeded directly from its output, so perturb it.
+        for (byte, perturb) in seed
+            .as_mut()
+            .iter_mut()
+            .zip([0xef, 0xbe, 0xad, 0xde].iter().cycle())
+        {
+            *byte ^= perturb;
+        }
+
+        seed.as_mut().to_vec()
+    }
+
+    fn reseeded(&self, seed: &[u8]) -> Box<dyn CustomRng> {
+        let mut buf = R::Seed::default();
+        buf.as_mut().copy_from_slice(seed);
+        Box::new(R::from_seed(buf))
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Clone for Box<dyn CustomRng> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
 }
 
 impl RngCore for TestRng {
@@ -147,6 +204,8 @@ impl RngCore for TestRng {
                 record.extend_from_slice(&read.to_le_bytes());
                 read
             }
+
+            &mut TestRngImpl::Custom(ref mut rng) => rng.next_u32(),
         }
     }
 
@@ -170,6 +229,8 @@ impl RngCore for TestRng {
                 record.extend_from_slice(&read.to_le_bytes());
                 read
             }
+
+            &mut TestRngImpl::Custom(ref mut rng) => rng.next_u64(),
         }
     }
 
@@ -201,6 +262,8 @@ impl RngCore for TestRng {
                 record.extend_from_slice(&dest);
                 res
             }
+
+            &mut TestRngImpl::Custom(ref mut rng) => rng.fill_bytes(dest),
         }
     }
 
@@ -225,6 +288,8 @@ impl RngCore for TestRng {
                 }
                 res
             }
+
+            TestRngImpl::Custom(ref mut rng) => rng.try_fill_bytes(dest),
         }
     }
 }
@@ -235,8 +300,13 @@ pub(crate) enum Seed {
     ChaCha([u8; 32]),
     PassThrough(Option<(usize, usize)>, Arc<[u8]>),
     Recorder([u8; 32]),
+    Custom(Arc<[u8]>),
 }
 
+/// The persistence key for seeds of custom RNGs. This is not an
+/// `RngAlgorithm` since such RNGs cannot be created from configuration.
+const CUSTOM_PERSISTENCE_KEY: &str = "cu";
+
 impl Seed {
     pub(crate) fn from_bytes(algorithm: RngAlgorithm, seed: &[u8]) -> Self {
         match algorithm {
@@ -286,6 +356,20 @@ impl Seed {
 
         let parts =
             string.trim().split(char::is_whitespace).collect::<Vec<_>>();
+        if CUSTOM_PERSISTENCE_KEY == parts[0] {
+            if 1 == parts.len() {
+                return Some(Seed::Custom(vec![].into()));
+            }
+
+            if 2 != parts.len() {
+                return None;
+            }
+
+            let mut seed = vec![0u8; parts[1].len() / 2];
+            from_base16(&mut seed, parts[1])?;
+            return Some(Seed::Custom(seed.into()));
+        }
+
         RngAlgorithm::from_persistence_key(&parts[0]).and_then(
             |alg| match alg {
                 RngAlgorithm::XorShift => {
@@ -396,10 +480,71 @@ impl Seed {
                 to_base16(&mut string, seed);
                 string
             }
+
+            Seed::Custom(ref seed) => {
+                let mut string = CUSTOM_PERSISTENCE_KEY.to_owned();
+                string.push(' ');
+                to_base16(&mut string, seed);
+                string
+            }
         }
     }
 }
 
+/// The state of the generator used when the operating system's entropy
+/// source is unavailable.
+#[cfg(feature = "std")]
+static FALLBACK_ENTROPY: std::sync::Mutex<u64> =
+    std::sync::Mutex::new(0x853c_49e6_748f_ea9b);
+
+/// Seed the generator which `RngSeed::Random` falls back to when the
+/// operating system's entropy source is unavailable.
+///
+/// This is always the case on `wasm32` targets, such as
+/// `wasm32-unknown-unknown` in a browser, which may not have an entropy
+/// source at all. Without calling this, every run there uses the same
+/// sequence of seeds. Seeding it once at start-up with something which
+/// varies, such as the current time from JavaScript's `Date.now()`, gives
+/// each run different inputs. The seed each runner used is still reported
+/// when a test fails, so that the run can be repeated by setting
+/// `Config::rng_seed`.
+///
+/// This is only available with the `std` feature.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn seed_fallback_entropy(seed: u64) {
+    *FALLBACK_ENTROPY.lock().unwrap_or_else(|e| e.into_inner()) = seed;
+}
+
+/// Returns a seed for `RngSeed::Random`, from the operating system's entropy
+/// source if possible, and otherwise from the generator seeded by
+/// `seed_fallback_entropy`.
+#[cfg(feature = "std")]
+pub(crate) fn random_seed() -> u64 {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let mut bytes = [0u8; 8];
+        if getrandom::getrandom(&mut bytes).is_ok() {
+            return u64::from_le_bytes(bytes);
+        }
+    }
+
+    fallback_seed()
+}
+
+/// Returns the next seed from the generator seeded by
+/// `seed_fallback_entropy`.
+#[cfg(feature = "std")]
+fn fallback_seed() -> u64 {
+    // SplitMix64, which produces well-distributed output from a counter.
+    let mut state = FALLBACK_ENTROPY.lock().unwrap_or_else(|e| e.into_inner());
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
 impl TestRng {
     /// Create a new RNG with the given algorithm and seed.
     ///
@@ -414,6 +559,35 @@ impl TestRng {
         TestRng::from_seed_internal(Seed::from_bytes(algorithm, seed))
     }
 
+    /// Create a new RNG which draws its values from `rng`.
+    ///
+    /// This allows using an RNG algorithm other than those proptest supports
+    /// directly, such as a counter-based RNG. Any RNGs derived from the
+    /// result (for example, the RNG passed to `prop_perturb`) use the same
+    /// algorithm, seeded from `rng`'s output. The underlying RNG can be
+    /// accessed with `custom_rng_mut`.
+    ///
+    /// Failures found with a custom RNG are persisted with the seed the
+    /// failing case was generated from, and are only replayed by test runners
+    /// which use a custom RNG with the same seed size.
+    pub fn custom<R>(rng: R) -> Self
+    where
+        R: RngCore + SeedableRng + Clone + fmt::Debug + Send + Sync + 'static,
+    {
+        TestRng {
+            rng: TestRngImpl::Custom(Box::new(rng)),
+        }
+    }
+
+    /// Return the underlying RNG if this was created with `custom` from an
+    /// RNG of type `R`.
+    pub fn custom_rng_mut<R: 'static>(&mut self) -> Option<&mut R> {
+        match self.rng {
+            TestRngImpl::Custom(ref mut rng) => rng.as_any_mut().downcast_mut(),
+            _ => None,
+        }
+    }
+
     /// Dumps the bytes obtained from the RNG so far (only works if the RNG is
     /// set to `Recorder`).
     ///
@@ -435,14 +609,14 @@ impl TestRng {
                 rng: match algorithm {
                     RngAlgorithm::XorShift => {
                         let rng = match seed {
-                            RngSeed::Random => XorShiftRng::from_entropy(),
+                            RngSeed::Random => XorShiftRng::seed_from_u64(random_seed()),
                             RngSeed::Fixed(seed) => XorShiftRng::seed_from_u64(seed),
                         };
                         TestRngImpl::XorShift(rng)
                     }
                     RngAlgorithm::ChaCha => {
                         let rng = match seed {
-                            RngSeed::Random => ChaChaRng::from_entropy(),
+                            RngSeed::Random => ChaChaRng::seed_from_u64(random_seed()),
                             RngSeed::Fixed(seed) => ChaChaRng::seed_from_u64(seed),
                         };
                         TestRngImpl::ChaCha(rng)
@@ -452,7 +626,7 @@ impl TestRng {
                     }
                     RngAlgorithm::Recorder => {
                         let rng =  match seed {
-                            RngSeed::Random => ChaChaRng::from_entropy(),
+                            RngSeed::Random => ChaChaRng::seed_from_u64(random_seed()),
                             RngSeed::Fixed(seed) => ChaChaRng::seed_from_u64(seed),
                         };
                         TestRngImpl::Recorder {rng, record: Vec::new()}
@@ -566,12 +740,27 @@ impl TestRng {
     /// Construct a TestRng by the perturbed randomized seed
     /// from an existing TestRng.
     pub(crate) fn gen_rng(&mut self) -> Self {
-        Self::from_seed_internal(self.new_rng_seed())
+        let seed = self.new_rng_seed();
+        self.with_seed(seed)
     }
 
     /// Overwrite the given TestRng with the provided seed.
     pub(crate) fn set_seed(&mut self, seed: Seed) {
-        *self = Self::from_seed_internal(seed);
+        *self = self.with_seed(seed);
+    }
+
+    /// Return whether `set_seed()` can be called with `seed`.
+    ///
+    /// Seeds for custom RNGs can only be used with a custom RNG whose seeds
+    /// are the same size.
+    pub(crate) fn accepts_seed(&self, seed: &Seed) -> bool {
+        match (seed, &self.rng) {
+            (Seed::Custom(seed), TestRngImpl::Custom(rng)) => {
+                seed.len() == rng.seed_len()
+            }
+            (Seed::Custom(_), _) => false,
+            _ => true,
+        }
     }
 
     /// Generate a new randomized seed, set it to this TestRng,
@@ -582,7 +771,25 @@ impl TestRng {
         seed
     }
 
-    /// Randomize a perturbed randomized seed from the given TestRng.
+    /// Return a seed for just the bytes read so far, if this is a
+    /// `PassThrough` RNG which has not split off any child RNGs.
+    ///
+    /// Child RNGs are given the second half of the remaining buffer, so once
+    /// one is created the whole buffer is needed to replay the same values.
+    /// Otherwise, the bytes after those already read are never used, and
+    /// reading zeroes in their place instead gives the same values.
+    #[cfg(feature = "std")]
+    pub(crate) fn consumed_seed(&self) -> Option<Seed> {
+        match self.rng {
+            TestRngImpl::PassThrough { off, end, ref data }
+                if end == data.len() =>
+            {
+                Some(Seed::PassThrough(Some((0, off)), Arc::clone(data)))
+            }
+            _ => None,
+        }
+    }
+
     pub(crate) fn new_rng_seed(&mut self) -> Seed {
         match self.rng {
             TestRngImpl::XorShift(ref mut rng) => {
@@ -621,6 +828,21 @@ impl TestRng {
             TestRngImpl::Recorder { ref mut rng, .. } => {
                 Seed::Recorder(rng.gen())
             }
+
+            TestRngImpl::Custom(ref mut rng) => {
+                Seed::Custom(rng.new_seed().into())
+            }
+        }
+    }
+
+    /// Construct a TestRng from a given seed, using the same custom RNG
+    /// algorithm as this one if the seed is for a custom RNG.
+    fn with_seed(&self, seed: Seed) -> Self {
+        match (seed, &self.rng) {
+            (Seed::Custom(seed), TestRngImpl::Custom(rng)) => Self {
+                rng: TestRngImpl::Custom(rng.reseeded(&seed)),
+            },
+            (seed, _) => Self::from_seed_internal(seed),
         }
     }
 
@@ -649,6 +871,10 @@ impl TestRng {
                     rng: ChaChaRng::from_seed(seed),
                     record: Vec::new(),
                 },
+
+                Seed::Custom(_) => {
+                    panic!("custom RNG seed used without a custom RNG")
+                }
             },
         }
     }
@@ -672,6 +898,7 @@ mod test {
                 any::<[u8;32]>().prop_map(Seed::ChaCha),
                 any::<Vec<u8>>().prop_map(|data| Seed::PassThrough(None, data.into())),
                 any::<[u8;32]>().prop_map(Seed::Recorder),
+                any::<Vec<u8>>().prop_map(|data| Seed::Custom(data.into())),
             ])
         {
             assert_eq!(seed, Seed::from_persistence(&seed.to_persistence()).unwrap());
@@ -718,6 +945,30 @@ mod test {
         }
     }
 
+    #[test]
+    fn custom_rng_is_used_by_derived_rngs() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaChaRng;
+        use rand_xorshift::XorShiftRng;
+
+        let mut rng = TestRng::custom(XorShiftRng::seed_from_u64(42));
+        let mut expected = XorShiftRng::seed_from_u64(42);
+        assert_eq!(expected.next_u64(), rng.next_u64());
+
+        let mut child = rng.gen_rng();
+        assert!(child.custom_rng_mut::<XorShiftRng>().is_some());
+        assert!(child.custom_rng_mut::<ChaChaRng>().is_none());
+        assert_ne!(rng.gen::<[u8; 32]>(), child.gen::<[u8; 32]>());
+
+        let seed = rng.gen_get_seed();
+        assert!(rng.accepts_seed(&seed));
+        assert!(!TestRng::deterministic_rng(RngAlgorithm::ChaCha)
+            .accepts_seed(&seed));
+        let mut replayed = TestRng::custom(XorShiftRng::seed_from_u64(0));
+        replayed.set_seed(seed);
+        assert_eq!(rng.gen::<[u8; 32]>(), replayed.gen::<[u8; 32]>());
+    }
+
     #[test]
     fn passthrough_rng_behaves_properly() {
         let mut rng = TestRng::from_seed(
@@ -737,4 +988,15 @@ mod test {
         rng.try_fill_bytes(&mut buf[0..4]).unwrap();
         assert_eq!([0, 0, 0, 0], buf);
     }
+
+    #[test]
+    fn fallback_entropy_is_seedable() {
+        use super::{fallback_seed, seed_fallback_entropy};
+
+        seed_fallback_entropy(42);
+        let first = (fallback_seed(), fallback_seed());
+        seed_fallback_entropy(42);
+        assert_eq!(first, (fallback_seed(), fallback_seed()));
+        assert_ne!(first.0, first.1);
+    }
 }
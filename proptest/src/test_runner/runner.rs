@@ -8,6 +8,8 @@
 // except according to those terms.
 
 use crate::std_facade::{Arc, BTreeMap, Box, String, Vec};
+#[cfg(feature = "std")]
+use core::future::Future;
 use core::sync::atomic::AtomicUsize;
 use core::sync::atomic::Ordering::SeqCst;
 use core::{fmt, iter};
@@ -26,14 +28,33 @@ use std::fs;
 use tempfile;
 
 use crate::strategy::*;
+#[cfg(feature = "std")]
+use crate::test_runner::classify;
 use crate::test_runner::config::*;
+#[cfg(feature = "std")]
+use crate::test_runner::coverage::CoverageSearch;
 use crate::test_runner::errors::*;
+#[cfg(feature = "std")]
+use crate::test_runner::executor::Executor;
 use crate::test_runner::failure_persistence::PersistedSeed;
+#[cfg(feature = "std")]
+use crate::test_runner::interrupt::{self, ShrinkGuard};
+#[cfg(feature = "std")]
+use crate::test_runner::json_output::{CaseRecord, FailureReport};
+#[cfg(feature = "std")]
+use crate::test_runner::parallel::ParallelCases;
 use crate::test_runner::reason::*;
 #[cfg(feature = "fork")]
 use crate::test_runner::replay;
 use crate::test_runner::result_cache::*;
-use crate::test_runner::rng::TestRng;
+#[cfg(feature = "std")]
+use crate::test_runner::rng::random_seed;
+use crate::test_runner::rng::Seed;
+use crate::test_runner::rng::{RngAlgorithm, TestRng};
+#[cfg(feature = "std")]
+use crate::test_runner::target::{self, TargetSearch};
+#[cfg(feature = "std")]
+use crate::test_runner::TestObserver;
 
 #[cfg(feature = "fork")]
 const ENV_FORK_FILE: &'static str = "_PROPTEST_FORKFILE";
@@ -64,34 +85,130 @@ macro_rules! verbose_message {
     };
 }
 
+/// Emits a `tracing` event at the given level, if the `tracing` feature is
+/// enabled.
+macro_rules! trace_event {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        tracing::event!(tracing::Level::$level, $($arg)+);
+    };
+}
+
 type RejectionDetail = BTreeMap<Reason, u32>;
 
+/// A strategy for choosing the seeds of new test cases based on feedback from
+/// earlier ones.
+#[cfg(feature = "std")]
+enum SeedSearch {
+    Target(TargetSearch),
+    Coverage(CoverageSearch),
+}
+
+#[cfg(feature = "std")]
+impl SeedSearch {
+    fn next_seed(&mut self) -> Seed {
+        match *self {
+            SeedSearch::Target(ref mut search) => {
+                let _ = target::take_utility();
+                search.next_seed()
+            }
+            SeedSearch::Coverage(ref mut search) => search.next_seed(),
+        }
+    }
+
+    /// Feed back the outcome of the last test case, returning whether the
+    /// search made progress.
+    fn observe(&mut self) -> bool {
+        match *self {
+            SeedSearch::Target(ref mut search) => {
+                search.observe(target::take_utility())
+            }
+            SeedSearch::Coverage(ref mut search) => search.observe(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for SeedSearch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SeedSearch::Target(ref search) => {
+                write!(f, "new best target utility {:?}", search.best())
+            }
+            SeedSearch::Coverage(ref search) => write!(
+                f,
+                "new coverage reached, corpus size {}",
+                search.corpus_len()
+            ),
+        }
+    }
+}
+
 /// State used when running a proptest test.
-#[derive(Clone)]
 pub struct TestRunner {
     config: Config,
     successes: u32,
     local_rejects: u32,
     global_rejects: u32,
     rng: TestRng,
+    rng_seed: Option<u64>,
     flat_map_regens: Arc<AtomicUsize>,
+    shrink_iterations: u32,
+    failures: Vec<(Reason, String)>,
+    duplicate_failures: u32,
 
     local_reject_detail: RejectionDetail,
     global_reject_detail: RejectionDetail,
+    #[cfg(feature = "std")]
+    labels: BTreeMap<String, u32>,
+    #[cfg(feature = "std")]
+    observer: Option<Box<dyn TestObserver>>,
+}
+
+impl Clone for TestRunner {
+    /// Clones the runner's state, except that the clone is not attached to
+    /// the observer of any run in progress.
+    fn clone(&self) -> Self {
+        TestRunner {
+            config: self.config.clone(),
+            successes: self.successes,
+            local_rejects: self.local_rejects,
+            global_rejects: self.global_rejects,
+            rng: self.rng.clone(),
+            rng_seed: self.rng_seed,
+            flat_map_regens: Arc::clone(&self.flat_map_regens),
+            shrink_iterations: self.shrink_iterations,
+            failures: self.failures.clone(),
+            duplicate_failures: self.duplicate_failures,
+            local_reject_detail: self.local_reject_detail.clone(),
+            global_reject_detail: self.global_reject_detail.clone(),
+            #[cfg(feature = "std")]
+            labels: self.labels.clone(),
+            #[cfg(feature = "std")]
+            observer: None,
+        }
+    }
 }
 
 impl fmt::Debug for TestRunner {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_struct("TestRunner")
-            .field("config", &self.config)
+        let mut s = f.debug_struct("TestRunner");
+        s.field("config", &self.config)
             .field("successes", &self.successes)
             .field("local_rejects", &self.local_rejects)
             .field("global_rejects", &self.global_rejects)
             .field("rng", &"<TestRng>")
+            .field("rng_seed", &self.rng_seed)
             .field("flat_map_regens", &self.flat_map_regens)
+            .field("shrink_iterations", &self.shrink_iterations)
+            .field("failures", &self.failures)
+            .field("duplicate_failures", &self.duplicate_failures)
             .field("local_reject_detail", &self.local_reject_detail)
-            .field("global_reject_detail", &self.global_reject_detail)
-            .finish()
+            .field("global_reject_detail", &self.global_reject_detail);
+        #[cfg(feature = "std")]
+        s.field("labels", &self.labels)
+            .field("observer", &self.observer);
+        s.finish()
     }
 }
 
@@ -110,6 +227,24 @@ impl fmt::Display for TestRunner {
         for (whence, count) in &self.global_reject_detail {
             writeln!(f, "\t\t{} times at {}", count, whence)?;
         }
+        if self.failures.len() > 1 {
+            writeln!(
+                f,
+                "\tfailures: {} ({} duplicates omitted)",
+                self.failures.len(),
+                self.duplicate_failures
+            )?;
+            for (why, input) in &self.failures {
+                writeln!(f, "\t\t{}; minimal failing input: {}", why, input)?;
+            }
+        }
+        if let Some(seed) = self.rng_seed {
+            writeln!(
+                f,
+                "\trng seed: {0} (set PROPTEST_RNG_SEED={0} to replay)",
+                seed
+            )?;
+        }
 
         Ok(())
     }
@@ -304,19 +439,54 @@ where
 
 type TestRunResult<S> = Result<(), TestError<<S as Strategy>::Value>>;
 
+/// A source of seeds for new test cases, along with the result of running
+/// the test on each case if it is already known.
+pub(crate) trait CaseSource {
+    fn next_case(
+        &mut self,
+        runner: &TestRunner,
+    ) -> (Seed, Option<TestCaseResult>);
+}
+
 impl TestRunner {
     /// Create a fresh `TestRunner` with the given configuration.
     ///
-    /// The runner will use an RNG with a generated seed and the default
-    /// algorithm.
+    /// The runner will use an RNG with the algorithm and seed from the
+    /// configuration. If the seed is `RngSeed::Random`, a seed is chosen from
+    /// the operating system's entropy source.
+    ///
+    /// Either way, the seed is included when the runner is displayed (as it
+    /// is in the panic message of a failing `proptest!` test), so that the
+    /// same run can be repeated, for example on a developer's machine after a
+    /// failure in CI, by setting `PROPTEST_RNG_SEED` to that value. The
+    /// default RNG algorithms produce the same values on every platform, but
+    /// the run is only repeated exactly if the configuration and the test
+    /// itself are unchanged.
     ///
     /// In `no_std` environments, every `TestRunner` will use the same
     /// hard-coded seed. This seed is not contractually guaranteed and may be
     /// changed between releases without notice.
     pub fn new(config: Config) -> Self {
-        let seed = config.rng_seed;
         let algorithm = config.rng_algorithm;
-        TestRunner::new_with_rng(config, TestRng::default_rng(seed, algorithm))
+        #[cfg(feature = "std")]
+        {
+            let seed = match config.rng_seed {
+                RngSeed::Random => random_seed(),
+                RngSeed::Fixed(seed) => seed,
+            };
+            let rng = TestRng::default_rng(RngSeed::Fixed(seed), algorithm);
+            let mut runner = TestRunner::new_with_rng(config, rng);
+            runner.rng_seed = Some(seed);
+            runner
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            let seed = config.rng_seed;
+            TestRunner::new_with_rng(
+                config,
+                TestRng::default_rng(seed, algorithm),
+            )
+        }
     }
 
     /// Create a fresh `TestRunner` with the standard deterministic RNG.
@@ -341,6 +511,9 @@ impl TestRunner {
     }
 
     /// Create a fresh `TestRunner` with the given configuration and RNG.
+    ///
+    /// To use an RNG algorithm not built into proptest, wrap it with
+    /// `TestRng::custom()`.
     pub fn new_with_rng(config: Config, rng: TestRng) -> Self {
         TestRunner {
             config: config,
@@ -348,12 +521,64 @@ impl TestRunner {
             local_rejects: 0,
             global_rejects: 0,
             rng: rng,
+            rng_seed: None,
             flat_map_regens: Arc::new(AtomicUsize::new(0)),
+            shrink_iterations: 0,
+            failures: Vec::new(),
+            duplicate_failures: 0,
             local_reject_detail: BTreeMap::new(),
             global_reject_detail: BTreeMap::new(),
+            #[cfg(feature = "std")]
+            labels: BTreeMap::new(),
+            #[cfg(feature = "std")]
+            observer: None,
         }
     }
 
+    /// Create a fresh `TestRunner` with the given configuration, whose random
+    /// data is read from `data` instead of being generated.
+    ///
+    /// This allows the same strategies to be used in fuzz targets, such as
+    /// those run by `cargo fuzz`, as in tests: every value generated is
+    /// decoded deterministically from the fuzzer's input, so the fuzzer can
+    /// explore structured values by mutating bytes.
+    ///
+    /// Once `data` is used up, the runner reads zeros, which make the
+    /// simplest choices: the start of every range, the first option of every
+    /// `prop_oneof!`, the shortest collections and `false`. An empty input
+    /// therefore generates roughly what shrinking would reach. A strategy
+    /// which rejects that value will keep rejecting it, so filtering
+    /// strategies fail with too many local rejects rather than generating a
+    /// value, and a low `max_local_rejects` keeps such inputs fast.
+    ///
+    /// This uses the `PassThrough` RNG algorithm; see `RngAlgorithm` for how
+    /// data is divided by strategies which derive new RNGs.
+    ///
+    /// ```
+    /// use proptest::prelude::*;
+    /// use proptest::strategy::ValueTree;
+    /// use proptest::test_runner::{Config, TestRunner};
+    ///
+    /// fn fuzz_target(data: &[u8]) {
+    ///     let config = Config { max_local_rejects: 16, ..Config::default() };
+    ///     let mut runner = TestRunner::from_bytes(config, data);
+    ///     let strategy = prop::collection::vec(0..100u32, 1..10);
+    ///     if let Ok(tree) = strategy.new_tree(&mut runner) {
+    ///         let values = tree.current();
+    ///         assert!(values.iter().max() <= Some(&99));
+    ///     }
+    /// }
+    ///
+    /// fuzz_target(b"");
+    /// fuzz_target(b"some bytes from a fuzzer");
+    /// ```
+    pub fn from_bytes(config: Config, data: &[u8]) -> Self {
+        TestRunner::new_with_rng(
+            config,
+            TestRng::from_seed(RngAlgorithm::PassThrough, data),
+        )
+    }
+
     /// Create a fresh `TestRunner` with the same config and global counters as
     /// this one, but with local state reset and an independent `Rng` (but
     /// deterministic).
@@ -364,9 +589,17 @@ impl TestRunner {
             local_rejects: 0,
             global_rejects: 0,
             rng: self.new_rng(),
+            rng_seed: None,
             flat_map_regens: Arc::clone(&self.flat_map_regens),
+            shrink_iterations: 0,
+            failures: Vec::new(),
+            duplicate_failures: 0,
             local_reject_detail: BTreeMap::new(),
             global_reject_detail: BTreeMap::new(),
+            #[cfg(feature = "std")]
+            labels: BTreeMap::new(),
+            #[cfg(feature = "std")]
+            observer: None,
         }
     }
 
@@ -386,6 +619,25 @@ impl TestRunner {
         &self.config
     }
 
+    /// Returns, for each label attached with `prop_classify!` or
+    /// `prop_collect!`, the number of passing test cases which carried it.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn labels(&self) -> &BTreeMap<String, u32> {
+        &self.labels
+    }
+
+    /// Returns the failures found by the last run, as the reason each case
+    /// failed and the `Debug` representation of its minimal failing input.
+    ///
+    /// This holds more than one failure only if `Config::max_failures` is
+    /// greater than 1, in which case the first is the one the run returned.
+    /// Failures whose minimal failing input has the same `Debug`
+    /// representation as that of an earlier failure are omitted.
+    pub fn failures(&self) -> &[(Reason, String)] {
+        &self.failures
+    }
+
     /// Dumps the bytes obtained from the RNG so far (only works if the RNG is
     /// set to `Recorder`).
     ///
@@ -419,6 +671,87 @@ impl TestRunner {
         }
     }
 
+    /// Run test cases against `f`, choosing inputs via `strategy`, with the
+    /// test function running on `Config::threads` threads at once.
+    ///
+    /// Inputs are generated on the current thread and then passed to the
+    /// other threads, so this requires the test function to be `Sync` and the
+    /// values to be `Send`. Shrinking happens on the current thread. In all
+    /// other respects this behaves like `run`, except that:
+    ///
+    /// - A run with a fixed RNG seed generates different inputs than the same
+    ///   run with `run` would (although the same as any other parallel run,
+    ///   regardless of the number of threads).
+    ///
+    /// - Labels from `prop_classify!` and `prop_collect!` are not recorded
+    ///   for test cases run on other threads.
+    ///
+    /// - Forking, timeouts, targeted search and coverage-guided generation
+    ///   all need to run each test case before choosing the next one, so if
+    ///   any of them is enabled, this simply calls `run`.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn run_parallel<S>(
+        &mut self,
+        strategy: &S,
+        test: impl Fn(S::Value) -> TestCaseResult + Sync,
+    ) -> TestRunResult<S>
+    where
+        S: Strategy,
+        S::Value: Send,
+    {
+        if self.config.threads <= 1 {
+            return self.run(strategy, test);
+        }
+
+        if self.config.fork()
+            || self.config.targeted
+            || self.config.coverage_feedback.is_some()
+        {
+            verbose_message!(
+                self,
+                INFO_LOG,
+                "Running test cases serially since forking or guided search \
+                 is enabled"
+            );
+            return self.run(strategy, test);
+        }
+
+        let threads = self.config.threads as usize;
+        let seeds = self.rng.gen_rng();
+        let mut cases = ParallelCases::new(strategy, &test, threads, seeds);
+        let (replay_steps, fork_output) = init_replay(&mut self.rng);
+        self.run_in_process_with_replay(
+            strategy,
+            &test,
+            replay_steps.into_iter(),
+            fork_output,
+            Some(&mut cases),
+        )
+    }
+
+    /// Run test cases against the async function `test`, choosing inputs via
+    /// `strategy` and running each test case to completion on `executor`.
+    ///
+    /// This otherwise behaves exactly like `run`, including with respect to
+    /// shrinking, failure persistence and forking.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn run_async<S, E, F, Fut>(
+        &mut self,
+        strategy: &S,
+        executor: &E,
+        test: F,
+    ) -> TestRunResult<S>
+    where
+        S: Strategy,
+        E: Executor + ?Sized,
+        F: Fn(S::Value) -> Fut,
+        Fut: Future<Output = TestCaseResult>,
+    {
+        self.run(strategy, |value| executor.block_on(test(value)))
+    }
+
     #[cfg(not(feature = "fork"))]
     fn run_in_fork<S: Strategy>(
         &mut self,
@@ -563,6 +896,7 @@ impl TestRunner {
             |_| panic!("Ran past the end of the replay"),
             replay.steps.into_iter(),
             ForkOutput::empty(),
+            None,
         )
     }
 
@@ -577,15 +911,67 @@ impl TestRunner {
             test,
             replay_steps.into_iter(),
             fork_output,
+            None,
         )
     }
 
     fn run_in_process_with_replay<S: Strategy>(
+        &mut self,
+        strategy: &S,
+        test: impl Fn(S::Value) -> TestCaseResult,
+        replay_from_fork: impl Iterator<Item = TestCaseResult>,
+        fork_output: ForkOutput,
+        cases: Option<&mut dyn CaseSource>,
+    ) -> TestRunResult<S> {
+        #[cfg(feature = "std")]
+        {
+            self.observer = self
+                .config
+                .observer
+                .as_ref()
+                .map(|new_observer| new_observer());
+        }
+
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!(
+            "proptest",
+            test_name = self.config.test_name,
+            source_file = self.config.source_file,
+            rng_seed = self.rng_seed,
+        )
+        .entered();
+
+        let result = self.run_cases(
+            strategy,
+            test,
+            replay_from_fork,
+            fork_output,
+            cases,
+        );
+
+        #[cfg(feature = "std")]
+        if let Some(mut observer) = self.observer.take() {
+            observer.finished(&match result {
+                Ok(()) => Ok(()),
+                Err(TestError::Abort(ref why)) => {
+                    Err(TestError::Abort(why.clone()))
+                }
+                Err(TestError::Fail(ref why, ref value)) => {
+                    Err(TestError::Fail(why.clone(), value as &dyn fmt::Debug))
+                }
+            });
+        }
+
+        result
+    }
+
+    fn run_cases<S: Strategy>(
         &mut self,
         strategy: &S,
         test: impl Fn(S::Value) -> TestCaseResult,
         mut replay_from_fork: impl Iterator<Item = TestCaseResult>,
         mut fork_output: ForkOutput,
+        mut cases: Option<&mut dyn CaseSource>,
     ) -> TestRunResult<S> {
         let old_rng = self.rng.clone();
 
@@ -593,38 +979,130 @@ impl TestRunner {
             .config
             .failure_persistence
             .as_ref()
-            .map(|f| f.load_persisted_failures2(self.config.source_file))
+            .map(|f| {
+                f.load_persisted_failures_for_test(
+                    self.config.source_file,
+                    self.config.test_name,
+                )
+            })
             .unwrap_or_default();
 
         let mut result_cache = self.new_cache();
+        let mut first_failure = None;
+        self.failures.clear();
+        self.duplicate_failures = 0;
 
         for PersistedSeed(persisted_seed) in
             persisted_failure_seeds.into_iter().rev()
         {
-            self.rng.set_seed(persisted_seed);
-            self.gen_and_run_case(
+            if !old_rng.accepts_seed(&persisted_seed) {
+                verbose_message!(
+                    self,
+                    INFO_LOG,
+                    "Skipping persisted seed {} which requires a custom RNG",
+                    persisted_seed.to_persistence()
+                );
+                continue;
+            }
+
+            self.rng = old_rng.clone();
+            self.rng.set_seed(persisted_seed.clone());
+            #[cfg(feature = "tracing")]
+            let _span = tracing::debug_span!(
+                "case",
+                persisted = true,
+                seed = %persisted_seed.to_persistence(),
+            )
+            .entered();
+            let result = self.gen_and_run_case(
                 strategy,
                 &test,
                 &mut replay_from_fork,
                 &mut *result_cache,
                 &mut fork_output,
                 true,
-            )?;
+            );
+            #[cfg(feature = "std")]
+            if !fork_output.is_in_fork() {
+                self.write_failure_report(&persisted_seed, &result);
+            }
+            if let Err(e) = result {
+                if let Err(e) = self.record_failure(e, &mut first_failure) {
+                    self.rng = old_rng;
+                    return Err(e);
+                }
+            }
         }
         self.rng = old_rng;
 
+        #[cfg(feature = "std")]
+        let mut seed_search = self.new_seed_search();
+        #[cfg(feature = "tracing")]
+        let mut case_index = 0u32;
+
         while self.successes < self.config.cases {
             // Generate a new seed and make an RNG from that so that we know
             // what seed to persist if this case fails.
-            let seed = self.rng.gen_get_seed();
+            let mut precomputed = None;
+            let seed = match cases {
+                Some(ref mut cases) => {
+                    let (seed, result) = cases.next_case(self);
+                    self.rng.set_seed(seed.clone());
+                    precomputed = result;
+                    seed
+                }
+                #[cfg(feature = "std")]
+                None => match seed_search {
+                    Some(ref mut search) => {
+                        let seed = search.next_seed();
+                        self.rng.set_seed(seed.clone());
+                        seed
+                    }
+                    None => self.rng.gen_get_seed(),
+                },
+                #[cfg(not(feature = "std"))]
+                None => self.rng.gen_get_seed(),
+            };
+            #[cfg(feature = "tracing")]
+            let _span = {
+                case_index += 1;
+                tracing::debug_span!(
+                    "case",
+                    index = case_index,
+                    seed = %seed.to_persistence(),
+                )
+                .entered()
+            };
             let result = self.gen_and_run_case(
                 strategy,
                 &test,
-                &mut replay_from_fork,
+                &mut precomputed.into_iter().chain(&mut replay_from_fork),
                 &mut *result_cache,
                 &mut fork_output,
                 false,
             );
+            #[cfg(feature = "std")]
+            let seed = match seed_search {
+                Some(ref mut search) => {
+                    if search.observe() {
+                        verbose_message!(
+                            self,
+                            TRACE,
+                            "Search progress: {}",
+                            search
+                        );
+                    }
+                    // Searches generate inputs from large buffers, most of
+                    // which is usually never read, so persist only the
+                    // bytes which were.
+                    self.rng.consumed_seed().unwrap_or(seed)
+                }
+                None => seed,
+            };
+            #[cfg(feature = "std")]
+            if !fork_output.is_in_fork() {
+                self.write_failure_report(&seed, &result);
+            }
             if let Err(TestError::Fail(_, ref value)) = result {
                 if let Some(ref mut failure_persistence) =
                     self.config.failure_persistence
@@ -635,8 +1113,9 @@ impl TestRunner {
                     // process. The parent relies on it remaining consistent
                     // and will take care of updating it itself.
                     if !fork_output.is_in_fork() {
-                        failure_persistence.save_persisted_failure2(
+                        failure_persistence.save_persisted_failure_for_test(
                             *source_file,
+                            self.config.test_name,
                             PersistedSeed(seed),
                             value,
                         );
@@ -645,13 +1124,75 @@ impl TestRunner {
             }
 
             if let Err(e) = result {
-                fork_output.terminate();
-                return Err(e.into());
+                if let Err(e) = self.record_failure(e, &mut first_failure) {
+                    fork_output.terminate();
+                    return Err(e);
+                }
             }
         }
 
+        #[cfg(feature = "std")]
+        if !self.labels.is_empty() {
+            verbose_message!(
+                self,
+                ALWAYS,
+                "{}",
+                classify::report(self.successes, &self.labels)
+            );
+        }
+
         fork_output.terminate();
-        Ok(())
+        match first_failure {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    /// Record the failure of a test case, returning `Err` if the run should
+    /// stop, or `Ok` if it should keep looking for further failures.
+    ///
+    /// `first_failure` holds the first failure found by the run, which is
+    /// the error returned once it stops.
+    fn record_failure<V: fmt::Debug>(
+        &mut self,
+        error: TestError<V>,
+        first_failure: &mut Option<TestError<V>>,
+    ) -> Result<(), TestError<V>> {
+        let (why, value) = match error {
+            TestError::Abort(_) => return Err(error),
+            TestError::Fail(why, value) => (why, value),
+        };
+        let input = format!("{:?}", value);
+        if self.failures.iter().any(|(_, seen)| *seen == input) {
+            // The value types need not implement `PartialEq`, so shrunk
+            // values are compared by their `Debug` output instead.
+            verbose_message!(
+                self,
+                INFO_LOG,
+                "Ignoring duplicate failure: {}; minimal failing input: {}",
+                why,
+                input
+            );
+            self.duplicate_failures += 1;
+        } else {
+            self.failures.push((why.clone(), input));
+            if first_failure.is_none() {
+                *first_failure = Some(TestError::Fail(why, value));
+            }
+        }
+
+        let max_failures = if self.config.fork() {
+            1
+        } else {
+            self.config.max_failures
+        };
+        if self.failures.len() >= max_failures as usize
+            || self.duplicate_failures >= self.config.cases
+        {
+            Err(first_failure.take().expect("no failure recorded"))
+        } else {
+            Ok(())
+        }
     }
 
     fn gen_and_run_case<S: Strategy>(
@@ -666,6 +1207,9 @@ impl TestRunner {
         let case = unwrap_or!(strategy.new_tree(self), msg =>
                 return Err(TestError::Abort(msg)));
 
+        #[cfg(feature = "std")]
+        let _ = classify::take_labels();
+
         // We only count new cases to our set of successful runs against
         // `PROPTEST_CASES` config.
         let ok_type = self.run_one_with_replay(
@@ -678,7 +1222,11 @@ impl TestRunner {
         )?;
         match ok_type {
             TestCaseOk::NewCaseSuccess | TestCaseOk::ReplayFromForkSuccess => {
-                self.successes += 1
+                self.successes += 1;
+                #[cfg(feature = "std")]
+                for label in classify::take_labels() {
+                    *self.labels.entry(label).or_insert(0) += 1;
+                }
             }
             TestCaseOk::PersistedCaseSuccess
             | TestCaseOk::CacheHitSuccess
@@ -726,6 +1274,12 @@ impl TestRunner {
         fork_output: &mut ForkOutput,
         is_from_persisted_seed: bool,
     ) -> Result<TestCaseOk, TestError<V::Value>> {
+        #[cfg(feature = "std")]
+        if let Some(ref mut observer) = self.observer {
+            observer.case_generated(&case.current());
+        }
+        trace_event!(DEBUG, input = ?case.current(), "running test case");
+
         let result = call_test(
             self,
             case.current(),
@@ -736,6 +1290,48 @@ impl TestRunner {
             is_from_persisted_seed,
         );
 
+        #[cfg(feature = "std")]
+        if let Some(ref path) = self.config.record_cases {
+            let (outcome, reason) = match result {
+                Ok(_) => ("passed", None),
+                Err(TestCaseError::Reject(ref whence)) => {
+                    ("rejected", Some(whence.message()))
+                }
+                Err(TestCaseError::Fail(ref why)) => {
+                    ("failed", Some(why.message()))
+                }
+            };
+            CaseRecord {
+                test_name: self.config.test_name,
+                input: format!("{:?}", case.current()),
+                outcome,
+                reason,
+            }
+            .write(path);
+        }
+
+        #[cfg(feature = "std")]
+        if let Some(ref mut observer) = self.observer {
+            match result {
+                Ok(_) => observer.case_passed(),
+                Err(TestCaseError::Reject(ref whence)) => {
+                    observer.case_rejected(whence)
+                }
+                Err(TestCaseError::Fail(_)) => (),
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        match result {
+            Ok(_) => tracing::debug!("test case passed"),
+            Err(TestCaseError::Reject(ref whence)) => {
+                tracing::debug!(reason = %whence, "test case rejected")
+            }
+            Err(TestCaseError::Fail(ref why)) => {
+                tracing::info!(reason = %why, "test case failed")
+            }
+        }
+
         match result {
             Ok(success_type) => Ok(success_type),
             Err(TestCaseError::Fail(why)) => {
@@ -767,6 +1363,8 @@ impl TestRunner {
         fork_output: &mut ForkOutput,
         is_from_persisted_seed: bool,
     ) -> Option<Reason> {
+        self.shrink_iterations = 0;
+
         // exit early if shrink disabled
         if self.config.max_shrink_iters == 0 {
             verbose_message!(
@@ -777,6 +1375,10 @@ impl TestRunner {
             return None
         }
 
+        #[cfg(feature = "std")]
+        let _guard = ShrinkGuard::new();
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("shrink").entered();
         #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
         let start_time = std::time::Instant::now();
         let mut last_failure = None;
@@ -812,9 +1414,9 @@ impl TestRunner {
                         "Aborting shrinking after {} iterations (set {} \
                          to a large(r) value to shrink more; current \
                          configuration: {} iterations)",
+                        iterations,
                         CONTROLLER,
-                        self.config.max_shrink_iters(),
-                        iterations
+                        self.config.max_shrink_iters()
                     );
                     true
                 } else if let Some(ms) = timed_out {
@@ -839,6 +1441,15 @@ impl TestRunner {
                         current
                     );
                     true
+                } else if self.is_shrink_interrupted() {
+                    verbose_message!(
+                        self,
+                        ALWAYS,
+                        "Shrinking interrupted after {} iterations; \
+                         reporting the smallest failing input found so far",
+                        iterations
+                    );
+                    true
                 } else {
                     false
                 };
@@ -852,6 +1463,7 @@ impl TestRunner {
                 }
 
                 iterations += 1;
+                self.shrink_iterations = iterations;
 
                 let result = call_test(
                     self,
@@ -863,6 +1475,40 @@ impl TestRunner {
                     is_from_persisted_seed,
                 );
 
+                #[cfg(feature = "std")]
+                if self.config.verbose >= TRACE {
+                    let outcome = match result {
+                        Ok(_) => "passed".into(),
+                        Err(TestCaseError::Reject(ref whence)) => {
+                            format!("rejected ({})", whence)
+                        }
+                        Err(TestCaseError::Fail(ref why)) => {
+                            format!("failed ({})", why)
+                        }
+                    };
+                    verbose_message!(
+                        self,
+                        TRACE,
+                        "Shrink step {}: {:?} {}",
+                        iterations,
+                        case.current(),
+                        outcome
+                    );
+                }
+
+                #[cfg(feature = "std")]
+                if let Some(ref mut observer) = self.observer {
+                    let failed = matches!(result, Err(TestCaseError::Fail(_)));
+                    observer.shrink_step(&case.current(), failed);
+                }
+                trace_event!(
+                    TRACE,
+                    step = iterations,
+                    input = ?case.current(),
+                    failed = matches!(result, Err(TestCaseError::Fail(_))),
+                    "shrink step"
+                );
+
                 match result {
                     // Rejections are effectively a pass here,
                     // since they indicate that any behaviour of
@@ -880,6 +1526,11 @@ impl TestRunner {
                     }
                     Err(TestCaseError::Fail(why)) => {
                         last_failure = Some(why);
+                        #[cfg(feature = "std")]
+                        if let Some(ref mut observer) = self.observer {
+                            observer
+                                .shrink_progress(&case.current(), iterations);
+                        }
                         if !case.simplify() {
                             verbose_message!(
                                 self,
@@ -894,9 +1545,55 @@ impl TestRunner {
             }
         }
 
+        trace_event!(
+            DEBUG,
+            steps = iterations,
+            input = ?case.current(),
+            "shrinking finished"
+        );
         last_failure
     }
 
+    #[cfg(feature = "std")]
+    fn is_shrink_interrupted(&self) -> bool {
+        interrupt::is_interrupted()
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn is_shrink_interrupted(&self) -> bool {
+        false
+    }
+
+    /// Write a report of `result` to `Config::json_output`, if it is a
+    /// failure and the output is set.
+    #[cfg(feature = "std")]
+    fn write_failure_report<V: fmt::Debug>(
+        &self,
+        seed: &Seed,
+        result: &Result<(), TestError<V>>,
+    ) {
+        let (output, reason, value) = match (&self.config.json_output, result) {
+            (Some(output), Err(TestError::Fail(reason, value))) => {
+                (output, reason, value)
+            }
+            _ => return,
+        };
+
+        FailureReport {
+            test_name: self.config.test_name,
+            source_file: self.config.source_file,
+            reason: reason.message(),
+            input: format!("{:?}", value),
+            seed: seed.to_persistence(),
+            rng_seed: self.rng_seed,
+            successes: self.successes,
+            local_rejects: self.local_rejects,
+            global_rejects: self.global_rejects,
+            shrink_iterations: self.shrink_iterations,
+        }
+        .write(output);
+    }
+
     /// Update the state to account for a local rejection from `whence`, and
     /// return `Ok` if the caller should keep going or `Err` to abort.
     pub fn reject_local(
@@ -944,6 +1641,33 @@ impl TestRunner {
     fn new_cache(&self) -> Box<dyn ResultCache> {
         (self.config.result_cache)()
     }
+
+    #[cfg(feature = "std")]
+    fn new_seed_search(&mut self) -> Option<SeedSearch> {
+        if self.config.coverage_feedback.is_none() && !self.config.targeted {
+            return None;
+        }
+
+        // The parent process replays the child's steps without running the
+        // test, so it never sees the feedback the search depends on.
+        if self.config.fork() {
+            verbose_message!(
+                self,
+                ALWAYS,
+                "Targeted and coverage-guided search are not supported with \
+                 forking; ignoring"
+            );
+            return None;
+        }
+
+        let rng = self.new_rng();
+        Some(match self.config.coverage_feedback {
+            Some(new_feedback) => {
+                SeedSearch::Coverage(CoverageSearch::new(new_feedback(), rng))
+            }
+            None => SeedSearch::Target(TargetSearch::new(rng)),
+        })
+    }
 }
 
 #[cfg(feature = "fork")]
@@ -1131,6 +1855,65 @@ mod test {
         assert_eq!(Err(TestError::Fail("not less than 5".into(), 5)), result);
     }
 
+    #[test]
+    fn keeps_going_until_max_failures() {
+        let mut runner = TestRunner::new(Config {
+            failure_persistence: None,
+            max_failures: 3,
+            ..Config::default()
+        });
+        let result = runner.run(&(0u32..1000u32), |v| {
+            prop_assert!(v < 500, "too big");
+            prop_assert!(v % 2 == 0, "odd");
+            Ok(())
+        });
+
+        let failures = runner.failures().to_vec();
+        assert_eq!(3, failures.len());
+        match result {
+            Err(TestError::Fail(why, value)) => {
+                assert_eq!((why, format!("{:?}", value)), failures[0]);
+            }
+            result => panic!("unexpected result {:?}", result),
+        }
+        assert!(format!("{}", runner).contains("\tfailures: 3 ("));
+
+        let mut runner = TestRunner::new(Config {
+            failure_persistence: None,
+            ..Config::default()
+        });
+        runner
+            .run(&(0u32..1000u32), |v| {
+                prop_assert!(v < 500, "too big");
+                Ok(())
+            })
+            .expect_err("didn't fail?");
+        assert_eq!(1, runner.failures().len());
+        assert!(!format!("{}", runner).contains("failures"));
+    }
+
+    #[test]
+    fn duplicate_failures_are_omitted() {
+        let mut runner = TestRunner::new(Config {
+            failure_persistence: None,
+            max_failures: 3,
+            cases: 16,
+            ..Config::default()
+        });
+        // Every case fails and shrinks to 0, so the run only stops because
+        // of the limit on duplicates.
+        let result = runner.run(&(0u32..1000u32), |_| {
+            Err(TestCaseError::fail("always fails"))
+        });
+
+        assert_eq!(Err(TestError::Fail("always fails".into(), 0)), result);
+        assert_eq!(
+            &[("always fails".into(), String::from("0"))],
+            runner.failures()
+        );
+        assert_eq!(16, runner.duplicate_failures);
+    }
+
     #[test]
     fn persisted_cases_do_not_count_towards_total_cases() {
         const FILE: &'static str = "persistence-test.txt";
@@ -1240,6 +2023,571 @@ mod test {
         assert_eq!(first_super_failure, second_super_failure);
     }
 
+    #[test]
+    fn targeted_search_finds_high_utility_failure() {
+        let mut runner = TestRunner::new_with_rng(
+            Config {
+                cases: 4096,
+                failure_persistence: None,
+                targeted: true,
+                ..Config::default()
+            },
+            TestRng::deterministic_rng(RngAlgorithm::ChaCha),
+        );
+        let result = runner.run(&crate::collection::vec(0u8..=255, 8), |v| {
+            let sum: u32 = v.iter().map(|&b| u32::from(b)).sum();
+            prop_target!(sum);
+            // Random inputs essentially never get here.
+            prop_assert!(sum < 1900);
+            Ok(())
+        });
+
+        match result {
+            Err(TestError::Fail(_, v)) => {
+                assert_eq!(1900, v.iter().map(|&b| u32::from(b)).sum::<u32>())
+            }
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn targeted_search_persists_only_consumed_bytes() {
+        use crate::test_runner::MapFailurePersistence;
+
+        let test = |v: Vec<u8>| {
+            let sum: u32 = v.iter().map(|&b| u32::from(b)).sum();
+            prop_target!(sum);
+            prop_assert!(sum < 1900);
+            Ok(())
+        };
+        let mut runner = TestRunner::new_with_rng(
+            Config {
+                cases: 4096,
+                failure_persistence: Some(Box::new(
+                    MapFailurePersistence::default(),
+                )),
+                source_file: Some("targeted"),
+                targeted: true,
+                ..Config::default()
+            },
+            TestRng::deterministic_rng(RngAlgorithm::ChaCha),
+        );
+        runner
+            .run(&crate::collection::vec(0u8..=255, 8), test)
+            .expect_err("didn't fail?");
+
+        let persisted = runner
+            .config()
+            .failure_persistence
+            .as_ref()
+            .unwrap()
+            .load_persisted_failures_for_test(Some("targeted"), None);
+        assert_eq!(1, persisted.len());
+        let seed = format!("{}", persisted[0]);
+        assert!(seed.len() < 100, "{}", seed);
+
+        // With no new cases, only the persisted failure can fail.
+        let config = Config {
+            cases: 0,
+            ..runner.config().clone()
+        };
+        TestRunner::new(config)
+            .run(&crate::collection::vec(0u8..=255, 8), test)
+            .expect_err("persisted failure should be replayed");
+    }
+
+    #[test]
+    fn coverage_guided_search_finds_nested_failure() {
+        use crate::arbitrary::any;
+        use crate::test_runner::CoverageFeedback;
+        use std::collections::BTreeSet;
+
+        thread_local! {
+            static DEPTH: Cell<usize> = const { Cell::new(0) };
+        }
+
+        #[derive(Debug, Default)]
+        struct DepthFeedback(BTreeSet<usize>);
+
+        impl CoverageFeedback for DepthFeedback {
+            fn begin_case(&mut self) {
+                DEPTH.with(|d| d.set(0));
+            }
+
+            fn end_case(&mut self) -> bool {
+                self.0.insert(DEPTH.with(|d| d.get()))
+            }
+        }
+
+        let mut runner = TestRunner::new_with_rng(
+            Config {
+                cases: 1 << 16,
+                failure_persistence: None,
+                coverage_feedback: Some(|| Box::new(DepthFeedback::default())),
+                ..Config::default()
+            },
+            TestRng::deterministic_rng(RngAlgorithm::ChaCha),
+        );
+        let result =
+            runner.run(&[any::<u8>(), any::<u8>(), any::<u8>()], |v| {
+                let depth =
+                    v.iter().zip(b"BUG").take_while(|(a, b)| a == b).count();
+                DEPTH.with(|d| d.set(depth));
+                prop_assert!(depth < 3);
+                Ok(())
+            });
+
+        match result {
+            Err(TestError::Fail(_, v)) => assert_eq!(*b"BUG", v),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+        // Blind generation would need around 2^24 cases.
+        assert!(runner.successes < 50_000, "took {} cases", runner.successes);
+    }
+
+    #[test]
+    fn custom_rng_failures_are_persisted_and_replayed() {
+        use crate::test_runner::MapFailurePersistence;
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+
+        let custom_rng = || TestRng::custom(XorShiftRng::seed_from_u64(0));
+        let input = (0u32..1000).prop_perturb(|v, mut rng| {
+            assert!(rng.custom_rng_mut::<XorShiftRng>().is_some());
+            v
+        });
+        let test = |v| {
+            prop_assert!(v < 500);
+            Ok(())
+        };
+
+        let mut runner = TestRunner::new_with_rng(
+            Config {
+                failure_persistence: Some(Box::new(
+                    MapFailurePersistence::default(),
+                )),
+                source_file: Some("custom_rng"),
+                ..Config::default()
+            },
+            custom_rng(),
+        );
+        match runner.run(&input, test) {
+            Err(TestError::Fail(_, 500)) => (),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+
+        // With no new cases, only the persisted failure can fail.
+        let config = Config {
+            cases: 0,
+            max_shrink_iters: 1024,
+            ..runner.config().clone()
+        };
+        match TestRunner::new_with_rng(config.clone(), custom_rng())
+            .run(&input, test)
+        {
+            Err(TestError::Fail(_, 500)) => (),
+            result => panic!("Unexpected result: {:?}", result),
+        }
+
+        // Runners without a custom RNG cannot replay the seed.
+        TestRunner::new(config)
+            .run(&(0u32..1000), test)
+            .expect("persisted custom seed should be skipped");
+    }
+
+    #[test]
+    fn labels_count_passing_cases() {
+        let mut runner = TestRunner::new(Config {
+            cases: 100,
+            failure_persistence: None,
+            ..Config::default()
+        });
+        runner
+            .run(&(0u32..10), |v| {
+                prop_classify!(v < 5, "small");
+                prop_collect!(v % 2);
+                prop_assume!(v != 0);
+                Ok(())
+            })
+            .unwrap();
+
+        let labels = runner.labels();
+        assert_eq!(100, labels["0"] + labels["1"]);
+        assert!(labels["small"] > 0 && labels["small"] < 100);
+    }
+
+    #[test]
+    fn failures_are_reported_as_json() {
+        let path = std::env::temp_dir()
+            .join(format!("proptest-json-output-{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut runner = TestRunner::new(Config {
+            failure_persistence: None,
+            test_name: Some("json_report"),
+            json_output: Some(crate::test_runner::JsonOutput::File(
+                path.clone(),
+            )),
+            ..Config::default()
+        });
+        let result = runner.run(&(0u32..1000), |v| {
+            if v < 500 {
+                Ok(())
+            } else {
+                Err(TestCaseError::fail("too big"))
+            }
+        });
+        assert!(result.is_err());
+
+        let report = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(1, lines.len());
+        assert!(
+            lines[0].starts_with(
+                "{\"test_name\":\"json_report\",\"source_file\":null,\
+                 \"reason\":\"too big\",\"input\":\"500\",\"seed\":\"cc "
+            ),
+            "{}",
+            lines[0]
+        );
+        assert!(lines[0].contains(&format!(
+            "\"rng_seed\":\"{}\",",
+            runner.rng_seed.unwrap()
+        )));
+        assert!(!lines[0].contains("\"shrink_iterations\":0}"));
+    }
+
+    #[test]
+    fn generated_cases_are_recorded() {
+        let path = std::env::temp_dir().join(format!(
+            "proptest-record-cases-{}.jsonl",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+
+        let mut runner = TestRunner::new(Config {
+            cases: 8,
+            failure_persistence: None,
+            record_cases: Some(path.clone()),
+            ..Config::default()
+        });
+        let result = runner.run(&(0u32..1000), |v| {
+            if v % 2 == 0 {
+                Ok(())
+            } else {
+                Err(TestCaseError::reject("odd"))
+            }
+        });
+        assert!(result.is_ok());
+
+        let record = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        let lines: Vec<&str> = record.lines().collect();
+        let rejected = lines
+            .iter()
+            .filter(|line| {
+                line.ends_with("\"outcome\":\"rejected\",\"reason\":\"odd\"}")
+            })
+            .count();
+        assert_eq!(8 + rejected, lines.len());
+        assert!(lines
+            .iter()
+            .all(|line| line.starts_with("{\"test_name\":null,\"input\":\"")));
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn run_is_traced() {
+        use std::string::String;
+        use std::sync::Mutex;
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata};
+
+        /// Records the names of spans and the messages of events.
+        #[derive(Default)]
+        struct Recorder(Arc<Mutex<Vec<String>>>);
+
+        impl Visit for &Recorder {
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                if field.name() == "message" {
+                    self.0.lock().unwrap().push(format!("{:?}", value));
+                }
+            }
+        }
+
+        impl tracing::Subscriber for Recorder {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut events = self.0.lock().unwrap();
+                events.push(format!("span {}", span.metadata().name()));
+                Id::from_u64(events.len() as u64)
+            }
+
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                event.record(&mut &*self);
+            }
+
+            fn enter(&self, _: &Id) {}
+
+            fn exit(&self, _: &Id) {}
+        }
+
+        let recorder = Recorder::default();
+        let events = Arc::clone(&recorder.0);
+        let result = tracing::subscriber::with_default(recorder, || {
+            TestRunner::new(Config {
+                failure_persistence: None,
+                ..Config::default()
+            })
+            .run(&(0u32..100), |v| {
+                if v % 10 == 0 {
+                    Err(TestCaseError::reject("round"))
+                } else if v >= 50 {
+                    Err(TestCaseError::fail("big"))
+                } else {
+                    Ok(())
+                }
+            })
+        });
+        assert!(result.is_err());
+
+        let events = events.lock().unwrap();
+        assert_eq!("span proptest", events[0]);
+        for expected in &[
+            "span case",
+            "running test case",
+            "test case passed",
+            "test case failed",
+            "span shrink",
+            "shrink step",
+            "shrinking finished",
+        ] {
+            assert!(events.iter().any(|e| e == expected), "{}", expected);
+        }
+    }
+
+    #[test]
+    fn observer_is_notified_of_progress() {
+        use std::string::{String, ToString};
+
+        thread_local! {
+            static EVENTS: RefCell<Vec<String>> =
+                const { RefCell::new(Vec::new()) };
+        }
+
+        fn record(event: String) {
+            EVENTS.with(|e| e.borrow_mut().push(event));
+        }
+
+        #[derive(Debug)]
+        struct Recorder;
+
+        impl TestObserver for Recorder {
+            fn case_generated(&mut self, value: &dyn fmt::Debug) {
+                record(format!("gen {:?}", value));
+            }
+
+            fn case_passed(&mut self) {
+                record("pass".to_string());
+            }
+
+            fn case_rejected(&mut self, whence: &Reason) {
+                record(format!("reject {}", whence));
+            }
+
+            fn shrink_step(&mut self, value: &dyn fmt::Debug, failed: bool) {
+                record(format!("shrink {:?} {}", value, failed));
+            }
+
+            fn shrink_progress(&mut self, value: &dyn fmt::Debug, step: u32) {
+                record(format!("progress {:?} {}", value, step));
+            }
+
+            fn finished(
+                &mut self,
+                result: &Result<(), TestError<&dyn fmt::Debug>>,
+            ) {
+                record(format!("{:?}", result));
+            }
+        }
+
+        let mut runner = TestRunner::new_with_rng(
+            Config {
+                failure_persistence: None,
+                observer: Some(Arc::new(|| Box::new(Recorder))),
+                ..Config::default()
+            },
+            TestRng::deterministic_rng(RngAlgorithm::ChaCha),
+        );
+        let result = runner.run(&(0u32..100), |v| {
+            if v % 10 == 0 {
+                Err(TestCaseError::reject("round"))
+            } else if v >= 50 {
+                Err(TestCaseError::fail("big"))
+            } else {
+                Ok(())
+            }
+        });
+        let value = match result {
+            Err(TestError::Fail(_, value)) => value,
+            result => panic!("Unexpected result: {:?}", result),
+        };
+
+        let events = EVENTS.with(|e| e.take());
+        let generated = events.iter().filter(|e| e.starts_with("gen ")).count();
+        let passed = events.iter().filter(|e| *e == "pass").count();
+        let rejected = events.iter().filter(|e| *e == "reject round").count();
+        assert_eq!(generated, passed + rejected + 1);
+        assert_eq!(runner.successes as usize, passed);
+        assert!(events.contains(&format!("shrink {} true", value)));
+
+        let shrinks = events.iter().filter(|e| e.starts_with("shrink "));
+        let progress = events
+            .iter()
+            .filter(|e| e.starts_with("progress "))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            shrinks.filter(|e| e.ends_with(" true")).count(),
+            progress.len()
+        );
+        assert!(progress
+            .last()
+            .unwrap()
+            .starts_with(&format!("progress {} ", value)));
+        assert_eq!(
+            &format!("Err(Fail(Reason(\"big\"), {}))", value),
+            events.last().unwrap()
+        );
+    }
+
+    #[test]
+    fn configs_are_equal_only_with_the_same_observer() {
+        #[derive(Debug)]
+        struct Silent;
+        impl TestObserver for Silent {}
+
+        let new_observer: Arc<dyn Fn() -> Box<dyn TestObserver> + Send + Sync> =
+            Arc::new(|| Box::new(Silent));
+        let config = Config {
+            observer: Some(new_observer.clone()),
+            ..Config::default()
+        };
+
+        assert_eq!(config, config.clone());
+        assert_ne!(config, Config::default());
+        assert_ne!(
+            config,
+            Config {
+                observer: Some(Arc::new(|| Box::new(Silent))),
+                ..Config::default()
+            }
+        );
+    }
+    #[test]
+    fn parallel_run_passes() {
+        use std::sync::atomic::AtomicU32;
+
+        let runs = AtomicU32::new(0);
+        let mut runner = TestRunner::new(Config {
+            cases: 100,
+            threads: 4,
+            failure_persistence: None,
+            ..Config::default()
+        });
+        runner
+            .run_parallel(&(0u32..100), |v| {
+                runs.fetch_add(1, SeqCst);
+                prop_assume!(v % 4 != 0);
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!(100, runner.successes);
+        assert!(runner.global_rejects > 0);
+        assert!(runs.load(SeqCst) >= 100 + runner.global_rejects);
+    }
+
+    #[test]
+    fn parallel_run_shrinks_and_persists_failures() {
+        use crate::test_runner::MapFailurePersistence;
+
+        let config = Config {
+            threads: 4,
+            failure_persistence: Some(Box::new(
+                MapFailurePersistence::default(),
+            )),
+            source_file: Some("parallel"),
+            rng_seed: RngSeed::Fixed(0),
+            ..Config::default()
+        };
+        let test = |v| {
+            if v == 7 {
+                panic!("seven");
+            }
+            prop_assert!(v < 500);
+            Ok(())
+        };
+
+        let mut runner = TestRunner::new(config.clone());
+        let first = runner.run_parallel(&(0u32..1000), test);
+        match first {
+            Err(TestError::Fail(_, 500)) => (),
+            ref result => panic!("Unexpected result: {:?}", result),
+        }
+
+        // The same seed finds the same failure with any number of threads.
+        let mut rerun = TestRunner::new(Config {
+            threads: 3,
+            failure_persistence: None,
+            ..config.clone()
+        });
+        assert_eq!(first, rerun.run_parallel(&(0u32..1000), test));
+        assert_eq!(runner.successes, rerun.successes);
+
+        // The failure is replayed first on the next run.
+        let mut replay = TestRunner::new(Config {
+            cases: 0,
+            max_shrink_iters: 1024,
+            ..runner.config().clone()
+        });
+        assert_eq!(first, replay.run(&(0u32..1000), test));
+    }
+
+    #[test]
+    fn reported_rng_seed_replays_failure() {
+        let config = Config {
+            failure_persistence: None,
+            ..Config::default()
+        };
+        let test = |v| {
+            prop_assert!(v % 7 != 3);
+            Ok(())
+        };
+
+        let mut runner = TestRunner::new(config.clone());
+        let failure = runner.run(&(0u32..1_000_000), test);
+        assert!(failure.is_err());
+        let seed = runner.rng_seed.unwrap();
+        assert!(format!("{}", runner)
+            .contains(&format!("PROPTEST_RNG_SEED={}", seed)));
+
+        let mut replay = TestRunner::new(Config {
+            rng_seed: RngSeed::Fixed(seed),
+            ..config
+        });
+        assert_eq!(failure, replay.run(&(0u32..1_000_000), test));
+        assert_eq!(runner.successes, replay.successes);
+    }
+
     #[test]
     fn new_rng_makes_separate_rng() {
         use rand::Rng;
@@ -1255,7 +2603,8 @@ mod test {
 
         // create value with recorder rng
         let default_config = Config::default();
-        let recorder_rng = TestRng::default_rng(RngSeed::Random, RngAlgorithm::Recorder);
+        let recorder_rng =
+            TestRng::default_rng(RngSeed::Random, RngAlgorithm::Recorder);
         let mut runner =
             TestRunner::new_with_rng(default_config.clone(), recorder_rng);
         let random_byte_array1 = runner.rng().gen::<[u8; 16]>();
@@ -1273,6 +2622,26 @@ mod test {
         assert_eq!(random_byte_array1, random_byte_array2);
     }
 
+    #[test]
+    fn values_are_decoded_from_bytes() {
+        let strategy = (
+            0..100u32,
+            crate::collection::vec(0..10u8, 2..6),
+            prop_oneof![Just('x'), Just('y'), Just('z')],
+        );
+        let generate = |data: &[u8]| {
+            let mut runner = TestRunner::from_bytes(Config::default(), data);
+            strategy.new_tree(&mut runner).unwrap().current()
+        };
+
+        // Exhausted data makes the simplest choices.
+        assert_eq!((0, vec![0, 0], 'x'), generate(b""));
+
+        let data = (0..64u8).map(|n| n.wrapping_mul(37)).collect::<Vec<_>>();
+        assert_eq!(generate(&data), generate(&data));
+        assert_ne!(generate(b""), generate(&data));
+    }
+
     #[cfg(feature = "fork")]
     #[test]
     fn run_successful_test_in_fork() {
@@ -0,0 +1,200 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Support for targeted property testing.
+//!
+//! A test body reports a numeric "utility" for the current input via
+//! [`target`] (usually through the `prop_target!` macro). When
+//! `Config::targeted` is set, the runner generates each input from a
+//! `PassThrough` byte buffer and uses simulated annealing over those buffers
+//! to find inputs which maximise the reported utility.
+
+use std::cell::Cell;
+use std::sync::Arc;
+use std::vec::Vec;
+
+use rand::Rng;
+
+use crate::test_runner::rng::Seed;
+use crate::test_runner::TestRng;
+
+thread_local! {
+    static UTILITY: Cell<Option<f64>> = const { Cell::new(None) };
+}
+
+/// Report the utility of the test case currently running on this thread.
+///
+/// When the test is run with `Config::targeted` enabled, the runner tries to
+/// find inputs which maximise this value. Calling this more than once in the
+/// same test case replaces the previously reported value. NaN is ignored.
+///
+/// This has no effect when targeted search is disabled.
+pub fn target(utility: f64) {
+    if !utility.is_nan() {
+        UTILITY.with(|u| u.set(Some(utility)));
+    }
+}
+
+/// Take the utility reported by the most recent test case, if any.
+pub(crate) fn take_utility() -> Option<f64> {
+    UTILITY.with(|u| u.take())
+}
+
+/// The number of bytes each candidate input is generated from.
+///
+/// Strategies which consume more than this see zeroes afterwards, which
+/// corresponds to the simplest choices.
+const BUFFER_LEN: usize = 1 << BUFFER_LEN_BITS;
+const BUFFER_LEN_BITS: u32 = 12;
+/// The probability of abandoning the current state for a fresh random input.
+pub(crate) const RESTART_PROBABILITY: f64 = 0.1;
+/// The factor by which the temperature decreases after each observation.
+const COOLING: f64 = 0.97;
+/// The maximum number of bytes changed to produce a neighbouring input.
+const MAX_MUTATIONS: u32 = 4;
+
+/// Generate a fresh, uniformly random input buffer.
+pub(crate) fn random_buffer(rng: &mut TestRng) -> Vec<u8> {
+    let mut buffer = vec![0u8; BUFFER_LEN];
+    rng.fill(&mut buffer[..]);
+    buffer
+}
+
+/// Produce a neighbour of `buffer` by changing a few of its bytes.
+pub(crate) fn mutate_buffer(rng: &mut TestRng, buffer: &[u8]) -> Vec<u8> {
+    let mut buffer = buffer.to_vec();
+    for _ in 0..rng.gen_range(1..=MAX_MUTATIONS) {
+        // Bias mutations towards the start of the buffer, since that is
+        // where most strategies draw their entropy from, by choosing the
+        // size of the range of candidate positions log-uniformly.
+        let limit = buffer.len() >> rng.gen_range(0..BUFFER_LEN_BITS);
+        let pos = rng.gen_range(0..limit);
+        if rng.gen() {
+            buffer[pos] = rng.gen();
+        } else {
+            let delta = rng.gen_range(1..=16);
+            buffer[pos] = if rng.gen() {
+                buffer[pos].wrapping_add(delta)
+            } else {
+                buffer[pos].wrapping_sub(delta)
+            };
+        }
+    }
+    buffer
+}
+
+/// Simulated annealing over the byte buffers used to generate test inputs.
+#[derive(Debug)]
+pub(crate) struct TargetSearch {
+    rng: TestRng,
+    current: Option<(f64, Arc<[u8]>)>,
+    best: Option<f64>,
+    temperature: f64,
+    candidate: Arc<[u8]>,
+}
+
+impl TargetSearch {
+    pub(crate) fn new(rng: TestRng) -> Self {
+        TargetSearch {
+            rng,
+            current: None,
+            best: None,
+            temperature: 1.0,
+            candidate: Arc::from(&[][..]),
+        }
+    }
+
+    /// The highest utility observed so far.
+    pub(crate) fn best(&self) -> Option<f64> {
+        self.best
+    }
+
+    /// Choose the next input to try and return the seed which generates it.
+    pub(crate) fn next_seed(&mut self) -> Seed {
+        let buffer = match self.current {
+            Some((_, ref current))
+                if !self.rng.gen_bool(RESTART_PROBABILITY) =>
+            {
+                mutate_buffer(&mut self.rng, current)
+            }
+            _ => random_buffer(&mut self.rng),
+        };
+
+        self.candidate = Arc::from(buffer);
+        Seed::PassThrough(None, Arc::clone(&self.candidate))
+    }
+
+    /// Record the utility reported by the input from the last call to
+    /// `next_seed()`, returning whether it is the best seen so far.
+    pub(crate) fn observe(&mut self, utility: Option<f64>) -> bool {
+        let utility = match utility {
+            Some(utility) => utility,
+            None => return false,
+        };
+
+        let accept = match self.current {
+            None => {
+                self.temperature = utility.abs().max(1.0);
+                true
+            }
+            Some((current, _)) => {
+                utility >= current
+                    || self.rng.gen::<f64>()
+                        < ((utility - current) / self.temperature).exp()
+            }
+        };
+        if accept {
+            self.current = Some((utility, Arc::clone(&self.candidate)));
+        }
+        self.temperature *= COOLING;
+
+        let improved = self.best.map_or(true, |best| utility > best);
+        if improved {
+            self.best = Some(utility);
+        }
+        improved
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_runner::RngAlgorithm;
+
+    #[test]
+    fn target_replaces_and_take_clears() {
+        let _ = take_utility();
+        target(1.0);
+        target(2.0);
+        target(f64::NAN);
+        assert_eq!(Some(2.0), take_utility());
+        assert_eq!(None, take_utility());
+    }
+
+    #[test]
+    fn search_moves_towards_higher_utility() {
+        let mut search =
+            TargetSearch::new(TestRng::deterministic_rng(RngAlgorithm::ChaCha));
+        let utility = |seed: &Seed| match *seed {
+            Seed::PassThrough(_, ref data) => {
+                data[..8].iter().map(|&b| f64::from(b)).sum::<f64>()
+            }
+            _ => unreachable!(),
+        };
+
+        for _ in 0..2048 {
+            let seed = search.next_seed();
+            search.observe(Some(utility(&seed)));
+        }
+
+        // Random buffers average 8 * 127.5; hill climbing should do much
+        // better than that.
+        assert!(search.best().unwrap() > 1900.0);
+    }
+}
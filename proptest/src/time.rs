@@ -0,0 +1,377 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating [`Duration`]s and [`SystemTime`]s.
+//!
+//! [`TimeParams`] in this module is used as the argument to the
+//! [`Arbitrary`](crate::arbitrary::Arbitrary) implementations for
+//! `Duration` and `SystemTime`.
+
+use core::ops::RangeInclusive;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::std_facade::Vec;
+use crate::strategy::statics;
+use crate::strategy::*;
+use crate::test_runner::*;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// The granularity of generated durations and times.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Precision {
+    /// Any number of nanoseconds.
+    #[default]
+    Nanos,
+    /// Whole microseconds.
+    Micros,
+    /// Whole milliseconds.
+    Millis,
+    /// Whole seconds.
+    Secs,
+}
+
+impl Precision {
+    fn unit_nanos(self) -> u128 {
+        match self {
+            Precision::Nanos => 1,
+            Precision::Micros => 1_000,
+            Precision::Millis => 1_000_000,
+            Precision::Secs => NANOS_PER_SEC,
+        }
+    }
+}
+
+/// Parameters for generating `Duration`s and `SystemTime`s.
+///
+/// Durations are generated between zero and the maximum, and times between
+/// the maximum either side of `UNIX_EPOCH`. Both shrink towards zero, that
+/// is towards `UNIX_EPOCH` for times.
+///
+/// With edge cases enabled, as they are by default, about a quarter of the
+/// values are zero, the smallest non-zero value at the chosen precision, or
+/// the largest values, including the largest which can be added to or
+/// subtracted from `UNIX_EPOCH` without overflowing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimeParams {
+    max: Option<Duration>,
+    precision: Precision,
+    edge_cases: bool,
+}
+
+impl TimeParams {
+    /// Gets the maximum duration, or offset from `UNIX_EPOCH`, to generate.
+    ///
+    /// `None` means `Duration::MAX` for durations and `i32::MAX` seconds for
+    /// times, since some platforms cannot represent times further from the
+    /// epoch.
+    pub fn max(&self) -> Option<Duration> {
+        self.max
+    }
+
+    /// Sets the maximum duration, or offset from `UNIX_EPOCH`, to generate.
+    ///
+    /// Times are additionally limited to those the platform can represent.
+    pub fn with_max(mut self, max: Option<Duration>) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Gets the granularity of generated values.
+    pub fn precision(&self) -> Precision {
+        self.precision
+    }
+
+    /// Sets the granularity of generated values.
+    pub fn with_precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Gets whether generation is biased towards edge cases.
+    pub fn edge_cases(&self) -> bool {
+        self.edge_cases
+    }
+
+    /// Sets whether generation is biased towards edge cases.
+    pub fn with_edge_cases(mut self, edge_cases: bool) -> Self {
+        self.edge_cases = edge_cases;
+        self
+    }
+}
+
+impl Default for TimeParams {
+    fn default() -> Self {
+        Self {
+            max: None,
+            precision: Precision::Nanos,
+            edge_cases: true,
+        }
+    }
+}
+
+/// Maps a number of precision units to a `Duration`.
+#[derive(Clone, Copy, Debug)]
+pub struct DurationUnits(u128);
+
+impl statics::MapFn<u128> for DurationUnits {
+    type Output = Duration;
+
+    fn apply(&self, units: u128) -> Duration {
+        from_nanos(units * self.0)
+    }
+}
+
+/// Maps a signed number of precision units to a `SystemTime` relative to
+/// `UNIX_EPOCH`.
+#[derive(Clone, Copy, Debug)]
+pub struct SystemTimeUnits(u128);
+
+impl statics::MapFn<i128> for SystemTimeUnits {
+    type Output = SystemTime;
+
+    fn apply(&self, units: i128) -> SystemTime {
+        let offset = from_nanos(units.unsigned_abs() * self.0);
+        if units >= 0 {
+            UNIX_EPOCH + offset
+        } else {
+            UNIX_EPOCH - offset
+        }
+    }
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `Duration`s.
+    ///
+    /// Created by `duration()`, and used by `any::<Duration>()`.
+    #[derive(Clone, Debug)]
+    pub struct DurationStrategy[][](
+        Union<statics::Map<RangeInclusive<u128>, DurationUnits>>)
+        -> DurationValueTree;
+    /// `ValueTree` corresponding to `DurationStrategy`.
+    #[derive(Clone, Debug)]
+    pub struct DurationValueTree[][](
+        UnionValueTree<statics::Map<RangeInclusive<u128>, DurationUnits>>)
+        -> Duration;
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates `SystemTime`s.
+    ///
+    /// Created by `system_time()`, and used by `any::<SystemTime>()`.
+    #[derive(Clone, Debug)]
+    pub struct SystemTimeStrategy[][](
+        Union<statics::Map<RangeInclusive<i128>, SystemTimeUnits>>)
+        -> SystemTimeValueTree;
+    /// `ValueTree` corresponding to `SystemTimeStrategy`.
+    #[derive(Clone, Debug)]
+    pub struct SystemTimeValueTree[][](
+        UnionValueTree<statics::Map<RangeInclusive<i128>, SystemTimeUnits>>)
+        -> SystemTime;
+}
+
+/// Creates a strategy which generates durations as described by `params`.
+pub fn duration(params: TimeParams) -> DurationStrategy {
+    let unit = params.precision.unit_nanos();
+    let max = params.max.unwrap_or(Duration::MAX).as_nanos() / unit;
+    let mut edges = Vec::new();
+    if params.edge_cases() {
+        edges.extend_from_slice(&[1, max.saturating_sub(1), max]);
+        let limit = epoch_limit(SystemTime::checked_add).as_nanos() / unit;
+        if limit <= max {
+            edges.extend_from_slice(&[limit, limit + 1]);
+        }
+    }
+
+    let options = weighted(0..=max, edges)
+        .into_iter()
+        .map(|(weight, range)| {
+            (weight, statics::Map::new(range, DurationUnits(unit)))
+        })
+        .collect();
+    DurationStrategy(Union::new_weighted(options))
+}
+
+/// Creates a strategy which generates times as described by `params`.
+pub fn system_time(params: TimeParams) -> SystemTimeStrategy {
+    let unit = params.precision.unit_nanos();
+    let max = params
+        .max
+        .unwrap_or_else(|| Duration::from_secs(i32::MAX as u64))
+        .as_nanos()
+        / unit;
+    let after = (epoch_limit(SystemTime::checked_add).as_nanos() / unit)
+        .min(max) as i128;
+    let before = (epoch_limit(SystemTime::checked_sub).as_nanos() / unit)
+        .min(max) as i128;
+    let mut edges = Vec::new();
+    if params.edge_cases() {
+        edges.extend_from_slice(&[
+            1,
+            -1,
+            after - 1,
+            after,
+            1 - before,
+            -before,
+        ]);
+    }
+
+    let options = weighted(-before..=after, edges)
+        .into_iter()
+        .map(|(weight, range)| {
+            (weight, statics::Map::new(range, SystemTimeUnits(unit)))
+        })
+        .collect();
+    SystemTimeStrategy(Union::new_weighted(options))
+}
+
+/// Returns the options of a union generating from `range` about three
+/// quarters of the time, and otherwise from zero or one of `edges`.
+///
+/// Zero comes first, so that shrinking can always reach it.
+fn weighted<T: Copy + Default + PartialOrd>(
+    range: RangeInclusive<T>,
+    mut edges: Vec<T>,
+) -> Vec<(u32, RangeInclusive<T>)> {
+    if edges.is_empty() {
+        return vec![(1, range)];
+    }
+
+    edges.retain(|edge| range.contains(edge));
+    let zero = T::default();
+    let mut options =
+        vec![(1, zero..=zero), (3 * (edges.len() as u32 + 1), range)];
+    options.extend(edges.into_iter().map(|edge| (1, edge..=edge)));
+    options
+}
+
+fn from_nanos(nanos: u128) -> Duration {
+    Duration::new(
+        (nanos / NANOS_PER_SEC) as u64,
+        (nanos % NANOS_PER_SEC) as u32,
+    )
+}
+
+/// Finds the largest duration which `op` can apply to `UNIX_EPOCH` without
+/// overflowing.
+fn epoch_limit(
+    op: fn(&SystemTime, Duration) -> Option<SystemTime>,
+) -> Duration {
+    let fits =
+        |secs, nanos| op(&UNIX_EPOCH, Duration::new(secs, nanos)).is_some();
+
+    let (mut lo, mut hi) = (0, u64::MAX);
+    while lo < hi {
+        let mid = hi - (hi - lo) / 2;
+        if fits(mid, 0) {
+            lo = mid;
+        } else {
+            hi = mid - 1;
+        }
+    }
+
+    let (mut lo_nanos, mut hi_nanos) = (0, 999_999_999);
+    while lo_nanos < hi_nanos {
+        let mid = hi_nanos - (hi_nanos - lo_nanos) / 2;
+        if fits(lo, mid) {
+            lo_nanos = mid;
+        } else {
+            hi_nanos = mid - 1;
+        }
+    }
+    Duration::new(lo, lo_nanos)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn durations_include_edge_cases() {
+        let mut runner = TestRunner::deterministic();
+        let limit = epoch_limit(SystemTime::checked_add);
+        let mut seen = HashSet::new();
+        for _ in 0..1024 {
+            let d = duration(TimeParams::default()).sample(&mut runner);
+            seen.insert(d);
+        }
+        for d in [
+            Duration::ZERO,
+            Duration::from_nanos(1),
+            Duration::MAX,
+            limit,
+        ]
+        .iter()
+        {
+            assert!(seen.contains(d), "{:?} never generated", d);
+        }
+    }
+
+    #[test]
+    fn times_respect_max_and_precision() {
+        let mut runner = TestRunner::deterministic();
+        let params = TimeParams::default()
+            .with_max(Some(Duration::from_secs(3600)))
+            .with_precision(Precision::Millis);
+        let (mut before, mut after) = (false, false);
+        for _ in 0..1024 {
+            let time = system_time(params).sample(&mut runner);
+            let offset = match time.duration_since(UNIX_EPOCH) {
+                Ok(offset) => {
+                    after |= offset == Duration::from_secs(3600);
+                    offset
+                }
+                Err(e) => {
+                    before |= e.duration() == Duration::from_secs(3600);
+                    e.duration()
+                }
+            };
+            assert!(offset <= Duration::from_secs(3600));
+            assert_eq!(0, offset.subsec_nanos() % 1_000_000, "{:?}", offset);
+        }
+        assert!(before && after);
+    }
+
+    #[test]
+    fn times_stay_representable() {
+        let mut runner = TestRunner::deterministic();
+        let params = TimeParams::default().with_max(Some(Duration::MAX));
+        for _ in 0..1024 {
+            // Panics on overflow.
+            system_time(params).sample(&mut runner);
+        }
+    }
+
+    #[test]
+    fn shrinks_to_zero() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..64 {
+            assert_eq!(
+                Duration::ZERO,
+                duration(TimeParams::default()).minimal(&mut runner)
+            );
+            assert_eq!(
+                UNIX_EPOCH,
+                system_time(TimeParams::default()).minimal(&mut runner)
+            );
+        }
+    }
+
+    #[test]
+    fn edge_cases_can_be_disabled() {
+        let mut runner = TestRunner::deterministic();
+        let params = TimeParams::default().with_edge_cases(false);
+        for _ in 0..256 {
+            let d = duration(params).sample(&mut runner);
+            assert!(d > Duration::from_secs(1 << 20), "{:?}", d);
+        }
+    }
+}
@@ -0,0 +1,240 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating tree shapes with a controlled number of nodes.
+//!
+//! `prop_recursive()` only bounds the size of the trees it generates
+//! probabilistically. The strategies here instead choose the exact number of
+//! nodes up front and partition it among the children of each node, so a
+//! test can ask for, say, a tree of exactly 1000 nodes.
+//!
+//! The generated `Shape`s carry no data; to build a tree of your own type,
+//! generate the node payloads separately, for example as a `Vec` with one
+//! element per node, and combine them with the shape in a `prop_map()`.
+
+use core::cmp::min;
+use core::ops::RangeInclusive;
+
+use crate::collection::{vec, SizeRange, VecStrategy, VecValueTree};
+use crate::num;
+use crate::std_facade::Vec;
+use crate::strategy::*;
+use crate::test_runner::*;
+use crate::tuple::TupleValueTree;
+
+/// The shape of a tree: a node together with the shapes of its children.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Shape {
+    nodes: usize,
+    children: Vec<Shape>,
+}
+
+impl Shape {
+    /// Return the shapes of the children of this node, in order.
+    pub fn children(&self) -> &[Shape] {
+        &self.children
+    }
+
+    /// Return whether this node has no children.
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Return the number of nodes in the tree, including this one.
+    pub fn nodes(&self) -> usize {
+        self.nodes
+    }
+
+    /// Return the number of nodes on the longest path from this node to a
+    /// leaf, including both ends.
+    pub fn depth(&self) -> usize {
+        1 + self.children.iter().map(Shape::depth).max().unwrap_or(0)
+    }
+}
+
+/// Builds a `Shape` with a given number of nodes from a stream of random
+/// choices.
+///
+/// Each node first picks how many children to have, from 1 up to the
+/// maximum (or none once the budget is used up), and then how many of its
+/// remaining nodes to give to each child but the last, which gets the rest.
+/// Choices of zero produce a path, so shapes shrink towards that.
+#[derive(Clone, Copy, Debug)]
+struct BuildShape {
+    max_children: usize,
+}
+
+impl statics::MapFn<(usize, Vec<u32>)> for BuildShape {
+    type Output = Shape;
+
+    fn apply(&self, (nodes, choices): (usize, Vec<u32>)) -> Shape {
+        let mut choices = choices.into_iter();
+        // Scale each choice into `0..size`, as with `sample::Index`.
+        let mut choose = |size: usize| {
+            let choice = choices.next().expect("BuildShape ran out of choices");
+            (((size as u64) * u64::from(choice)) >> 32) as usize
+        };
+
+        // Decide the number of children of each node, in preorder.
+        let mut child_counts = Vec::with_capacity(nodes);
+        let mut budgets = vec![nodes];
+        while let Some(budget) = budgets.pop() {
+            let mut left = budget - 1;
+            let children = if 0 == left {
+                0
+            } else {
+                1 + choose(min(self.max_children, left))
+            };
+            child_counts.push(children);
+
+            let first = budgets.len();
+            for later in (0..children).rev() {
+                let size = if 0 == later {
+                    left
+                } else {
+                    1 + choose(left - later)
+                };
+                budgets.push(size);
+                left -= size;
+            }
+            // The first child must be on top of the stack to keep preorder.
+            budgets[first..].reverse();
+        }
+
+        // Then assemble the shapes bottom-up. In reverse preorder, a node's
+        // children are already on the stack, with its first child on top.
+        let mut shapes: Vec<Shape> = Vec::new();
+        for &count in child_counts.iter().rev() {
+            let children: Vec<Shape> =
+                (0..count).map(|_| shapes.pop().unwrap()).collect();
+            shapes.push(Shape {
+                nodes: 1 + children.iter().map(Shape::nodes).sum::<usize>(),
+                children,
+            });
+        }
+
+        shapes.pop().unwrap()
+    }
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates tree `Shape`s.
+    ///
+    /// Created by `shape()`.
+    #[derive(Clone, Debug)]
+    pub struct ShapeStrategy[][](
+        statics::Map<(RangeInclusive<usize>, VecStrategy<num::u32::Any>),
+                     BuildShape>)
+        -> ShapeValueTree;
+    /// `ValueTree` corresponding to `ShapeStrategy`.
+    #[derive(Clone, Debug)]
+    pub struct ShapeValueTree[][](
+        statics::Map<TupleValueTree<(num::usize::BinarySearch,
+                                     VecValueTree<num::u32::BinarySearch>)>,
+                     BuildShape>)
+        -> Shape;
+}
+
+/// Create a strategy for tree `Shape`s whose number of nodes is within
+/// `nodes`, and where each node has at most `max_children` children.
+///
+/// Passing a single number as `nodes` gives trees of exactly that many
+/// nodes. Otherwise, the number of nodes is chosen uniformly from the range,
+/// and shrinks towards its lower bound. Shapes then shrink towards a path,
+/// where every node but the last has one child.
+///
+/// ## Panics
+///
+/// Panics if `nodes` allows trees of zero nodes, or if `max_children` is
+/// zero.
+///
+/// ## Example
+///
+/// ```
+/// use proptest::prelude::*;
+/// use proptest::tree::shape;
+///
+/// proptest! {
+///     # /*
+///     #[test]
+///     # */
+///     fn binary_trees_have_1000_nodes(tree in shape(1000, 2)) {
+///         prop_assert_eq!(1000, tree.nodes());
+///         prop_assert!(tree.children().len() <= 2);
+///     }
+/// }
+/// #
+/// # fn main() { binary_trees_have_1000_nodes(); }
+/// ```
+pub fn shape(
+    nodes: impl Into<SizeRange>,
+    max_children: usize,
+) -> ShapeStrategy {
+    let nodes = nodes.into();
+    let (start, end) = nodes.start_end_incl();
+    nodes.assert_nonempty();
+    assert!(start > 0, "Trees must have at least one node");
+    assert!(max_children > 0, "Trees must allow at least one child");
+
+    // Each node uses at most two choices: one for its number of children,
+    // and one for the size of each child but its last.
+    ShapeStrategy(statics::Map::new(
+        (start..=end, vec(num::u32::ANY, 2 * end)),
+        BuildShape { max_children },
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check(shape: &Shape, max_children: usize) -> usize {
+        assert!(shape.children().len() <= max_children);
+        let nodes = 1 + shape
+            .children()
+            .iter()
+            .map(|c| check(c, max_children))
+            .sum::<usize>();
+        assert_eq!(nodes, shape.nodes());
+        nodes
+    }
+
+    #[test]
+    fn shapes_have_exact_node_count() {
+        let mut runner = TestRunner::deterministic();
+        for &(nodes, max_children) in &[(1, 1), (10, 1), (100, 2), (1000, 5)] {
+            let input = shape(nodes, max_children);
+            for _ in 0..64 {
+                let shape = input.new_tree(&mut runner).unwrap().current();
+                assert_eq!(nodes, check(&shape, max_children));
+            }
+        }
+
+        let path = shape(10, 3).minimal(&mut runner);
+        assert_eq!(10, path.depth());
+    }
+
+    #[test]
+    fn shapes_cover_node_range() {
+        let mut runner = TestRunner::deterministic();
+        let input = shape(5..10, 3);
+        let mut seen = [false; 10];
+        for _ in 0..256 {
+            let shape = input.new_tree(&mut runner).unwrap().current();
+            seen[check(&shape, 3)] = true;
+        }
+        assert_eq!([false; 5], seen[..5]);
+        assert!(seen[5..].iter().all(|&s| s));
+    }
+
+    #[test]
+    fn shape_sanity() {
+        check_strategy_sanity(shape(1..4, 2), None);
+    }
+}
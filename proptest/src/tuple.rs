@@ -11,6 +11,12 @@
 //!
 //! There is no explicit "tuple strategy"; simply make a tuple containing the
 //! strategy and that tuple is itself a strategy.
+//!
+//! Tuples of up to 12 strategies are supported, which is the largest arity
+//! for which the standard library implements `Debug` and `Default` for
+//! tuples. Larger products can be built by nesting tuples, for example
+//! `((a, b, c, d), (e, f, g, h), (i, j, k, l), (m, n))`, and destructuring
+//! or `prop_map`ping the nested value.
 
 use crate::strategy::*;
 use crate::test_runner::*;
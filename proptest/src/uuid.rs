@@ -0,0 +1,131 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating `uuid::Uuid` values.
+//!
+//! All strategies shrink towards the nil UUID, or as close to it as the
+//! version and variant they preserve allow.
+
+use core::ops::RangeInclusive;
+
+use ::uuid::{Builder, Uuid};
+
+use crate::num;
+use crate::strategy::statics;
+use crate::strategy::*;
+use crate::test_runner::*;
+
+/// How the bits of a generated UUID are laid out.
+#[derive(Clone, Copy, Debug)]
+enum Layout {
+    /// All bits are used as generated.
+    Raw,
+    /// The version and variant bits are set for a random (version 4) UUID.
+    V4,
+}
+
+impl statics::MapFn<u128> for Layout {
+    type Output = Uuid;
+
+    fn apply(&self, bits: u128) -> Uuid {
+        match self {
+            Layout::Raw => Uuid::from_u128(bits),
+            Layout::V4 => {
+                Builder::from_random_bytes(bits.to_be_bytes()).into_uuid()
+            }
+        }
+    }
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates UUIDs.
+    ///
+    /// Created by the functions in this module.
+    #[derive(Clone, Debug)]
+    pub struct UuidStrategy[][](
+        statics::Map<RangeInclusive<u128>, Layout>) -> UuidValueTree;
+    /// `ValueTree` corresponding to `UuidStrategy`.
+    #[derive(Clone, Debug)]
+    pub struct UuidValueTree[][](
+        statics::Map<num::u128::BinarySearch, Layout>) -> Uuid;
+}
+
+/// Creates a strategy which generates UUIDs from uniformly random bits, and
+/// so of any version and variant.
+///
+/// This is the strategy used by `any::<Uuid>()`.
+pub fn any() -> UuidStrategy {
+    UuidStrategy(statics::Map::new(0..=u128::MAX, Layout::Raw))
+}
+
+/// Creates a strategy which generates random (version 4) UUIDs with the
+/// RFC 9562 variant.
+///
+/// Shrinks towards `00000000-0000-4000-8000-000000000000`.
+pub fn v4() -> UuidStrategy {
+    UuidStrategy(statics::Map::new(0..=u128::MAX, Layout::V4))
+}
+
+/// Creates a strategy which always generates the nil UUID.
+pub fn nil() -> UuidStrategy {
+    UuidStrategy(statics::Map::new(0..=0, Layout::Raw))
+}
+
+#[cfg(test)]
+mod test {
+    use ::uuid::{Variant, Version};
+
+    use super::*;
+
+    #[test]
+    fn v4_has_version_and_variant() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..256 {
+            let mut tree = v4().new_tree(&mut runner).unwrap();
+            loop {
+                let uuid = tree.current();
+                assert_eq!(Some(Version::Random), uuid.get_version());
+                assert_eq!(Variant::RFC4122, uuid.get_variant());
+                if !tree.simplify() {
+                    break;
+                }
+            }
+            assert_eq!(
+                Uuid::parse_str("00000000-0000-4000-8000-000000000000")
+                    .unwrap(),
+                tree.current()
+            );
+        }
+    }
+
+    #[test]
+    fn any_covers_versions_and_shrinks_to_nil() {
+        let mut runner = TestRunner::deterministic();
+        let mut versions = std::collections::HashSet::new();
+        for _ in 0..1024 {
+            let mut tree = any().new_tree(&mut runner).unwrap();
+            versions.insert(tree.current().get_version_num());
+            while tree.simplify() {}
+            assert!(tree.current().is_nil());
+        }
+        assert_eq!(16, versions.len());
+    }
+
+    #[test]
+    fn nil_is_nil() {
+        let mut runner = TestRunner::deterministic();
+        assert!(nil().new_tree(&mut runner).unwrap().current().is_nil());
+    }
+
+    #[test]
+    fn uuid_sanity() {
+        check_strategy_sanity(any(), None);
+        check_strategy_sanity(v4(), None);
+    }
+}
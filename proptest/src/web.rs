@@ -0,0 +1,418 @@
+//-
+// Copyright 2026 The proptest developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Strategies for generating syntactically valid hostnames, email addresses
+//! and URLs.
+//!
+//! By default, the generated values are the ordinary kind found in most
+//! applications. `WebConfig` enables internationalized domain names and
+//! rarer forms which are valid but often mishandled, such as IP literals,
+//! quoted email local parts and maximum-length labels.
+
+use crate::collection::vec;
+use crate::std_facade::{Box, String, ToOwned, Vec};
+use crate::strategy::*;
+use crate::test_runner::*;
+
+/// Configuration for the strategies in this module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WebConfig {
+    /// The schemes URLs may have. The default is `http` and `https`.
+    ///
+    /// Must not be empty.
+    pub schemes: &'static [&'static str],
+    /// Whether hostnames may contain non-ASCII labels, written in Unicode
+    /// rather than Punycode. The default is `false`.
+    pub idn: bool,
+    /// Whether URLs may contain a port. The default is `true`.
+    pub ports: bool,
+    /// Whether URLs may contain a query string and a fragment. The default
+    /// is `true`.
+    pub query: bool,
+    /// Whether to also generate valid but unusual forms: IP address hosts,
+    /// single-label and fully-qualified hostnames, labels of the maximum
+    /// length, quoted email local parts, userinfo and percent-encoding in
+    /// URLs. The default is `false`.
+    pub pathological: bool,
+}
+
+impl Default for WebConfig {
+    fn default() -> Self {
+        WebConfig {
+            schemes: &["http", "https"],
+            idn: false,
+            ports: true,
+            query: true,
+            pathological: false,
+        }
+    }
+}
+
+opaque_strategy_wrapper! {
+    /// Strategy which generates hostnames, email addresses or URLs.
+    ///
+    /// Created by the functions in this module.
+    #[derive(Clone, Debug)]
+    pub struct WebStrategy[][](SBoxedStrategy<String>) -> WebValueTree;
+    /// `ValueTree` corresponding to `WebStrategy`.
+    pub struct WebValueTree[][](Box<dyn ValueTree<Value = String>>) -> String;
+}
+
+/// The maximum length of a hostname, excluding a trailing dot.
+const MAX_HOSTNAME_LEN: usize = 253;
+/// The maximum length of an email address, as limited by SMTP.
+const MAX_EMAIL_LEN: usize = 254;
+/// The maximum length of the local part of an email address.
+const MAX_LOCAL_PART_LEN: usize = 64;
+
+fn regex(regex: &str) -> SBoxedStrategy<String> {
+    crate::string::string_regex(regex).unwrap().sboxed()
+}
+
+/// Creates a strategy which generates hostnames with the default
+/// `WebConfig`.
+pub fn hostname() -> WebStrategy {
+    hostname_with_config(&WebConfig::default())
+}
+
+/// Creates a strategy which generates hostnames, that is, domain names made
+/// of letters, digits and hyphens, as described by `config`.
+///
+/// Hostnames shrink towards fewer and shorter labels.
+pub fn hostname_with_config(config: &WebConfig) -> WebStrategy {
+    WebStrategy(domain(config))
+}
+
+fn label(config: &WebConfig) -> SBoxedStrategy<String> {
+    let ascii = regex("[a-z0-9]([a-z0-9-]{0,10}[a-z0-9])?");
+    let mut labels = vec![(8, ascii)];
+    if config.idn {
+        labels.push((
+            2,
+            // Latin with diacritics, Greek, Cyrillic and CJK ideographs,
+            // which are all valid in IDNA labels without bidi rules.
+            regex(concat!(
+                "[a-z0-9\u{E0}-\u{F6}\u{F8}-\u{FF}\u{3B1}-\u{3C9}",
+                "\u{430}-\u{44F}\u{4E00}-\u{9FFF}]{1,10}"
+            )),
+        ));
+    }
+    if config.pathological {
+        labels.push((1, regex("[a-z0-9][a-z0-9-]{61}[a-z0-9]")));
+        labels.push((1, regex("[0-9]{1,3}")));
+    }
+    Union::new_weighted(labels).sboxed()
+}
+
+fn domain(config: &WebConfig) -> SBoxedStrategy<String> {
+    let tld = if config.idn {
+        regex("[a-z]{2,6}|\u{4E2D}\u{56FD}|\u{440}\u{444}")
+    } else {
+        regex("[a-z]{2,6}")
+    };
+    let dotted = (vec(label(config), 1..4), tld).prop_map(|(labels, tld)| {
+        let mut name = labels.join(".");
+        name.push('.');
+        name.push_str(&tld);
+        name
+    });
+
+    let name = if config.pathological {
+        Union::new_weighted(vec![
+            (8, dotted.sboxed()),
+            (1, regex("localhost|[a-z][a-z0-9-]{0,10}[a-z0-9]")),
+            (
+                1,
+                domain(&WebConfig {
+                    pathological: false,
+                    ..*config
+                })
+                .prop_map(|name| name + ".")
+                .sboxed(),
+            ),
+        ])
+        .sboxed()
+    } else {
+        dotted.sboxed()
+    };
+    name.prop_filter("hostname too long", |name| {
+        name.trim_end_matches('.').len() <= MAX_HOSTNAME_LEN
+    })
+    .sboxed()
+}
+
+fn ipv4() -> SBoxedStrategy<String> {
+    crate::arbitrary::any::<[u8; 4]>()
+        .prop_map(|[a, b, c, d]| format!("{}.{}.{}.{}", a, b, c, d))
+        .sboxed()
+}
+
+fn ipv6() -> SBoxedStrategy<String> {
+    crate::arbitrary::any::<[u16; 8]>()
+        .prop_map(|segments| format!("{}", std::net::Ipv6Addr::from(segments)))
+        .sboxed()
+}
+
+/// Creates a strategy which generates email addresses with the default
+/// `WebConfig`.
+pub fn email() -> WebStrategy {
+    email_with_config(&WebConfig::default())
+}
+
+/// Creates a strategy which generates email addresses as described by
+/// `config`, following the `addr-spec` syntax of RFC 5322 without comments
+/// or folding whitespace.
+///
+/// Addresses shrink towards short local parts and hostnames.
+pub fn email_with_config(config: &WebConfig) -> WebStrategy {
+    let atom = "[A-Za-z0-9!#$%&'*+/=?^_`{|}~-]{1,10}";
+    let dot_atom = regex(&format!("{0}(\\.{0}){{0,2}}", atom));
+
+    let (local, domain) = if config.pathological {
+        (
+            Union::new_weighted(vec![
+                (4, dot_atom),
+                // Quoted strings may contain spaces, `@` and escaped quotes.
+                (1, regex(r#""([ !#-\[\]-~]|\\["\\]){0,10}""#)),
+            ])
+            .sboxed(),
+            Union::new_weighted(vec![
+                (4, domain(config)),
+                (1, ipv4().prop_map(|ip| format!("[{}]", ip)).sboxed()),
+                (1, ipv6().prop_map(|ip| format!("[IPv6:{}]", ip)).sboxed()),
+            ])
+            .sboxed(),
+        )
+    } else {
+        (dot_atom, domain(config))
+    };
+
+    WebStrategy(
+        (local, domain)
+            .prop_filter("local part too long", |(local, _)| {
+                local.len() <= MAX_LOCAL_PART_LEN
+            })
+            .prop_map(|(local, domain)| format!("{}@{}", local, domain))
+            .prop_filter("email address too long", |email| {
+                email.len() <= MAX_EMAIL_LEN
+            })
+            .sboxed(),
+    )
+}
+
+/// Creates a strategy which generates URLs with the default `WebConfig`.
+pub fn url() -> WebStrategy {
+    url_with_config(&WebConfig::default())
+}
+
+/// Creates a strategy which generates absolute URLs with an authority, as
+/// described by `config`, following the syntax of RFC 3986.
+///
+/// URLs shrink towards a short hostname without a port, path, query or
+/// fragment.
+///
+/// ## Panics
+///
+/// Panics if `config.schemes` is empty.
+pub fn url_with_config(config: &WebConfig) -> WebStrategy {
+    assert!(!config.schemes.is_empty(), "No URL schemes to choose from");
+
+    let scheme = crate::sample::select(config.schemes).prop_map(str::to_owned);
+    let segment = if config.pathological {
+        "([A-Za-z0-9._~!$&'()*+,;=:@-]|%[0-9A-F]{2}){0,10}"
+    } else {
+        "[A-Za-z0-9._~-]{0,10}"
+    };
+    let path = regex(&format!("(/{}){{0,4}}", segment));
+    let (host, userinfo) = if config.pathological {
+        (
+            Union::new_weighted(vec![
+                (8, domain(config)),
+                (1, ipv4()),
+                (1, ipv6().prop_map(|ip| format!("[{}]", ip)).sboxed()),
+            ])
+            .sboxed(),
+            regex(concat!(
+                "([A-Za-z0-9._~-]{1,8}",
+                "(:([A-Za-z0-9._~-]|%[0-9A-F]{2}){0,8})?@)?"
+            )),
+        )
+    } else {
+        (domain(config), Just(String::new()).sboxed())
+    };
+    let port = if config.ports {
+        crate::option::weighted(0.25, crate::num::u16::ANY)
+            .prop_map(|port| port.map_or(String::new(), |p| format!(":{}", p)))
+            .sboxed()
+    } else {
+        Just(String::new()).sboxed()
+    };
+    let query = if config.query {
+        let param = "[a-z_]{1,8}=([A-Za-z0-9._~-]|%[0-9A-F]{2}){0,8}";
+        regex(&format!(
+            "(\\?{0}(&{0}){{0,3}})?(#[A-Za-z0-9._~-]{{0,8}})?",
+            param
+        ))
+    } else {
+        Just(String::new()).sboxed()
+    };
+
+    WebStrategy(
+        (scheme, userinfo, host, port, path, query)
+            .prop_map(|(scheme, userinfo, host, port, path, query)| {
+                let parts: Vec<String> = vec![
+                    scheme,
+                    "://".to_owned(),
+                    userinfo,
+                    host,
+                    port,
+                    path,
+                    query,
+                ];
+                parts.concat()
+            })
+            .sboxed(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use regex::Regex;
+
+    use super::*;
+
+    fn generate(strategy: WebStrategy, count: usize) -> Vec<String> {
+        let mut runner = TestRunner::deterministic();
+        (0..count)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+            .collect()
+    }
+
+    fn pathological() -> WebConfig {
+        WebConfig {
+            pathological: true,
+            ..WebConfig::default()
+        }
+    }
+
+    fn is_hostname(name: &str) -> bool {
+        let label = Regex::new("^[a-z0-9]([a-z0-9-]{0,61}[a-z0-9])?$").unwrap();
+        name.len() <= MAX_HOSTNAME_LEN
+            && name.split('.').all(|l| label.is_match(l))
+    }
+
+    #[test]
+    fn hostnames_are_valid() {
+        for name in generate(hostname(), 512) {
+            assert!(is_hostname(&name), "{:?}", name);
+            assert!(name.contains('.'), "{:?}", name);
+        }
+
+        let names = generate(hostname_with_config(&pathological()), 1024);
+        for name in &names {
+            let name = name.strip_suffix('.').unwrap_or(name);
+            assert!(is_hostname(name), "{:?}", name);
+        }
+        assert!(names.iter().any(|n| n.ends_with('.')));
+        assert!(names.iter().any(|n| !n.contains('.')));
+        assert!(names
+            .iter()
+            .any(|n| n.split('.').any(|label| label.len() == 63)));
+    }
+
+    #[test]
+    fn idn_hostnames_contain_unicode() {
+        let config = WebConfig {
+            idn: true,
+            ..WebConfig::default()
+        };
+        let names = generate(hostname_with_config(&config), 256);
+        assert!(names.iter().any(|n| !n.is_ascii()));
+        for name in names {
+            assert!(
+                name.split('.').all(|label| !label.is_empty()
+                    && label.chars().all(|c| c.is_alphanumeric() || c == '-')),
+                "{:?}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn emails_are_valid() {
+        let plain = Regex::new(
+            r"^[A-Za-z0-9!#$%&'*+/=?^_`{|}~-]+(\.[A-Za-z0-9!#$%&'*+/=?^_`{|}~-]+)*@([a-z0-9.-]+)$",
+        )
+        .unwrap();
+        for email in generate(email(), 512) {
+            let domain = &plain
+                .captures(&email)
+                .unwrap_or_else(|| panic!("{:?}", email))[2];
+            assert!(is_hostname(domain), "{:?}", email);
+        }
+
+        let emails = generate(email_with_config(&pathological()), 1024);
+        for email in &emails {
+            assert!(email.len() <= MAX_EMAIL_LEN);
+            let at = email.rfind('@').unwrap();
+            assert!(at <= MAX_LOCAL_PART_LEN, "{:?}", email);
+        }
+        assert!(emails.iter().any(|e| e.starts_with('"')));
+        assert!(emails.iter().any(|e| e.ends_with(']')));
+    }
+
+    #[test]
+    fn urls_are_valid() {
+        let rx = Regex::new(
+            r"^(https?)://([a-z0-9.-]+)(:[0-9]{1,5})?(/[A-Za-z0-9._~-]*)*(\?[^#]*)?(#.*)?$",
+        )
+        .unwrap();
+        for url in generate(url(), 512) {
+            let captures =
+                rx.captures(&url).unwrap_or_else(|| panic!("{:?}", url));
+            assert!(is_hostname(&captures[2]), "{:?}", url);
+            if let Some(port) = captures.get(3) {
+                assert!(port.as_str()[1..].parse::<u16>().is_ok(), "{:?}", url);
+            }
+        }
+
+        let config = WebConfig {
+            schemes: &["ftp"],
+            ports: false,
+            query: false,
+            ..WebConfig::default()
+        };
+        for url in generate(url_with_config(&config), 256) {
+            let rest = url.strip_prefix("ftp://").unwrap();
+            assert!(!rest.contains(&[':', '?', '#'][..]), "{:?}", url);
+        }
+
+        let urls = generate(url_with_config(&pathological()), 1024);
+        assert!(urls.iter().any(|u| u.contains('[')));
+        assert!(urls.iter().any(|u| u.contains('@')));
+        assert!(urls.iter().any(|u| u.contains('%')));
+    }
+
+    #[test]
+    fn urls_shrink_to_bare_hosts() {
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..32 {
+            let mut tree = url().new_tree(&mut runner).unwrap();
+            while tree.simplify() {}
+            let url = tree.current();
+            assert!(
+                Regex::new("^http://[a-z0-9]\\.[a-z]{2}$")
+                    .unwrap()
+                    .is_match(&url),
+                "{:?}",
+                url
+            );
+        }
+    }
+}